@@ -8,7 +8,10 @@ use hyper::StatusCode;
 use mongodb::bson::oid::ObjectId;
 
 use crate::{
-    api::{decode_user_id_cookie, internal_server_error, not_found, validate_player, WhyError},
+    api::{
+        decode_user_id_cookie, internal_server_error, not_found, validate_player,
+        validate_storyteller, WhyError,
+    },
     mongo::characters::CharacterCurrent,
     shared::{
         character::{DeleteCharacter, GetCharacter, PatchCharacter, PutCharacter},
@@ -73,7 +76,8 @@ pub async fn delete_character(
     }
 }
 
-/// Handler for PATCH requests to apply a mutation to a character.
+/// Handler for PATCH requests to apply a mutation to a character. Stunts may
+/// only be awarded by the storyteller.
 pub async fn patch_character(
     State(mut state): State<AppState>,
     jar: SignedCookieJar,
@@ -81,12 +85,22 @@ pub async fn patch_character(
     Json(mutation): Json<CharacterMutation>,
 ) -> Result<(), (StatusCode, Json<WhyError>)> {
     let user_id = decode_user_id_cookie(jar)?;
-    validate_player(&mut state, user_id, campaign_id).await?;
+
+    // Stunts can only be awarded by the storyteller, who may award them to
+    // any character in the campaign
+    let as_storyteller = matches!(mutation, CharacterMutation::AwardStunt(_));
+    if as_storyteller {
+        validate_storyteller(&mut state, user_id, campaign_id).await?;
+    } else {
+        validate_player(&mut state, user_id, campaign_id).await?;
+    }
+
     PatchCharacter {
         player: user_id,
         campaign_id,
         character_id,
         mutation,
+        as_storyteller,
     }
     .execute(
         &state.mongodb_client,
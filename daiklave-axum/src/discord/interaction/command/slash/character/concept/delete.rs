@@ -58,6 +58,7 @@ pub async fn character_concept_delete(
         campaign_id,
         character_id,
         mutation,
+        as_storyteller: false,
     }
     .execute(
         &state.mongodb_client,
@@ -64,6 +64,7 @@ pub async fn set_concept_modal(interaction: &ModalInteraction, state: &mut AppSt
         campaign_id,
         character_id,
         mutation,
+        as_storyteller: false,
     }
     .execute(
         &state.mongodb_client,
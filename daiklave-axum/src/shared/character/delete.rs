@@ -89,7 +89,7 @@ impl DeleteCharacter {
         &self,
         connection: &mut CON,
     ) -> Result<(), DatabaseError> {
-        let mut key = "characterId:".as_bytes().to_vec();
+        let mut key = super::CHARACTER_CACHE_PREFIX.as_bytes().to_vec();
         key.extend(self.character_id.bytes());
 
         connection.del(vec![key]).await?;
@@ -35,7 +35,7 @@ impl GetCharacter {
         &self,
         connection: &mut CON,
     ) -> Result<Option<CharacterCurrent>, DatabaseError> {
-        let mut key = "characterId:".as_bytes().to_vec();
+        let mut key = super::CHARACTER_CACHE_PREFIX.as_bytes().to_vec();
         key.extend(self.character_id.bytes());
 
         let maybe_bytes: Option<Vec<u8>> = connection.get(vec![key]).await?;
@@ -65,7 +65,7 @@ impl GetCharacter {
         }
 
         if let Some(character) = self.execute_mongo(database).await? {
-            let mut key = "characterId:".as_bytes().to_vec();
+            let mut key = super::CHARACTER_CACHE_PREFIX.as_bytes().to_vec();
             key.extend(self.character_id.bytes());
 
             let value = postcard::to_allocvec(&character)
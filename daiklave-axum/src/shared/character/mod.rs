@@ -9,3 +9,8 @@ pub use delete::DeleteCharacter;
 pub use get::GetCharacter;
 pub use patch::PatchCharacter;
 pub use put::PutCharacter;
+
+/// The prefix for cached characters' Redis keys. The cached value is the
+/// postcard encoding of the character, which is not self-describing, so this
+/// must be bumped whenever the serialized shape of CharacterMemo changes.
+const CHARACTER_CACHE_PREFIX: &str = "characterId:v1:";
@@ -1,5 +1,5 @@
 use daiklave_core::{CharacterMemo, CharacterMutation};
-use mongodb::bson::{doc, oid::ObjectId};
+use mongodb::bson::{doc, oid::ObjectId, Document};
 use redis::AsyncCommands;
 use serenity::all::UserId;
 
@@ -13,7 +13,8 @@ use crate::{
 
 /// An instruction to partially update a character using a [CharacterMutation].
 pub struct PatchCharacter {
-    /// The Discord snowflake of the character's player.
+    /// The Discord snowflake of the user making the change. Unless acting
+    /// as storyteller, this must be the character's player.
     pub player: UserId,
     /// The Id of the campaign to which the character belongs.
     pub campaign_id: ObjectId,
@@ -21,9 +22,24 @@ pub struct PatchCharacter {
     pub character_id: ObjectId,
     /// The mutation to apply.
     pub mutation: CharacterMutation,
+    /// If true, the change is made by the campaign's storyteller, who may
+    /// patch any character in the campaign rather than only their own.
+    pub as_storyteller: bool,
 }
 
 impl PatchCharacter {
+    /// The query matching the character to be patched.
+    fn filter(&self) -> Result<Document, DatabaseError> {
+        let mut filter = doc! {
+            "_id": self.character_id,
+            "campaignId": self.campaign_id,
+        };
+        if !self.as_storyteller {
+            filter.insert("player", to_bson(&self.player)?);
+        }
+        Ok(filter)
+    }
+
     /// Starts a session to read, update, and overwrite character. This is
     /// required on a cache miss, as well as when the mutation changes the
     /// character's name.
@@ -34,15 +50,9 @@ impl PatchCharacter {
     ) -> Result<CharacterCurrent, DatabaseError> {
         session.start_transaction(None).await?;
 
-        let player_bson = to_bson(&self.player)?;
-
         // Get the existing character
         let characters = database.collection::<CharacterCurrent>("characters");
-        let filter = doc! {
-            "_id": self.character_id,
-            "player": &player_bson,
-            "campaignId": self.campaign_id,
-        };
+        let filter = self.filter()?;
         let CharacterCurrent {
             _id,
             player,
@@ -52,6 +62,7 @@ impl PatchCharacter {
             .find_one_with_session(filter, None, session)
             .await?
             .ok_or_else(|| DatabaseError::NotFound("Character".to_string()))?;
+        let player_bson = to_bson(&player)?;
 
         let new_character = character
             .apply_mutation(&self.mutation)
@@ -73,11 +84,7 @@ impl PatchCharacter {
         };
 
         // Replace the character in the database.
-        let query = doc! {
-            "_id": self.character_id,
-            "player": &player_bson,
-            "campaignId": self.campaign_id,
-        };
+        let query = self.filter()?;
         characters
             .replace_one_with_session(query, &new_character_document, None, session)
             .await?;
@@ -110,23 +117,20 @@ impl PatchCharacter {
     /// the player document.
     async fn execute_mongo_replacement(
         &self,
+        player: UserId,
         replacement: CharacterMemo,
         database: &mongodb::Database,
     ) -> Result<(), DatabaseError> {
         let characters = database.collection::<CharacterCurrent>("characters");
         let new_character_document = CharacterCurrent {
             _id: self.character_id,
-            player: self.player,
+            player,
             campaign_id: self.campaign_id,
             character: replacement,
         };
 
         // Replace the character in the database.
-        let query = doc! {
-            "_id": self.character_id,
-            "player": to_bson(&self.player)?,
-            "campaignId": self.campaign_id,
-        };
+        let query = self.filter()?;
         characters
             .replace_one(query, new_character_document, None)
             .await?;
@@ -138,7 +142,7 @@ impl PatchCharacter {
         &self,
         connection: &mut CON,
     ) -> Result<Option<CharacterCurrent>, DatabaseError> {
-        let mut key = "characterId:".as_bytes().to_vec();
+        let mut key = super::CHARACTER_CACHE_PREFIX.as_bytes().to_vec();
         key.extend(self.character_id.bytes());
 
         let maybe_bytes: Option<Vec<u8>> = connection.get(vec![key]).await?;
@@ -147,8 +151,11 @@ impl PatchCharacter {
             let character: CharacterCurrent = postcard::from_bytes(&bytes)
                 .map_err(|_| DatabaseError::DeserializationError("Character".to_owned()))?;
 
-            // Only return the character if the campaign and player match the request
-            if character.campaign_id == self.campaign_id && character.player == self.player {
+            // Only return the character if the campaign and player match the
+            // request. The storyteller may patch any player's character.
+            if character.campaign_id == self.campaign_id
+                && (self.as_storyteller || character.player == self.player)
+            {
                 return Ok(Some(character));
             }
         }
@@ -162,7 +169,7 @@ impl PatchCharacter {
         value: &CharacterCurrent,
         connection: &mut CON,
     ) -> Result<(), DatabaseError> {
-        let mut key = "characterId:".as_bytes().to_vec();
+        let mut key = super::CHARACTER_CACHE_PREFIX.as_bytes().to_vec();
         key.extend(value._id.bytes());
 
         let value = postcard::to_allocvec(&value.character)
@@ -207,7 +214,7 @@ impl PatchCharacter {
                     })?;
 
             let database = client.database(database_name);
-            self.execute_mongo_replacement(new_character.clone(), &database)
+            self.execute_mongo_replacement(player, new_character.clone(), &database)
                 .await?;
             CharacterCurrent {
                 _id,
@@ -79,7 +79,7 @@ impl PutCharacter {
         &self,
         connection: &mut CON,
     ) -> Result<(), DatabaseError> {
-        let mut key = "characterId:".as_bytes().to_vec();
+        let mut key = super::CHARACTER_CACHE_PREFIX.as_bytes().to_vec();
         key.extend(self.character_id.bytes());
 
         connection.del(vec![key]).await?;
@@ -1,5 +1,5 @@
 use crate::{
-    artifact::ArtifactName,
+    artifact::{ArtifactName, MagicMaterial},
     book_reference::BookReference,
     hearthstones::{hearthstone::Hearthstone, HearthstonePosition},
     merits::merit::{Merit, MeritSource},
@@ -147,6 +147,13 @@ impl<'source> ArmorType<'source> {
         }
     }
 
+    pub fn material(&self) -> Option<MagicMaterial> {
+        match self {
+            ArmorType::Artifact(_, no_attunement, _) => Some(no_attunement.magic_material),
+            ArmorType::Mundane(_, _) => None,
+        }
+    }
+
     pub(crate) fn merits(&self) -> Vec<Merit<'source>> {
         match self {
             ArmorType::Artifact(name, armor, _) => {
@@ -0,0 +1,96 @@
+use crate::{
+    armor::armor_item::{ArmorTag, ArmorWeightClass},
+    book_reference::{Book, BookReference},
+};
+
+use super::AddBaseArtifactArmor;
+
+/// The base artifact armor listed in the Core Rulebook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CoreRulebookBaseArtifactArmor {
+    /// Silken Armor
+    SilkenArmor,
+    /// Breastplate (Artifact)
+    Breastplate,
+    /// Lamellar (Artifact)
+    Lamellar,
+    /// Chain Hauberk (Artifact)
+    ChainHauberk,
+    /// Articulated Plate (Artifact)
+    ArticulatedPlate,
+    /// Superheavy Plate (Artifact)
+    SuperheavyPlate,
+}
+
+impl CoreRulebookBaseArtifactArmor {
+    /// The name of the item, as printed in the book.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SilkenArmor => "Silken Armor",
+            Self::Breastplate => "Breastplate (Artifact)",
+            Self::Lamellar => "Lamellar (Artifact)",
+            Self::ChainHauberk => "Chain Hauberk (Artifact)",
+            Self::ArticulatedPlate => "Articulated Plate (Artifact)",
+            Self::SuperheavyPlate => "Superheavy Plate (Artifact)",
+        }
+    }
+
+    /// The page of the Core Rulebook the item is listed on. All armor is
+    /// listed together on page 600.
+    pub fn book_reference(&self) -> BookReference {
+        BookReference::published(Book::CoreRulebook, 600)
+    }
+
+    /// Iterates over every entry in the catalog, in book order.
+    pub fn iter() -> impl Iterator<Item = CoreRulebookBaseArtifactArmor> {
+        [
+            Self::SilkenArmor,
+            Self::Breastplate,
+            Self::Lamellar,
+            Self::ChainHauberk,
+            Self::ArticulatedPlate,
+            Self::SuperheavyPlate,
+        ]
+        .into_iter()
+    }
+}
+
+impl AddBaseArtifactArmor {
+    /// Iterates over every item in the Core Rulebook catalog, ready to be
+    /// added to a character.
+    pub fn core_rulebook() -> impl Iterator<Item = AddBaseArtifactArmor> {
+        CoreRulebookBaseArtifactArmor::iter().map(Self::from_core_rulebook)
+    }
+
+    /// Constructs the given item from the Core Rulebook catalog.
+    pub fn from_core_rulebook(item: CoreRulebookBaseArtifactArmor) -> AddBaseArtifactArmor {
+        let name = item.name();
+        let book_reference = item.book_reference();
+
+        let armor = match item {
+            CoreRulebookBaseArtifactArmor::SilkenArmor => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Light)
+                .tag(ArmorTag::Concealable)
+                .tag(ArmorTag::Silent),
+            CoreRulebookBaseArtifactArmor::Breastplate => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Medium),
+            CoreRulebookBaseArtifactArmor::Lamellar => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Heavy),
+            CoreRulebookBaseArtifactArmor::ChainHauberk => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Medium),
+            CoreRulebookBaseArtifactArmor::ArticulatedPlate => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Heavy),
+            CoreRulebookBaseArtifactArmor::SuperheavyPlate => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Heavy)
+                .tag(ArmorTag::Special),
+        };
+
+        armor.build()
+    }
+}
@@ -3,6 +3,7 @@ mod add_base;
 mod base;
 /// A builder path for constructing a new Artifact armor item.
 pub(crate) mod builder;
+mod catalog;
 mod error;
 mod memo;
 mod name;
@@ -11,6 +12,7 @@ mod no_attunement;
 pub use add::AddArtifactArmor;
 pub use add_base::AddBaseArtifactArmor;
 pub use base::BaseArtifactArmor;
+pub use catalog::CoreRulebookBaseArtifactArmor;
 pub use error::ArtifactError;
 pub(crate) use memo::ArtifactArmor;
 pub use name::ArtifactArmorName;
@@ -22,7 +22,7 @@ pub struct ArtifactArmorNoAttunementMemo {
 impl From<&ArtifactArmorNoAttunement<'_>> for ArtifactArmorNoAttunementMemo {
     fn from(view: &ArtifactArmorNoAttunement<'_>) -> Self {
         Self {
-            book_reference: view.book_reference,
+            book_reference: view.book_reference.clone(),
             lore: view.lore.map(|s| s.to_owned()),
             powers: view.powers.map(|s| s.to_owned()),
             base_armor_name: view.base_armor_name.to_owned(),
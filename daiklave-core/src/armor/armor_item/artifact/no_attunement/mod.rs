@@ -21,7 +21,7 @@ pub struct ArtifactArmorNoAttunement<'source> {
 impl<'source> From<&'source ArtifactArmorNoAttunementMemo> for ArtifactArmorNoAttunement<'source> {
     fn from(memo: &'source ArtifactArmorNoAttunementMemo) -> Self {
         Self {
-            book_reference: memo.book_reference,
+            book_reference: memo.book_reference.clone(),
             lore: memo.lore.as_deref(),
             powers: memo.powers.as_deref(),
             base_armor_name: memo.base_armor_name.as_str(),
@@ -43,7 +43,7 @@ impl<'source> From<&'source ArtifactArmorNoAttunementMemo> for ArtifactArmorNoAt
 
 impl<'source> ArtifactArmorNoAttunement<'source> {
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.book_reference
+        self.book_reference.clone()
     }
 
     pub(crate) fn base_armor(&self) -> &'source BaseArmor {
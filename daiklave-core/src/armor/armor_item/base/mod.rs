@@ -17,7 +17,7 @@ pub(crate) struct BaseArmor {
 
 impl BaseArmor {
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.book_reference
+        self.book_reference.clone()
     }
 
     pub fn weight_class(&self) -> ArmorWeightClass {
@@ -33,7 +33,12 @@ pub use unequip::UnequipArmor;
 pub use weight_class::ArmorWeightClass;
 
 use crate::{
-    book_reference::BookReference, hearthstones::hearthstone::Hearthstone, merits::merit::Merit,
+    artifact::{ArtifactName, MagicMaterial},
+    book_reference::BookReference,
+    charms::charm::evocation::EvokableName,
+    hearthstones::hearthstone::Hearthstone,
+    merits::merit::Merit,
+    Character,
 };
 
 use self::{
@@ -156,4 +161,23 @@ impl<'source> ArmorItem<'source> {
     pub fn powers(&self) -> Option<&'source str> {
         self.0.powers()
     }
+
+    /// If the armor is an artifact, the Magic Material it is made from. None
+    /// for mundane armor.
+    pub fn material(&self) -> Option<MagicMaterial> {
+        self.0.material()
+    }
+
+    /// The Evocations the character can currently use from this armor.
+    /// Evocations which are disabled (for example, because the armor is not
+    /// attuned) are omitted. Always empty for mundane armor.
+    pub fn evocations(&self, character: &Character<'source>) -> impl Iterator<Item = &'source str> {
+        match self.name() {
+            ArmorName::Artifact(name) => {
+                character.available_evocations(EvokableName::Artifact(ArtifactName::Armor(name)))
+            }
+            ArmorName::Mundane(_) => Vec::new(),
+        }
+        .into_iter()
+    }
 }
@@ -0,0 +1,102 @@
+use crate::{
+    armor::armor_item::{ArmorTag, ArmorWeightClass},
+    book_reference::{Book, BookReference},
+};
+
+use super::AddMundaneArmor;
+
+/// The mundane armor listed in the Core Rulebook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CoreRulebookMundaneArmor {
+    /// Buff Jacket
+    BuffJacket,
+    /// Chain Shirt
+    ChainShirt,
+    /// Breastplate
+    Breastplate,
+    /// Chain Hauberk
+    ChainHauberk,
+    /// Lamellar
+    Lamellar,
+    /// Plate
+    Plate,
+    /// Superheavy Plate
+    SuperheavyPlate,
+}
+
+impl CoreRulebookMundaneArmor {
+    /// The name of the item, as printed in the book.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BuffJacket => "Buff Jacket",
+            Self::ChainShirt => "Chain Shirt",
+            Self::Breastplate => "Breastplate",
+            Self::ChainHauberk => "Chain Hauberk",
+            Self::Lamellar => "Lamellar",
+            Self::Plate => "Plate",
+            Self::SuperheavyPlate => "Superheavy Plate",
+        }
+    }
+
+    /// The page of the Core Rulebook the item is listed on. All armor is
+    /// listed together on page 592.
+    pub fn book_reference(&self) -> BookReference {
+        BookReference::published(Book::CoreRulebook, 592)
+    }
+
+    /// Iterates over every entry in the catalog, in book order.
+    pub fn iter() -> impl Iterator<Item = CoreRulebookMundaneArmor> {
+        [
+            Self::BuffJacket,
+            Self::ChainShirt,
+            Self::Breastplate,
+            Self::ChainHauberk,
+            Self::Lamellar,
+            Self::Plate,
+            Self::SuperheavyPlate,
+        ]
+        .into_iter()
+    }
+}
+
+impl AddMundaneArmor {
+    /// Iterates over every item in the Core Rulebook catalog, ready to be
+    /// added to a character.
+    pub fn core_rulebook() -> impl Iterator<Item = AddMundaneArmor> {
+        CoreRulebookMundaneArmor::iter().map(Self::from_core_rulebook)
+    }
+
+    /// Constructs the given item from the Core Rulebook catalog.
+    pub fn from_core_rulebook(item: CoreRulebookMundaneArmor) -> AddMundaneArmor {
+        let name = item.name();
+        let book_reference = item.book_reference();
+
+        let armor = match item {
+            CoreRulebookMundaneArmor::BuffJacket => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Light),
+            CoreRulebookMundaneArmor::ChainShirt => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Light)
+                .tag(ArmorTag::Concealable),
+            CoreRulebookMundaneArmor::Breastplate => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Medium),
+            CoreRulebookMundaneArmor::ChainHauberk => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Medium),
+            CoreRulebookMundaneArmor::Lamellar => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Heavy),
+            CoreRulebookMundaneArmor::Plate => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Heavy),
+            CoreRulebookMundaneArmor::SuperheavyPlate => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(ArmorWeightClass::Heavy)
+                .tag(ArmorTag::Special),
+        };
+
+        armor.build()
+    }
+}
@@ -1,4 +1,5 @@
 mod add;
+mod catalog;
 mod memo;
 mod name;
 mod remove;
@@ -6,6 +7,7 @@ mod remove;
 use std::ops::Deref;
 
 pub use add::AddMundaneArmor;
+pub use catalog::CoreRulebookMundaneArmor;
 pub use memo::MundaneArmor;
 pub use name::MundaneArmorName;
 pub use remove::RemoveMundaneArmor;
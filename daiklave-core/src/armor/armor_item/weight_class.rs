@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 /// A weight category for armor.
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Serialize, Deserialize)]
 pub enum ArmorWeightClass {
     /// Light armor, no significant mobility restrictions.
     Light,
@@ -33,6 +33,15 @@ impl AddArtifact {
             attunement_cost: None,
         }
     }
+
+    /// The artifact's rating in merit dots. Ratings of 6+ are N/A artifacts.
+    pub fn merit_dots(&self) -> u8 {
+        match self {
+            AddArtifact::Weapon(add_artifact_weapon) => add_artifact_weapon.merit_dots(),
+            AddArtifact::Armor(add_artifact_armor) => add_artifact_armor.armor.0.merit_dots,
+            AddArtifact::Wonder(add_wonder) => add_wonder.wonder.0.merit_dots,
+        }
+    }
 }
 
 impl From<AddArtifactWeapon> for AddArtifact {
@@ -2,10 +2,12 @@ mod no_attunement;
 pub(crate) use no_attunement::{WonderNoAttunement, WonderNoAttunementMemo};
 
 use crate::{
-    artifact::MagicMaterial,
+    artifact::{ArtifactName, MagicMaterial},
     book_reference::BookReference,
+    charms::charm::evocation::EvokableName,
     hearthstones::hearthstone::Hearthstone,
     merits::merit::{Merit, MeritSource},
+    Character,
 };
 
 /// A Wonder that belongs to the character, and may be attuned or unattuned.
@@ -56,11 +58,26 @@ impl<'source> OwnedWonder<'source> {
         self.1.hearthstone_slots()
     }
 
+    /// The number of motes it takes to attune to the wonder. None if the
+    /// wonder does not require attunement.
+    pub fn attunement_cost(&self) -> Option<u8> {
+        self.1.attunement_cost
+    }
+
     /// Returns true if the wonder is currently attuned.
     pub fn is_attuned(&self) -> bool {
         self.2.is_some()
     }
 
+    /// The Evocations the character can currently use from the wonder.
+    /// Evocations which are disabled (for example, because the wonder is not
+    /// attuned) are omitted.
+    pub fn evocations(&self, character: &Character<'source>) -> impl Iterator<Item = &'source str> {
+        character
+            .available_evocations(EvokableName::Artifact(ArtifactName::Wonder(self.0)))
+            .into_iter()
+    }
+
     /// If the wonder has a specific Magic Material, returns it.
     pub fn material(&self) -> Option<MagicMaterial> {
         self.1.material()
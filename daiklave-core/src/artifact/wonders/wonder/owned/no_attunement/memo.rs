@@ -20,7 +20,7 @@ pub(crate) struct WonderNoAttunementMemo {
 impl From<&WonderNoAttunement<'_>> for WonderNoAttunementMemo {
     fn from(view: &WonderNoAttunement<'_>) -> Self {
         Self {
-            book_reference: view.book_reference,
+            book_reference: view.book_reference.clone(),
             lore: view.lore.map(|s| s.to_owned()),
             powers: view.powers.to_owned(),
             hearthstone_slots: view
@@ -21,7 +21,7 @@ pub(crate) struct WonderNoAttunement<'source> {
 impl<'source> From<&'source WonderNoAttunementMemo> for WonderNoAttunement<'source> {
     fn from(memo: &'source WonderNoAttunementMemo) -> Self {
         Self {
-            book_reference: memo.book_reference,
+            book_reference: memo.book_reference.clone(),
             lore: memo.lore.as_deref(),
             powers: memo.powers.as_str(),
             hearthstone_slots: memo
@@ -38,7 +38,7 @@ impl<'source> From<&'source WonderNoAttunementMemo> for WonderNoAttunement<'sour
 
 impl<'source> WonderNoAttunement<'source> {
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.book_reference
+        self.book_reference.clone()
     }
 
     pub fn lore(&self) -> Option<&'source str> {
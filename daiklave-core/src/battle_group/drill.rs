@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// How well-trained and disciplined a battle group is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Drill {
+    /// Conscripts, peasant levies, and mobs.
+    Poor,
+    /// Professional soldiers.
+    Average,
+    /// Veteran or elite troops.
+    Elite,
+}
+
+impl Drill {
+    /// The bonus this Drill rating adds to the battle group's Parry and
+    /// Evasion.
+    pub fn defense_bonus(&self) -> u8 {
+        match self {
+            Drill::Poor => 0,
+            Drill::Average => 1,
+            Drill::Elite => 2,
+        }
+    }
+
+    /// The difficulty of the Willpower roll the battle group must make to
+    /// avoid routing.
+    pub fn rout_difficulty(&self) -> u8 {
+        match self {
+            Drill::Poor => 3,
+            Drill::Average => 2,
+            Drill::Elite => 1,
+        }
+    }
+}
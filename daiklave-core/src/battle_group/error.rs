@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+use crate::CharacterMutationError;
+
+/// An error related to battle groups.
+#[derive(Debug, Error)]
+pub enum BattleGroupError {
+    /// Size must be between 1 and 5.
+    #[error("Battle group Size must be between 1 and 5")]
+    InvalidSize,
+    /// Might must be between 0 and 3.
+    #[error("Battle group Might must be between 0 and 3")]
+    InvalidMight,
+    /// Can't resolve a rout check if the battle group doesn't need to make
+    /// one.
+    #[error("Battle group has no pending rout check")]
+    NoRoutCheck,
+    /// A broken battle group has been destroyed as a fighting force. It
+    /// can't take or heal damage, change Size, or be rallied.
+    #[error("Battle group has been broken")]
+    Defeated,
+    /// The battle group's Magnitude track could not be updated.
+    #[error("Could not update Magnitude: {0}")]
+    Magnitude(CharacterMutationError),
+}
@@ -0,0 +1,364 @@
+mod drill;
+mod error;
+mod mutation;
+mod rout;
+mod statblock;
+
+pub use drill::Drill;
+pub use error::BattleGroupError;
+pub use mutation::BattleGroupMutation;
+pub use rout::RoutCheck;
+pub use statblock::BattleGroupStatblock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::health::{DamageLevel, Health};
+
+/// A mass of similar combatants acting as a single unit. Battle groups do not
+/// suffer wound penalties; instead, damage reduces their Magnitude, and each
+/// time Magnitude is exhausted the group loses a dot of Size and its
+/// Magnitude refills.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BattleGroup {
+    pub(crate) statblock: BattleGroupStatblock,
+    pub(crate) size: u8,
+    pub(crate) drill: Drill,
+    pub(crate) might: u8,
+    pub(crate) magnitude_track: Health,
+    pub(crate) broken: bool,
+    pub(crate) rout_check_pending: bool,
+    pub(crate) routed: bool,
+}
+
+impl BattleGroup {
+    pub(crate) fn new(
+        statblock: BattleGroupStatblock,
+        size: u8,
+        drill: Drill,
+        might: u8,
+    ) -> Result<Self, BattleGroupError> {
+        if !(1..=5).contains(&size) {
+            return Err(BattleGroupError::InvalidSize);
+        }
+
+        if might > 3 {
+            return Err(BattleGroupError::InvalidMight);
+        }
+
+        let mut battle_group = Self {
+            statblock,
+            size,
+            drill,
+            might,
+            magnitude_track: Health::default(),
+            broken: false,
+            rout_check_pending: false,
+            routed: false,
+        };
+        battle_group.refill_magnitude();
+        Ok(battle_group)
+    }
+
+    /// Applies a mutation to the battle group.
+    pub fn apply_mutation(
+        &mut self,
+        mutation: &BattleGroupMutation,
+    ) -> Result<&mut Self, BattleGroupError> {
+        match mutation {
+            BattleGroupMutation::TakeDamage(take_damage) => {
+                self.take_damage(take_damage.level, take_damage.amount.get())
+            }
+            BattleGroupMutation::HealDamage(heal_damage) => self.heal_damage(heal_damage.0.get()),
+            BattleGroupMutation::SetSize(size) => self.set_size(size.get()),
+            BattleGroupMutation::SetDrill(drill) => self.set_drill(*drill),
+            BattleGroupMutation::SetMight(might) => self.set_might(*might),
+            BattleGroupMutation::ResolveRoutCheck(successes) => self.resolve_rout_check(*successes),
+            BattleGroupMutation::Rally => self.rally(),
+        }
+    }
+
+    /// The name of the battle group.
+    pub fn name(&self) -> &str {
+        self.statblock.name.as_str()
+    }
+
+    /// The statblock of a typical member of the battle group.
+    pub fn statblock(&self) -> &BattleGroupStatblock {
+        &self.statblock
+    }
+
+    /// The battle group's current Size, from 1 to 5. A broken group retains
+    /// the Size it had when it was destroyed.
+    pub fn size(&self) -> u8 {
+        self.size
+    }
+
+    /// The battle group's Drill.
+    pub fn drill(&self) -> Drill {
+        self.drill
+    }
+
+    /// The battle group's Might, from 0 to 3.
+    pub fn might(&self) -> u8 {
+        self.might
+    }
+
+    /// The battle group's maximum Magnitude at its current Size: the total
+    /// health levels of a typical member, plus Size.
+    pub fn max_magnitude(&self) -> u8 {
+        u8::try_from(self.statblock.health.iter().count())
+            .unwrap_or(u8::MAX)
+            .saturating_add(self.size)
+    }
+
+    /// The battle group's remaining Magnitude.
+    pub fn magnitude(&self) -> u8 {
+        self.max_magnitude().saturating_sub(self.magnitude_damage())
+    }
+
+    /// The battle group's Magnitude track, as boxes of damage. Battle groups
+    /// do not suffer wound penalties, so all boxes are at -0.
+    pub fn magnitude_track(&self) -> &Health {
+        &self.magnitude_track
+    }
+
+    /// True if the battle group has lost its last dot of Size and been
+    /// destroyed as a fighting force.
+    pub fn is_broken(&self) -> bool {
+        self.broken
+    }
+
+    /// True if the battle group failed a rout check and is fleeing.
+    pub fn is_routed(&self) -> bool {
+        self.routed
+    }
+
+    /// If the battle group has taken heavy losses and needs to roll to
+    /// avoid routing, returns the pool and difficulty for the roll.
+    pub fn rout_check(&self) -> Option<RoutCheck> {
+        if self.rout_check_pending {
+            Some(RoutCheck {
+                pool: self.statblock.willpower,
+                difficulty: self.drill.rout_difficulty(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The bonus the battle group adds to its attack accuracy pools, equal
+    /// to Size plus Might.
+    pub fn accuracy_bonus(&self) -> u8 {
+        self.size + self.might
+    }
+
+    /// The bonus the battle group adds to its attack damage, equal to Size
+    /// plus Might.
+    pub fn damage_bonus(&self) -> u8 {
+        self.size + self.might
+    }
+
+    /// The bonus the battle group adds to its Parry and Evasion from Drill.
+    pub fn defense_bonus(&self) -> u8 {
+        self.drill.defense_bonus()
+    }
+
+    /// Iterates over the battle group's named attacks, with accuracy and
+    /// damage including the Size and Might bonuses.
+    pub fn attacks(&self) -> impl Iterator<Item = (&str, u8, u8)> + '_ {
+        self.statblock
+            .attacks
+            .iter()
+            .map(|(name, (accuracy, damage))| {
+                (
+                    name.as_str(),
+                    accuracy.saturating_add(self.accuracy_bonus()),
+                    damage.saturating_add(self.damage_bonus()),
+                )
+            })
+    }
+
+    /// Gets a specific named attack's accuracy and damage, including the
+    /// Size and Might bonuses.
+    pub fn attack(&self, name: &str) -> Option<(u8, u8)> {
+        self.statblock.attacks.get(name).map(|(accuracy, damage)| {
+            (
+                accuracy.saturating_add(self.accuracy_bonus()),
+                damage.saturating_add(self.damage_bonus()),
+            )
+        })
+    }
+
+    /// Gets a named non-attack action pool. These are unmodified by Size.
+    pub fn action_pool(&self, name: &str) -> Option<u8> {
+        self.statblock.action_pools.get(name).copied()
+    }
+
+    /// The battle group's Parry, including its Drill bonus.
+    pub fn parry(&self) -> u8 {
+        self.statblock.parry.saturating_add(self.defense_bonus())
+    }
+
+    /// The battle group's Evasion, including its Drill bonus.
+    pub fn evasion(&self) -> u8 {
+        self.statblock.evasion.saturating_add(self.defense_bonus())
+    }
+
+    /// The battle group's soak, including its Size bonus.
+    pub fn soak(&self) -> u8 {
+        self.statblock.soak.saturating_add(self.size)
+    }
+
+    /// The battle group's Hardness.
+    pub fn hardness(&self) -> u8 {
+        self.statblock.hardness
+    }
+
+    /// The battle group's Resolve.
+    pub fn resolve(&self) -> u8 {
+        self.statblock.resolve
+    }
+
+    /// The battle group's Guile.
+    pub fn guile(&self) -> u8 {
+        self.statblock.guile
+    }
+
+    fn magnitude_damage(&self) -> u8 {
+        self.magnitude_track.bashing_damage
+            + self.magnitude_track.lethal_damage
+            + self.magnitude_track.aggravated_damage
+    }
+
+    fn refill_magnitude(&mut self) {
+        self.magnitude_track = Health {
+            zero_boxes: self.max_magnitude(),
+            minus_one_boxes: 0,
+            minus_two_boxes: 0,
+            minus_four_boxes: 0,
+            incapacitated_boxes: 0,
+            bashing_damage: 0,
+            lethal_damage: 0,
+            aggravated_damage: 0,
+            healing_progress: 0,
+        };
+    }
+
+    /// Deals damage to the battle group's Magnitude. All levels of damage
+    /// reduce Magnitude equally. Whenever Magnitude is exhausted, the group
+    /// loses a dot of Size (carrying over any excess damage) and must make a
+    /// rout check; a group that loses its last dot of Size is broken. A
+    /// group that is reduced to half Magnitude or less must also make a rout
+    /// check.
+    pub fn take_damage(
+        &mut self,
+        damage_level: DamageLevel,
+        amount: u8,
+    ) -> Result<&mut Self, BattleGroupError> {
+        if self.broken {
+            return Err(BattleGroupError::Defeated);
+        }
+
+        let half_magnitude = self.max_magnitude() / 2;
+        let started_above_half = self.magnitude() > half_magnitude;
+        let mut remaining = amount;
+
+        while remaining > 0 {
+            let magnitude = self.magnitude();
+            if remaining < magnitude {
+                match damage_level {
+                    DamageLevel::Bashing => self.magnitude_track.bashing_damage += remaining,
+                    DamageLevel::Lethal => self.magnitude_track.lethal_damage += remaining,
+                    DamageLevel::Aggravated => self.magnitude_track.aggravated_damage += remaining,
+                }
+                remaining = 0;
+            } else {
+                remaining -= magnitude;
+                self.rout_check_pending = true;
+                if self.size <= 1 {
+                    self.broken = true;
+                    self.rout_check_pending = false;
+                    self.magnitude_track.bashing_damage = 0;
+                    self.magnitude_track.lethal_damage = 0;
+                    self.magnitude_track.aggravated_damage = self.max_magnitude();
+                    return Ok(self);
+                }
+                self.size -= 1;
+                self.refill_magnitude();
+            }
+        }
+
+        if started_above_half && self.magnitude() <= half_magnitude {
+            self.rout_check_pending = true;
+        }
+
+        Ok(self)
+    }
+
+    /// Restores Magnitude, removing bashing before lethal before aggravated
+    /// damage. Cannot restore lost Size.
+    pub fn heal_damage(&mut self, amount: u8) -> Result<&mut Self, BattleGroupError> {
+        if self.broken {
+            return Err(BattleGroupError::Defeated);
+        }
+
+        self.magnitude_track
+            .heal_damage(amount)
+            .map_err(BattleGroupError::Magnitude)?;
+        Ok(self)
+    }
+
+    /// Sets the battle group's Size, restoring Magnitude to full. A broken
+    /// group has been destroyed and cannot be restored.
+    pub fn set_size(&mut self, size: u8) -> Result<&mut Self, BattleGroupError> {
+        if self.broken {
+            return Err(BattleGroupError::Defeated);
+        }
+
+        if !(1..=5).contains(&size) {
+            return Err(BattleGroupError::InvalidSize);
+        }
+
+        self.size = size;
+        self.refill_magnitude();
+        Ok(self)
+    }
+
+    /// Sets the battle group's Drill.
+    pub fn set_drill(&mut self, drill: Drill) -> Result<&mut Self, BattleGroupError> {
+        self.drill = drill;
+        Ok(self)
+    }
+
+    /// Sets the battle group's Might.
+    pub fn set_might(&mut self, might: u8) -> Result<&mut Self, BattleGroupError> {
+        if might > 3 {
+            return Err(BattleGroupError::InvalidMight);
+        }
+
+        self.might = might;
+        Ok(self)
+    }
+
+    /// Resolves a pending rout check. If the successes rolled are less than
+    /// the difficulty, the battle group routs.
+    pub fn resolve_rout_check(&mut self, successes: u8) -> Result<&mut Self, BattleGroupError> {
+        let rout_check = self.rout_check().ok_or(BattleGroupError::NoRoutCheck)?;
+        self.rout_check_pending = false;
+        if successes < rout_check.difficulty {
+            self.routed = true;
+        }
+        Ok(self)
+    }
+
+    /// Rallies a routed battle group so that it can fight again. A broken
+    /// group cannot be rallied.
+    pub fn rally(&mut self) -> Result<&mut Self, BattleGroupError> {
+        if self.broken {
+            return Err(BattleGroupError::Defeated);
+        }
+
+        self.routed = false;
+        Ok(self)
+    }
+}
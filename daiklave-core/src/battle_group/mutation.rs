@@ -0,0 +1,41 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use crate::health::{HealDamage, TakeDamage};
+
+use super::Drill;
+
+/// The ways a battle group can be atomically updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BattleGroupMutation {
+    /// Deal damage to the battle group's Magnitude, reducing its Size when
+    /// Magnitude is exhausted.
+    TakeDamage(TakeDamage),
+    /// Restore Magnitude, healing bashing before lethal before aggravated.
+    HealDamage(HealDamage),
+    /// Sets the battle group's Size, restoring its Magnitude to full. Broken
+    /// groups cannot be restored.
+    SetSize(NonZeroU8),
+    /// Sets the battle group's Drill.
+    SetDrill(Drill),
+    /// Sets the battle group's Might (0 to 3).
+    SetMight(u8),
+    /// Resolves a pending rout check with the number of successes rolled.
+    ResolveRoutCheck(u8),
+    /// Rallies a routed battle group, clearing its routed status. Broken
+    /// groups cannot be rallied.
+    Rally,
+}
+
+impl From<TakeDamage> for BattleGroupMutation {
+    fn from(take_damage: TakeDamage) -> Self {
+        Self::TakeDamage(take_damage)
+    }
+}
+
+impl From<HealDamage> for BattleGroupMutation {
+    fn from(heal_damage: HealDamage) -> Self {
+        Self::HealDamage(heal_damage)
+    }
+}
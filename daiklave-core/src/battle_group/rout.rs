@@ -0,0 +1,9 @@
+/// A Willpower roll a battle group must make to hold together after taking
+/// heavy losses. If the roll fails to meet the difficulty, the group routs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoutCheck {
+    /// The number of dice to roll; the base statblock's Willpower.
+    pub pool: u8,
+    /// The number of successes needed to hold, based on Drill.
+    pub difficulty: u8,
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::health::Health;
+
+use super::{BattleGroup, BattleGroupError, Drill};
+
+/// The statistics of a typical member of a battle group. The battle group
+/// uses these as the base for its own pools and defenses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BattleGroupStatblock {
+    pub(crate) name: String,
+    pub(crate) action_pools: HashMap<String, u8>,
+    pub(crate) attacks: HashMap<String, (u8, u8)>,
+    pub(crate) parry: u8,
+    pub(crate) evasion: u8,
+    pub(crate) soak: u8,
+    pub(crate) hardness: u8,
+    pub(crate) resolve: u8,
+    pub(crate) guile: u8,
+    pub(crate) willpower: u8,
+    pub(crate) health: Health,
+}
+
+impl BattleGroupStatblock {
+    /// Starts a new statblock with the given name. Pools and defenses default
+    /// to zero, with Willpower 3 and the default health track.
+    pub fn name(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            action_pools: HashMap::new(),
+            attacks: HashMap::new(),
+            parry: 0,
+            evasion: 0,
+            soak: 0,
+            hardness: 0,
+            resolve: 0,
+            guile: 0,
+            willpower: 3,
+            health: Health::default(),
+        }
+    }
+
+    /// Adds a named non-attack action pool, like "Senses 5".
+    pub fn action_pool(mut self, name: impl Into<String>, dice: u8) -> Self {
+        self.action_pools.insert(name.into(), dice);
+        self
+    }
+
+    /// Adds a named attack with its accuracy pool and damage.
+    pub fn attack(mut self, name: impl Into<String>, accuracy: u8, damage: u8) -> Self {
+        self.attacks.insert(name.into(), (accuracy, damage));
+        self
+    }
+
+    /// Sets the Parry of a typical member.
+    pub fn parry(mut self, parry: u8) -> Self {
+        self.parry = parry;
+        self
+    }
+
+    /// Sets the Evasion of a typical member.
+    pub fn evasion(mut self, evasion: u8) -> Self {
+        self.evasion = evasion;
+        self
+    }
+
+    /// Sets the total soak (including armor) of a typical member.
+    pub fn soak(mut self, soak: u8) -> Self {
+        self.soak = soak;
+        self
+    }
+
+    /// Sets the Hardness of a typical member.
+    pub fn hardness(mut self, hardness: u8) -> Self {
+        self.hardness = hardness;
+        self
+    }
+
+    /// Sets the Resolve of a typical member.
+    pub fn resolve(mut self, resolve: u8) -> Self {
+        self.resolve = resolve;
+        self
+    }
+
+    /// Sets the Guile of a typical member.
+    pub fn guile(mut self, guile: u8) -> Self {
+        self.guile = guile;
+        self
+    }
+
+    /// Sets the Willpower of a typical member, used for rout checks.
+    pub fn willpower(mut self, willpower: u8) -> Self {
+        self.willpower = willpower;
+        self
+    }
+
+    /// Sets the health track of a typical member. Its total number of boxes
+    /// determines the battle group's base Magnitude.
+    pub fn health(mut self, health: Health) -> Self {
+        self.health = health;
+        self
+    }
+
+    /// Forms a battle group out of many members with this statblock. Size
+    /// must be between 1 and 5, and Might between 0 and 3.
+    pub fn battle_group(
+        self,
+        size: u8,
+        drill: Drill,
+        might: u8,
+    ) -> Result<BattleGroup, BattleGroupError> {
+        BattleGroup::new(self, size, drill, might)
+    }
+}
@@ -1,10 +1,63 @@
+use std::ops::RangeInclusive;
+
 use serde::{Deserialize, Serialize};
 
-/// Published Exalted 3e books.
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+/// Published Exalted 3e books, or a homebrew source.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum Book {
     /// The core rulebook.
     CoreRulebook,
     /// Arms of the Chosen, featuring a variety of Artifacts and Hearthstones.
     ArmsOfTheChosen,
+    /// Hundred Devils Night Parade, a bestiary of demons, gods, and
+    /// elementals.
+    HundredDevilsNightParade,
+    /// Adversaries of the Righteous, a collection of antagonists.
+    AdversariesOfTheRighteous,
+    /// Dragon-Blooded: What Fire Has Wrought.
+    DragonBloodedWhatFireHasWrought,
+    /// Lunars: Fangs at the Gate.
+    LunarsFangsAtTheGate,
+    /// Exigents: Crucible of Legends.
+    ExigentsCrucibleOfLegends,
+    /// Miracles of the Solar Exalted, an expanded list of Solar Charms.
+    MiraclesOfTheSolarExalted,
+    /// Heirs to the Shogunate, a setting book for the Realm and the
+    /// Dragon-Blooded.
+    HeirsToTheShogunate,
+    /// A homebrew or otherwise unpublished source. The name of the source
+    /// is held by the BookReference.
+    Homebrew,
+}
+
+impl Book {
+    /// The valid page numbers for the book. Returns None for homebrew
+    /// sources, which may use any positive page number.
+    pub fn pages(&self) -> Option<RangeInclusive<i16>> {
+        let last_page = match self {
+            Book::CoreRulebook => 680,
+            Book::ArmsOfTheChosen => 282,
+            Book::HundredDevilsNightParade => 250,
+            Book::AdversariesOfTheRighteous => 262,
+            Book::DragonBloodedWhatFireHasWrought => 452,
+            Book::LunarsFangsAtTheGate => 364,
+            Book::ExigentsCrucibleOfLegends => 312,
+            Book::MiraclesOfTheSolarExalted => 332,
+            Book::HeirsToTheShogunate => 238,
+            Book::Homebrew => {
+                return None;
+            }
+        };
+
+        Some(1..=last_page)
+    }
+
+    /// Whether the page number is in range for the book.
+    pub fn contains_page(&self, page_number: i16) -> bool {
+        if let Some(range) = self.pages() {
+            range.contains(&page_number)
+        } else {
+            page_number > 0
+        }
+    }
 }
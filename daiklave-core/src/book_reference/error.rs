@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+/// An error related to constructing a book reference.
+#[derive(Debug, Error)]
+pub enum BookReferenceError {
+    /// The page number is not within the referenced book.
+    #[error("Page number is out of range for the book")]
+    PageOutOfRange,
+    /// A homebrew source must have a name.
+    #[error("Homebrew sources must have a name")]
+    EmptySource,
+}
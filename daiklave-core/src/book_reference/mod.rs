@@ -1,18 +1,66 @@
 mod book;
+mod error;
 
 pub use book::Book;
+pub use error::BookReferenceError;
 use serde::{Deserialize, Serialize};
-/// A reference to a specific page of a specific Exalted 3e book.
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+/// A reference to a specific page of a specific Exalted 3e book, or to a
+/// homebrew source.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedBookReference")]
 pub struct BookReference {
     pub(crate) book: Book,
     pub(crate) page_number: i16,
+    pub(crate) homebrew_source: Option<Box<str>>,
 }
 
 impl BookReference {
-    /// Constructs a new BookReference.
-    pub fn new(book: Book, page_number: i16) -> Self {
-        Self { book, page_number }
+    /// Constructs a new BookReference to a published book. Returns an Err if
+    /// the page number is outside of the book, or if the book is Homebrew
+    /// (use homebrew instead).
+    pub fn new(book: Book, page_number: i16) -> Result<Self, BookReferenceError> {
+        if book == Book::Homebrew {
+            return Err(BookReferenceError::EmptySource);
+        }
+
+        if !book.contains_page(page_number) {
+            return Err(BookReferenceError::PageOutOfRange);
+        }
+
+        Ok(Self::published(book, page_number))
+    }
+
+    /// Constructs a reference to a published book without validating it, for
+    /// the crate's own catalogs.
+    pub(crate) const fn published(book: Book, page_number: i16) -> Self {
+        Self {
+            book,
+            page_number,
+            homebrew_source: None,
+        }
+    }
+
+    /// Constructs a new reference to a homebrew or otherwise unpublished
+    /// source, identified by name. Returns an Err if the name is empty or the
+    /// page number is not positive.
+    pub fn homebrew(
+        source: impl Into<String>,
+        page_number: i16,
+    ) -> Result<Self, BookReferenceError> {
+        let source = source.into();
+        if source.is_empty() {
+            return Err(BookReferenceError::EmptySource);
+        }
+
+        if !Book::Homebrew.contains_page(page_number) {
+            return Err(BookReferenceError::PageOutOfRange);
+        }
+
+        Ok(Self {
+            book: Book::Homebrew,
+            page_number,
+            homebrew_source: Some(source.into_boxed_str()),
+        })
     }
 
     /// The book being referenced.
@@ -20,8 +68,33 @@ impl BookReference {
         self.book
     }
 
+    /// The name of the homebrew source, if this is not a published book.
+    pub fn homebrew_source(&self) -> Option<&str> {
+        self.homebrew_source.as_deref()
+    }
+
     /// The page number in the referenced book.
     pub fn page_number(&self) -> i16 {
         self.page_number
     }
 }
+
+#[derive(Deserialize)]
+struct UncheckedBookReference {
+    book: Book,
+    page_number: i16,
+    #[serde(default)]
+    homebrew_source: Option<String>,
+}
+
+impl TryFrom<UncheckedBookReference> for BookReference {
+    type Error = BookReferenceError;
+
+    fn try_from(unchecked: UncheckedBookReference) -> Result<Self, Self::Error> {
+        match (unchecked.book, unchecked.homebrew_source) {
+            (Book::Homebrew, Some(source)) => Self::homebrew(source, unchecked.page_number),
+            (Book::Homebrew, None) => Err(BookReferenceError::EmptySource),
+            (book, _) => Self::new(book, unchecked.page_number),
+        }
+    }
+}
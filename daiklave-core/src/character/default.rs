@@ -23,6 +23,13 @@ impl<'source> Default for Character<'source> {
             other_languages: Default::default(),
             intimacies: Default::default(),
             experience: Default::default(),
+            health_levels: Default::default(),
+            intimacy_history: Default::default(),
+            essence_progression: false,
+            craft_projects: Vec::new(),
+            spell_shaping: None,
+            sorcerous_workings: Vec::new(),
+            martial_arts_form: None,
         }
     }
 }
@@ -6,21 +6,23 @@ use crate::{
     abilities::AbilitiesVanillaMemo,
     attributes::Attributes,
     book_reference::BookReference,
-    craft::CraftMemo,
+    craft::{CraftMemo, CraftProject},
     exaltation::ExaltationMemo,
-    experience::ExperiencePool,
+    experience::ExperienceLedger,
     flaws::flaw::FlawName,
-    health::Health,
+    health::{Health, HealthLevels},
     hearthstones::{
         hearthstone::{GeomancyLevel, HearthstoneName},
         UnslottedHearthstoneMemo,
     },
-    intimacies::intimacy::{IntimacyLevel, IntimacyTypeMemo},
+    intimacies::intimacy::{IntimacyChange, IntimacyLevel, IntimacyTypeMemo},
     languages::language::LanguageMutation,
+    martial_arts::charm::MartialArtsCharmName,
     merits::merit::{
         template::{NonStackableMeritName, StackableMeritTemplateName},
         DemenseName, NonStackableMeritInstance, StackableMeritInstance,
     },
+    sorcery::{SorcerousWorking, SpellShaping},
     willpower::Willpower,
     Character, CharacterMutation, CharacterMutationError,
 };
@@ -47,7 +49,24 @@ pub struct CharacterMemo {
     pub(crate) native_language: LanguageMutation,
     pub(crate) other_languages: HashSet<LanguageMutation>,
     pub(crate) intimacies: HashMap<IntimacyTypeMemo, IntimacyLevel>,
-    pub(crate) experience: ExperiencePool,
+    #[serde(default)]
+    pub(crate) experience: ExperienceLedger,
+    /// None for characters saved before health levels were tracked by
+    /// source; their stored health track becomes the base track.
+    #[serde(default)]
+    pub(crate) health_levels: Option<HealthLevels>,
+    #[serde(default)]
+    pub(crate) intimacy_history: Vec<IntimacyChange>,
+    #[serde(default)]
+    pub(crate) essence_progression: bool,
+    #[serde(default)]
+    pub(crate) craft_projects: Vec<CraftProject>,
+    #[serde(default)]
+    pub(crate) spell_shaping: Option<SpellShaping>,
+    #[serde(default)]
+    pub(crate) sorcerous_workings: Vec<SorcerousWorking>,
+    #[serde(default)]
+    pub(crate) martial_arts_form: Option<MartialArtsCharmName>,
 }
 
 impl From<Character<'_>> for CharacterMemo {
@@ -80,7 +99,10 @@ impl From<Character<'_>> for CharacterMemo {
                 .stackable_merits
                 .iter()
                 .map(|((template_name, detail), &instance)| {
-                    (((*template_name).into(), (*detail).into()), instance.to_owned())
+                    (
+                        ((*template_name).into(), (*detail).into()),
+                        instance.to_owned(),
+                    )
                 })
                 .collect(),
             flaws: character
@@ -102,6 +124,13 @@ impl From<Character<'_>> for CharacterMemo {
                 .map(|(&intimacy_type, &level)| (intimacy_type.to_owned(), level))
                 .collect(),
             experience: character.experience,
+            health_levels: Some(character.health_levels),
+            intimacy_history: character.intimacy_history,
+            essence_progression: character.essence_progression,
+            craft_projects: character.craft_projects,
+            spell_shaping: character.spell_shaping,
+            sorcerous_workings: character.sorcerous_workings,
+            martial_arts_form: character.martial_arts_form,
         }
     }
 }
@@ -110,9 +139,12 @@ impl CharacterMemo {
     /// Applies a specific CharacterMutation or returns an error. Note that
     /// this operation is applied immutably, returning a cloned and updated
     /// version of the character.
-    pub fn apply_mutation(&self, mutation: &CharacterMutation) -> Result<CharacterMemo, CharacterMutationError> {
+    pub fn apply_mutation(
+        &self,
+        mutation: &CharacterMutation,
+    ) -> Result<CharacterMemo, CharacterMutationError> {
         let mut character_view: Character = self.into();
         character_view.apply_mutation(mutation)?;
         Ok(character_view.into())
     }
-}
\ No newline at end of file
+}
@@ -51,6 +51,7 @@ impl<'view, 'source> Character<'source> {
         name: &str,
     ) -> Result<&mut Self, CharacterMutationError> {
         self.exaltation.remove_mundane_armor(name)?;
+        self.correct_martial_arts_form();
         Ok(self)
     }
 
@@ -60,12 +61,14 @@ impl<'view, 'source> Character<'source> {
         armor_name: ArmorName<'_>,
     ) -> Result<&mut Self, CharacterMutationError> {
         self.exaltation.equip_armor(armor_name)?;
+        self.correct_martial_arts_form();
         Ok(self)
     }
 
     /// Unequips the currently-equipped piece of armor.
     pub fn unequip_armor(&mut self) -> Result<&mut Self, CharacterMutationError> {
         self.exaltation.unequip_armor()?;
+        self.correct_martial_arts_form();
         Ok(self)
     }
 }
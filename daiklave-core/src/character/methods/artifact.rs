@@ -53,8 +53,9 @@ impl<'view, 'source> Character<'source> {
                 self.exaltation.remove_wonder(wonder_name)?;
             }
         }
-        // May lose evocations along with the artifact
-        self.correct_evocations(&[]);
+        // Evocations of the artifact are disabled (not removed) until it is
+        // regained
+        self.correct_martial_arts_form();
         Ok(self)
     }
 
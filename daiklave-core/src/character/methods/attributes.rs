@@ -19,6 +19,13 @@ impl<'source> Character<'source> {
     ) -> Result<&mut Self, CharacterMutationError> {
         let old_dots = self.attributes().get(attribute_name).dots();
         self.attributes.set_dots(attribute_name, dots)?;
+        if attribute_name == AttributeName::Stamina
+            && old_dots != dots
+            && self.health_levels.ox_body_technique > 0
+        {
+            // Ox-Body Technique health levels depend on Stamina
+            self.rederive_health()?;
+        }
         if old_dots > dots {
             let sorcery_removed = if attribute_name == AttributeName::Intelligence {
                 self.exaltation.correct_sorcery_level(
@@ -1,4 +1,11 @@
-use crate::{abilities::AbilityError, craft::Craft, Character, CharacterMutationError};
+use crate::{
+    abilities::AbilityError,
+    artifact::AddArtifact,
+    craft::{Craft, CraftError, CraftProject, CraftProjectKind},
+    experience::ExperienceEntry,
+    extended_roll::ExtendedRollStatus,
+    Character, CharacterMutationError,
+};
 
 impl<'view, 'source> Character<'source> {
     /// The character's Craft abilities and specialties.
@@ -53,4 +60,116 @@ impl<'view, 'source> Character<'source> {
             .remove_specialty(specialty)?;
         Ok(self)
     }
+
+    /// The character's crafting projects, in the order they were started.
+    pub fn craft_projects(&self) -> impl Iterator<Item = &CraftProject> + '_ {
+        self.craft_projects.iter()
+    }
+
+    /// Gets a crafting project by name.
+    pub fn craft_project(&self, name: &str) -> Option<&CraftProject> {
+        self.craft_projects
+            .iter()
+            .find(|project| project.name.as_str() == name)
+    }
+
+    /// Begins a crafting project. The character must have dots in the
+    /// project's Craft focus.
+    pub fn start_craft_project(
+        &mut self,
+        project: &CraftProject,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        if self.craft_project(&project.name).is_some() {
+            return Err(CharacterMutationError::CraftError(
+                CraftError::DuplicateProject,
+            ));
+        }
+        if self.craft.dots(&project.focus) == 0 {
+            return Err(CharacterMutationError::CraftError(CraftError::NoCraftDots));
+        }
+        let mut project = project.clone();
+        project.extended_roll.rolls.clear();
+        self.craft_projects.push(project);
+        Ok(self)
+    }
+
+    /// Records the successes rolled for one interval of work on a project.
+    /// If this completes the project, its crafting experience is awarded.
+    pub fn roll_craft_project(
+        &mut self,
+        name: &str,
+        successes: u8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let project = self
+            .craft_projects
+            .iter_mut()
+            .find(|project| project.name.as_str() == name)
+            .ok_or(CharacterMutationError::CraftError(
+                CraftError::ProjectNotFound,
+            ))?;
+        let status = project.extended_roll.roll(successes, None).ok_or(
+            CharacterMutationError::CraftError(CraftError::ProjectFinished),
+        )?;
+
+        if status == ExtendedRollStatus::Completed {
+            let (pool, amount) = project.kind.experience_award(project.is_exceptional());
+            let entry = ExperienceEntry::gain(pool, amount)
+                .reason(format!("Completed {}", project.name))
+                .build()
+                .0;
+            self.experience.record(entry)?;
+        }
+        Ok(self)
+    }
+
+    /// Removes a crafting project, whatever its status.
+    pub fn abandon_craft_project(
+        &mut self,
+        name: &str,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let index = self
+            .craft_projects
+            .iter()
+            .position(|project| project.name.as_str() == name)
+            .ok_or(CharacterMutationError::CraftError(
+                CraftError::ProjectNotFound,
+            ))?;
+        self.craft_projects.remove(index);
+        Ok(self)
+    }
+
+    /// Turns a completed superior or legendary project into an artifact on
+    /// the character's sheet, removing the project. The artifact must be
+    /// rated the same as the project (N/A for legendary projects).
+    pub fn claim_crafted_artifact(
+        &mut self,
+        name: &str,
+        artifact: &'source AddArtifact,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let project = self
+            .craft_project(name)
+            .ok_or(CharacterMutationError::CraftError(
+                CraftError::ProjectNotFound,
+            ))?;
+        if !matches!(
+            project.kind,
+            CraftProjectKind::Superior(_) | CraftProjectKind::Legendary
+        ) {
+            return Err(CharacterMutationError::CraftError(
+                CraftError::NotAnArtifactProject,
+            ));
+        }
+        if project.extended_roll.status() != ExtendedRollStatus::Completed {
+            return Err(CharacterMutationError::CraftError(
+                CraftError::ProjectNotComplete,
+            ));
+        }
+        if !project.kind.creates_artifact_rated(artifact.merit_dots()) {
+            return Err(CharacterMutationError::CraftError(
+                CraftError::ArtifactRatingMismatch,
+            ));
+        }
+        self.add_artifact(artifact)?;
+        self.abandon_craft_project(name)
+    }
 }
@@ -1,7 +1,10 @@
 use std::num::NonZeroU8;
 
 use crate::{
-    exaltation::exalt::essence::{Essence, MotePoolName, UncommitMotes},
+    exaltation::exalt::essence::{
+        essence_for_experience, Essence, EssenceMismatch, MotePoolName, UncommitMotes,
+    },
+    experience::ExperiencePoolName,
     Character, CharacterMutationError,
 };
 
@@ -33,6 +36,18 @@ impl<'view, 'source> Character<'source> {
         Ok(self)
     }
 
+    /// Commits motes like [Character::commit_motes], but the commitment is
+    /// released automatically when the scene ends.
+    pub fn commit_scene_motes(
+        &mut self,
+        name: &'source str,
+        first: MotePoolName,
+        amount: NonZeroU8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.exaltation.commit_scene_motes(name, first, amount)?;
+        Ok(self)
+    }
+
     /// Recovers motes, moving them from spent to available. Will not uncommit
     /// motes.
     pub fn recover_motes(
@@ -55,7 +70,9 @@ impl<'view, 'source> Character<'source> {
 
     /// Changes the essence rating of the character to the specified value.
     /// This also uncommits all active effects and recovers all motes. If
-    /// the rating is decreased, may cause Charms or Sorcery to be lost.
+    /// the rating is decreased, may cause Charms or Sorcery to be lost. If
+    /// Essence progression is enabled and the new rating disagrees with
+    /// spent experience, essence_mismatch() will report it.
     pub fn set_essence_rating(
         &mut self,
         rating: NonZeroU8,
@@ -75,4 +92,61 @@ impl<'view, 'source> Character<'source> {
 
         Ok(self)
     }
+
+    /// True if the character's Essence rises automatically as they spend
+    /// experience.
+    pub fn essence_progression(&self) -> bool {
+        self.essence_progression
+    }
+
+    /// Opts in to (or out of) automatic Essence progression. When enabled,
+    /// an Exalt whose Essence is lower than their spent experience implies
+    /// is immediately raised to that rating.
+    pub fn set_essence_progression(
+        &mut self,
+        enabled: bool,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.essence_progression = enabled;
+        self.progress_essence();
+        Ok(self)
+    }
+
+    /// The Essence rating implied by the total normal and Exalt experience
+    /// the character has spent. None for mortals.
+    pub fn expected_essence_rating(&self) -> Option<NonZeroU8> {
+        self.essence()?;
+        let experience = self.experience();
+        let spent = experience
+            .pool(ExperiencePoolName::Base)
+            .spent()
+            .saturating_add(experience.pool(ExperiencePoolName::Exalt).spent());
+        Some(essence_for_experience(spent))
+    }
+
+    /// If Essence progression is enabled and the character's Essence rating
+    /// disagrees with their spent experience, returns both ratings.
+    pub fn essence_mismatch(&self) -> Option<EssenceMismatch> {
+        if !self.essence_progression {
+            return None;
+        }
+        let expected = self.expected_essence_rating()?;
+        let actual = NonZeroU8::new(self.essence()?.rating())?;
+        if expected == actual {
+            None
+        } else {
+            Some(EssenceMismatch { expected, actual })
+        }
+    }
+
+    /// If Essence progression is enabled, raises Essence to the rating
+    /// implied by spent experience, preserving commitments. Never lowers
+    /// Essence.
+    pub(crate) fn progress_essence(&mut self) {
+        if !self.essence_progression {
+            return;
+        }
+        if let Some(expected) = self.expected_essence_rating() {
+            self.exaltation.raise_essence_rating(expected);
+        }
+    }
 }
@@ -5,10 +5,10 @@ use crate::{
         armor_item::{artifact::ArtifactError, ArmorName},
         ArmorError,
     },
-    artifact::ArtifactName,
+    artifact::{ArtifactName, MagicMaterial, Sonance},
     charms::{
         charm::{
-            evocation::{AddEvocation, EvokableName},
+            evocation::{AddEvocation, EvocationKeyword, EvokableName},
             Charm, CharmName,
         },
         CharmError,
@@ -20,47 +20,158 @@ use crate::{
 };
 
 impl<'source> Character<'source> {
-    /// Adds an evocation to the character.
+    /// Adds an evocation to the character. The character must possess the
+    /// artifact or hearthstone it is drawn from, and be attuned to it if it
+    /// is an artifact which requires attunement. Exalts dissonant with the artifact's Magic Material
+    /// can only learn Evocations with the Dissonant keyword.
     pub fn add_evocation(
         &mut self,
         add_evocation: &'source AddEvocation,
     ) -> Result<&mut Self, CharacterMutationError> {
         let AddEvocation { name, evocation } = add_evocation;
-        match evocation.evokable_name() {
-            EvokableName::Hearthstone(hearthstone_id) => {
-                if self.hearthstones().get(hearthstone_id).is_none() {
+        let material = self.check_evokable(evocation.evokable_name())?;
+        if material.and_then(|material| self.sonance(material)) == Some(Sonance::Dissonant)
+            && !evocation
+                .keywords()
+                .any(|keyword| keyword == EvocationKeyword::Dissonant)
+        {
+            return Err(CharacterMutationError::CharmError(CharmError::Dissonant));
+        }
+
+        // Can't build on Evocations which are currently disabled
+        let disabled = self.disabled_evocations();
+        if evocation
+            .evocation_prerequisites()
+            .chain(match evocation.upgrade() {
+                Some(CharmName::Evocation(upgrade_evocation_name)) => Some(upgrade_evocation_name),
+                _ => None,
+            })
+            .any(|prerequisite_name| disabled.contains(prerequisite_name))
+        {
+            return Err(CharacterMutationError::CharmError(
+                CharmError::PrerequisitesNotMet,
+            ));
+        }
+
+        self.exaltation.add_evocation(name, evocation)?;
+        Ok(self)
+    }
+
+    /// Checks that the character has the artifact or hearthstone an Evocation
+    /// is drawn from, and is attuned to it if it is an artifact which requires
+    /// attunement. Returns the artifact's Magic Material, if it has one.
+    fn check_evokable(
+        &self,
+        evokable_name: EvokableName<'_>,
+    ) -> Result<Option<MagicMaterial>, CharacterMutationError> {
+        let (attuned, material) = match evokable_name {
+            EvokableName::Hearthstone(name) => {
+                if self.hearthstones().get(name).is_none() {
                     return Err(CharacterMutationError::HearthstoneError(
                         HearthstoneError::NotFound,
                     ));
                 }
+                (true, None)
             }
             EvokableName::Artifact(ArtifactName::Armor(name)) => {
-                if self.armor().get(ArmorName::Artifact(name)).is_none() {
-                    return Err(CharacterMutationError::ArmorError(ArmorError::NotFound));
-                }
+                let armor = self
+                    .armor()
+                    .get(ArmorName::Artifact(name))
+                    .ok_or(CharacterMutationError::ArmorError(ArmorError::NotFound))?;
+                (
+                    armor.is_attuned() || armor.attunement_cost().is_none(),
+                    armor.material(),
+                )
             }
             EvokableName::Artifact(ArtifactName::Weapon(name)) => {
-                if !self.weapons().iter().any(|(weapon_name, _)| {
-                    if let WeaponName::Artifact(actual_name) = weapon_name {
-                        actual_name == name
-                    } else {
-                        false
-                    }
-                }) {
-                    return Err(CharacterMutationError::WeaponError(WeaponError::NotFound));
-                }
+                let weapons = self.weapons();
+                let weapon = weapons
+                    .iter()
+                    .find_map(|(weapon_name, equipped)| match weapon_name {
+                        WeaponName::Artifact(actual_name) if actual_name == name => {
+                            weapons.get(weapon_name, equipped)
+                        }
+                        _ => None,
+                    })
+                    .ok_or(CharacterMutationError::WeaponError(WeaponError::NotFound))?;
+                (weapon.is_attuned(), weapon.material())
             }
             EvokableName::Artifact(ArtifactName::Wonder(name)) => {
-                if self.wonders().get(name).is_none() {
-                    return Err(CharacterMutationError::ArtifactError(
-                        ArtifactError::NotFound,
-                    ));
-                }
+                let wonder =
+                    self.wonders()
+                        .get(name)
+                        .ok_or(CharacterMutationError::ArtifactError(
+                            ArtifactError::NotFound,
+                        ))?;
+                (
+                    wonder.is_attuned() || wonder.attunement_cost().is_none(),
+                    wonder.material(),
+                )
             }
         };
 
-        self.exaltation.add_evocation(name, evocation)?;
-        Ok(self)
+        if attuned {
+            Ok(material)
+        } else {
+            Err(CharacterMutationError::CharmError(CharmError::NotAttuned))
+        }
+    }
+
+    /// The names of the Evocations the character knows but cannot currently
+    /// use, because they have lost (or unattuned from) the artifact or
+    /// hearthstone it is drawn from, or because an Evocation it builds on is
+    /// disabled.
+    pub(crate) fn disabled_evocations(&self) -> HashSet<&'source str> {
+        let evocations = if let Exaltation::Exalt(exalt) = &self.exaltation {
+            exalt.evocations.as_slice()
+        } else {
+            return HashSet::new();
+        };
+
+        let mut disabled = evocations
+            .iter()
+            .filter(|(_, evocation)| self.check_evokable(evocation.evokable_name()).is_err())
+            .map(|(name, _)| *name)
+            .collect::<HashSet<&str>>();
+
+        // Disabling an Evocation cascades down its tree
+        loop {
+            let cascaded = evocations
+                .iter()
+                .filter(|(name, evocation)| {
+                    !disabled.contains(name)
+                        && evocation
+                            .evocation_prerequisites()
+                            .chain(match evocation.upgrade() {
+                                Some(CharmName::Evocation(upgrade_evocation_name)) => {
+                                    Some(upgrade_evocation_name)
+                                }
+                                _ => None,
+                            })
+                            .any(|prerequisite_name| disabled.contains(prerequisite_name))
+                })
+                .map(|(name, _)| *name)
+                .collect::<Vec<&str>>();
+
+            if cascaded.is_empty() {
+                return disabled;
+            }
+            disabled.extend(cascaded);
+        }
+    }
+
+    /// The names of the Evocations the character knows and can currently use
+    /// from an artifact or hearthstone.
+    pub(crate) fn available_evocations(
+        &self,
+        evokable_name: EvokableName<'_>,
+    ) -> Vec<&'source str> {
+        let disabled = self.disabled_evocations();
+        self.exaltation
+            .evocations_of(evokable_name)
+            .into_iter()
+            .filter(|name| !disabled.contains(name))
+            .collect()
     }
 
     pub(crate) fn correct_evocations(&mut self, force_remove: &[&str]) -> bool {
@@ -90,35 +201,6 @@ impl<'source> Character<'source> {
             .fold(
                 HashSet::from_iter(force_remove.iter().map(|&s| s.to_owned())),
                 |mut ids_to_remove, (evocation_name, evocation)| {
-                    match evocation.evokable_name() {
-                        EvokableName::Hearthstone(name) => {
-                            if self.hearthstones().get(name).is_none() {
-                                ids_to_remove.insert(evocation_name.to_owned());
-                            }
-                        }
-                        EvokableName::Artifact(ArtifactName::Armor(name)) => {
-                            if self.armor().get(ArmorName::Artifact(name)).is_none() {
-                                ids_to_remove.insert(evocation_name.to_owned());
-                            }
-                        }
-                        EvokableName::Artifact(ArtifactName::Weapon(name)) => {
-                            if !self.weapons().iter().any(|(weapon_name, _)| {
-                                if let WeaponName::Artifact(artifact_name) = weapon_name {
-                                    artifact_name == name
-                                } else {
-                                    false
-                                }
-                            }) {
-                                ids_to_remove.insert(evocation_name.to_owned());
-                            }
-                        }
-                        EvokableName::Artifact(ArtifactName::Wonder(name)) => {
-                            if self.wonders().get(name).is_none() {
-                                ids_to_remove.insert(evocation_name.to_owned());
-                            }
-                        }
-                    };
-
                     if evocation.essence_required() > actual_essence {
                         ids_to_remove.insert(evocation_name.to_owned());
                     }
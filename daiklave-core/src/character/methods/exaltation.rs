@@ -24,6 +24,7 @@ impl<'source> Character<'source> {
             return Ok(self);
         }
         self.exaltation.set_mortal()?;
+        self.correct_martial_arts_form();
         self.set_willpower_rating(
             NonZeroU8::new(self.willpower.rating.get().max(2) - 2)
                 .unwrap_or(NonZeroU8::new(1).unwrap()),
@@ -1,26 +1,21 @@
 use std::num::NonZeroU16;
 
 use crate::{
-    exaltation::{
-        exalt::{essence::EssenceError, exalt_type::ExaltType},
-        Exaltation,
+    exaltation::{exalt::essence::EssenceError, Exaltation},
+    experience::{
+        AwardSessionExperience, Experience, ExperienceEntry, ExperienceEntryKind,
+        ExperiencePoolName,
     },
-    experience::Experience,
     Character, CharacterMutationError,
 };
 
 impl<'source> Character<'source> {
-    /// Get the experience pools for the character.
-    pub fn experience(&self) -> Experience {
-        let base = self.experience;
-        let exalt = match &self.exaltation {
-            Exaltation::Mortal(_) => None,
-            Exaltation::Exalt(exalt) => match &exalt.exalt_type {
-                ExaltType::Solar(solar) => Some(solar.experience()),
-            },
-        };
-
-        Experience { base, exalt }
+    /// Get the experience pools and ledger for the character.
+    pub fn experience(&self) -> Experience<'_> {
+        Experience {
+            ledger: &self.experience,
+            exalted: matches!(self.exaltation, Exaltation::Exalt(_)),
+        }
     }
 
     /// Add base/normal experience points to the character.
@@ -28,8 +23,11 @@ impl<'source> Character<'source> {
         &mut self,
         amount: NonZeroU16,
     ) -> Result<&mut Self, CharacterMutationError> {
-        self.experience.gain(amount);
-        Ok(self)
+        self.log_experience(
+            ExperienceEntry::gain(ExperiencePoolName::Base, amount)
+                .build()
+                .0,
+        )
     }
 
     /// Spend base/normal experience points.
@@ -37,8 +35,11 @@ impl<'source> Character<'source> {
         &mut self,
         amount: NonZeroU16,
     ) -> Result<&mut Self, CharacterMutationError> {
-        self.experience.spend(amount)?;
-        Ok(self)
+        self.log_experience(
+            ExperienceEntry::spend(ExperiencePoolName::Base, amount)
+                .build()
+                .0,
+        )
     }
 
     /// Add experience points to the character's Exalt experience pool.
@@ -46,17 +47,11 @@ impl<'source> Character<'source> {
         &mut self,
         amount: NonZeroU16,
     ) -> Result<&mut Self, CharacterMutationError> {
-        match &mut self.exaltation {
-            Exaltation::Mortal(_) => {
-                return Err(CharacterMutationError::EssenceError(EssenceError::Mortal));
-            }
-            Exaltation::Exalt(exalt) => match &mut exalt.exalt_type {
-                ExaltType::Solar(solar) => {
-                    solar.experience.gain(amount);
-                }
-            },
-        };
-        Ok(self)
+        self.log_experience(
+            ExperienceEntry::gain(ExperiencePoolName::Exalt, amount)
+                .build()
+                .0,
+        )
     }
 
     /// Spend experience points from the character's Exalt experience pool.
@@ -64,16 +59,68 @@ impl<'source> Character<'source> {
         &mut self,
         amount: NonZeroU16,
     ) -> Result<&mut Self, CharacterMutationError> {
-        match &mut self.exaltation {
-            Exaltation::Mortal(_) => {
+        self.log_experience(
+            ExperienceEntry::spend(ExperiencePoolName::Exalt, amount)
+                .build()
+                .0,
+        )
+    }
+
+    /// Records an entry in the character's experience ledger. If the entry
+    /// is linked to a mutation, that mutation is applied as well.
+    pub fn record_experience(
+        &mut self,
+        entry: &'source ExperienceEntry,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        // Check the entry is valid before applying the linked mutation, so
+        // that its error takes priority
+        self.check_experience(entry)?;
+        if let Some(linked) = entry.linked.as_deref() {
+            self.apply_mutation(linked)?;
+        }
+        self.log_experience(entry.clone())
+    }
+
+    /// Awards experience at the end of a session, recording a separate
+    /// ledger entry for each reason.
+    pub fn award_session_experience(
+        &mut self,
+        award: &AwardSessionExperience,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        for session_award in award.awards.iter() {
+            let entry = ExperienceEntry::gain(session_award.pool, session_award.amount)
+                .reason(session_award.reason.name())
+                .session(award.session);
+            let entry = if let Some(timestamp) = award.timestamp {
+                entry.at(timestamp)
+            } else {
+                entry
+            };
+            self.log_experience(entry.build().0)?;
+        }
+        Ok(self)
+    }
+
+    fn check_experience(&self, entry: &ExperienceEntry) -> Result<(), CharacterMutationError> {
+        if entry.pool == ExperiencePoolName::Exalt {
+            if let Exaltation::Mortal(_) = self.exaltation {
                 return Err(CharacterMutationError::EssenceError(EssenceError::Mortal));
             }
-            Exaltation::Exalt(exalt) => match &mut exalt.exalt_type {
-                ExaltType::Solar(solar) => {
-                    solar.experience.spend(amount)?;
-                }
-            },
-        };
+        }
+        self.experience.check(entry)?;
+        Ok(())
+    }
+
+    fn log_experience(
+        &mut self,
+        entry: ExperienceEntry,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.check_experience(&entry)?;
+        let spent = entry.kind == ExperienceEntryKind::Spend;
+        self.experience.record(entry)?;
+        if spent {
+            self.progress_essence();
+        }
         Ok(self)
     }
 }
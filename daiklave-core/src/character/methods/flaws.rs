@@ -24,7 +24,7 @@ impl<'view, 'source> Character<'source> {
         } = flaw;
 
         if let Entry::Vacant(e) = self.flaws.entry(name.as_str()) {
-            e.insert((*book_reference, description.as_str()));
+            e.insert((book_reference.clone(), description.as_str()));
             Ok(self)
         } else {
             Err(CharacterMutationError::MeritError(
@@ -1,7 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, num::NonZeroU16};
 
 use crate::{
-    health::{DamageLevel, Health, WoundPenalty},
+    attributes::AttributeName,
+    exaltation::Exaltation,
+    health::{
+        AddHealthLevels, DamageLevel, Health, HealthLevelSource, NaturalHealingReport, WoundPenalty,
+    },
     Character, CharacterMutationError,
 };
 
@@ -11,48 +15,86 @@ impl<'source> Character<'source> {
         &self.health
     }
 
-    /// Sets the character to have the specified number of each type of health
-    /// box.
+    /// Sets the character's base health track to have the specified number
+    /// of each type of health box. Health levels from other sources, such as
+    /// the Ox-Body Technique, are added on top of this.
     pub fn set_health_track(
         &mut self,
         new_wound_penalties: &HashMap<WoundPenalty, u8>,
     ) -> Result<&mut Self, CharacterMutationError> {
-        // Preserve existing damage if possible
-        let (bashing, lethal, aggravated) = (
+        self.health_levels.base = new_wound_penalties.clone();
+        self.rederive_health()
+    }
+
+    /// Every health box the character has and the source it came from,
+    /// ordered from left to right along the health track.
+    pub fn health_level_sources(&self) -> Vec<(HealthLevelSource, WoundPenalty)> {
+        self.health_levels.boxes(self.stamina())
+    }
+
+    /// Adds health levels from a source, preserving existing damage.
+    pub fn add_health_levels(
+        &mut self,
+        add_health_levels: &AddHealthLevels,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.health_levels.add(add_health_levels)?;
+        self.rederive_health()
+    }
+
+    /// Removes the health levels granted by a source (or a single purchase
+    /// of the Ox-Body Technique), preserving existing damage.
+    pub fn remove_health_levels(
+        &mut self,
+        source: &HealthLevelSource,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.health_levels.remove(source)?;
+        self.rederive_health()
+    }
+
+    fn stamina(&self) -> u8 {
+        self.attributes.get(AttributeName::Stamina).dots()
+    }
+
+    /// Recomputes the health track from its sources, preserving existing
+    /// damage if possible.
+    pub(crate) fn rederive_health(&mut self) -> Result<&mut Self, CharacterMutationError> {
+        let track = self.health_levels.track(self.stamina());
+        let (bashing, lethal, aggravated, healing_progress) = (
             self.health.bashing_damage,
             self.health.lethal_damage,
             self.health.aggravated_damage,
+            self.health.healing_progress,
         );
 
         self.health = Health {
-            zero_boxes: new_wound_penalties
-                .get(&WoundPenalty::Zero)
-                .cloned()
-                .unwrap_or_default(),
-            minus_one_boxes: new_wound_penalties
+            zero_boxes: track.get(&WoundPenalty::Zero).cloned().unwrap_or_default(),
+            minus_one_boxes: track
                 .get(&WoundPenalty::MinusOne)
                 .cloned()
                 .unwrap_or_default(),
-            minus_two_boxes: new_wound_penalties
+            minus_two_boxes: track
                 .get(&WoundPenalty::MinusTwo)
                 .cloned()
                 .unwrap_or_default(),
-            minus_four_boxes: new_wound_penalties
+            minus_four_boxes: track
                 .get(&WoundPenalty::MinusFour)
                 .cloned()
                 .unwrap_or_default(),
-            incapacitated_boxes: new_wound_penalties
+            incapacitated_boxes: track
                 .get(&WoundPenalty::Incapacitated)
                 .cloned()
                 .unwrap_or_default(),
             bashing_damage: 0,
             lethal_damage: 0,
             aggravated_damage: 0,
+            healing_progress: 0,
         };
 
-        self.take_damage(DamageLevel::Bashing, bashing)
-            .and_then(|character| character.take_damage(DamageLevel::Lethal, lethal))
-            .and_then(|character| character.take_damage(DamageLevel::Aggravated, aggravated))
+        self.take_damage(DamageLevel::Bashing, bashing)?
+            .take_damage(DamageLevel::Lethal, lethal)?
+            .take_damage(DamageLevel::Aggravated, aggravated)?;
+        self.health.healing_progress = healing_progress;
+        Ok(self)
     }
 
     /// Adds damage to character (including overflow rollovers). Caps out at
@@ -73,4 +115,26 @@ impl<'source> Character<'source> {
         self.health.heal_damage(amount)?;
         Ok(self)
     }
+
+    /// True if the character heals at the accelerated rate of the Exalted:
+    /// either they are Exalted, or they are a mortal with the Exalted
+    /// Healing merit.
+    pub fn has_exalted_healing(&self) -> bool {
+        match &self.exaltation {
+            Exaltation::Mortal(mortal) => mortal.exalted_healing,
+            Exaltation::Exalt(_) => true,
+        }
+    }
+
+    /// Heals the character naturally over the specified hours of rest,
+    /// returning the boxes healed and the time remaining to heal the rest.
+    pub fn heal_naturally(
+        &mut self,
+        hours: NonZeroU16,
+    ) -> Result<NaturalHealingReport, CharacterMutationError> {
+        let exalted_healing = self.has_exalted_healing();
+        Ok(self
+            .health
+            .heal_naturally(hours.get().into(), exalted_healing))
+    }
 }
@@ -390,8 +390,8 @@ impl<'view, 'source> Character<'source> {
             .ok_or(CharacterMutationError::HearthstoneError(
                 HearthstoneError::NotFound,
             ))?;
-        // May lose evocations along with the hearthstone
-        self.correct_evocations(&[]);
+        // Evocations of the hearthstone are disabled (not removed) until it
+        // is regained
         Ok(self)
     }
 }
@@ -2,7 +2,10 @@ use std::collections::hash_map::Entry;
 
 use crate::{
     intimacies::{
-        intimacy::{AddIntimacy, IntimacyError, RemoveIntimacy},
+        intimacy::{
+            AddIntimacy, ChangeTieEmotion, IntimacyChange, IntimacyChangeKind, IntimacyError,
+            IntimacyTypeMemo, RemoveIntimacy, StrengthenIntimacy, WeakenIntimacy,
+        },
         Intimacies,
     },
     Character, CharacterMutationError,
@@ -47,4 +50,89 @@ impl<'view, 'source> Character<'source> {
             ))
         }
     }
+
+    /// Iterates over every recorded change to the character's Intimacies,
+    /// oldest first.
+    pub fn intimacy_history(&self) -> impl Iterator<Item = &IntimacyChange> + '_ {
+        self.intimacy_history.iter()
+    }
+
+    /// Strengthens an Intimacy by one level, recording the cause.
+    pub fn strengthen_intimacy(
+        &mut self,
+        strengthen: &'source StrengthenIntimacy,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let level = self.intimacies.get_mut(&strengthen.intimacy_type).ok_or(
+            CharacterMutationError::IntimacyError(IntimacyError::NotFound),
+        )?;
+        let from = *level;
+        let to = from
+            .stronger()
+            .ok_or(CharacterMutationError::IntimacyError(
+                IntimacyError::AlreadyDefining,
+            ))?;
+        *level = to;
+        self.intimacy_history.push(IntimacyChange {
+            intimacy_type: strengthen.intimacy_type.clone(),
+            kind: IntimacyChangeKind::Strengthened { from, to },
+            cause: strengthen.cause.clone(),
+        });
+        Ok(self)
+    }
+
+    /// Weakens an Intimacy by one level, recording the cause. A Minor
+    /// Intimacy is removed.
+    pub fn weaken_intimacy(
+        &mut self,
+        weaken: &'source WeakenIntimacy,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let from = *self.intimacies.get(&weaken.intimacy_type).ok_or(
+            CharacterMutationError::IntimacyError(IntimacyError::NotFound),
+        )?;
+        let to = from.weaker();
+        if let Some(to) = to {
+            self.intimacies.insert(&weaken.intimacy_type, to);
+        } else {
+            self.intimacies.remove(&weaken.intimacy_type);
+        }
+        self.intimacy_history.push(IntimacyChange {
+            intimacy_type: weaken.intimacy_type.clone(),
+            kind: IntimacyChangeKind::Weakened { from, to },
+            cause: weaken.cause.clone(),
+        });
+        Ok(self)
+    }
+
+    /// Changes the emotional context of a Tie, keeping its level and
+    /// recording the cause.
+    pub fn change_tie_emotion(
+        &mut self,
+        change: &'source ChangeTieEmotion,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let IntimacyTypeMemo::Tie(_, old_emotion) = &change.from else {
+            return Err(CharacterMutationError::IntimacyError(
+                IntimacyError::NotATie,
+            ));
+        };
+        if change.from != change.to && self.intimacies.contains_key(&change.to) {
+            return Err(CharacterMutationError::IntimacyError(
+                IntimacyError::DuplicateIntimacy,
+            ));
+        }
+        let level =
+            self.intimacies
+                .remove(&change.from)
+                .ok_or(CharacterMutationError::IntimacyError(
+                    IntimacyError::NotFound,
+                ))?;
+        self.intimacies.insert(&change.to, level);
+        self.intimacy_history.push(IntimacyChange {
+            intimacy_type: change.to.clone(),
+            kind: IntimacyChangeKind::EmotionChanged {
+                from: old_emotion.clone(),
+            },
+            cause: change.cause.clone(),
+        });
+        Ok(self)
+    }
 }
@@ -0,0 +1,78 @@
+use std::num::NonZeroU8;
+
+use crate::{
+    lifecycle::{LifecycleReport, FULL_NIGHT_HOURS, MOTES_PER_HOUR_RESTING},
+    Character, CharacterMutationError,
+};
+
+impl<'source> Character<'source> {
+    /// Ends the current scene, releasing all scene-length mote commitments.
+    /// Any sorcerous motes being shaped toward a spell vanish, and a Limit
+    /// Break with a scene-long Virtue Flaw ends.
+    pub fn end_scene(&mut self) -> Result<LifecycleReport, CharacterMutationError> {
+        Ok(LifecycleReport {
+            released_commitments: self
+                .exaltation
+                .release_scene_commitments()
+                .into_iter()
+                .map(|name| name.to_owned())
+                .collect(),
+            sorcerous_motes_lost: self.spell_shaping.take().map_or(0, |shaping| shaping.motes),
+            limit_break_ended: self.limit_mut().is_some_and(|limit| limit.end_scene()),
+            ..Default::default()
+        })
+    }
+
+    /// Rests for the specified number of hours, recovering motes. A full
+    /// night's rest also restores one Willpower, if the character is below
+    /// their permanent rating, and counts as a day toward the end of a Limit
+    /// Break.
+    pub fn rest(&mut self, hours: NonZeroU8) -> Result<LifecycleReport, CharacterMutationError> {
+        let mut report = LifecycleReport {
+            motes_recovered: self
+                .exaltation
+                .respire_motes(hours.get().saturating_mul(MOTES_PER_HOUR_RESTING)),
+            ..Default::default()
+        };
+        if hours.get() >= FULL_NIGHT_HOURS {
+            if self.willpower.current < self.willpower.rating.get() {
+                self.willpower.current += 1;
+                report.willpower_recovered = 1;
+            }
+            report.limit_break_ended = self.limit_mut().is_some_and(|limit| limit.end_day());
+        }
+        Ok(report)
+    }
+
+    /// Ends the current session, which also ends the scene. Reduces the
+    /// character's Limit by one, if they have any and it hasn't broken.
+    pub fn end_session(&mut self) -> Result<LifecycleReport, CharacterMutationError> {
+        let mut report = self.end_scene()?;
+        if self
+            .limit()
+            .is_some_and(|limit| limit.track() > 0 && !limit.is_broken())
+        {
+            self.reduce_limit(NonZeroU8::new(1).unwrap())?;
+            report.limit_reduced = 1;
+        }
+        Ok(report)
+    }
+
+    /// Ends the current story, which also ends the session. Recovers all
+    /// spent motes and restores Willpower to the character's permanent
+    /// rating. Willpower above the rating is kept.
+    pub fn end_story(&mut self) -> Result<LifecycleReport, CharacterMutationError> {
+        let mut report = self.end_session()?;
+        report.merge(LifecycleReport {
+            motes_recovered: self.exaltation.respire_motes(u8::MAX),
+            willpower_recovered: self
+                .willpower
+                .rating
+                .get()
+                .saturating_sub(self.willpower.current),
+            ..Default::default()
+        });
+        self.willpower.current = self.willpower.current.max(self.willpower.rating.get());
+        Ok(report)
+    }
+}
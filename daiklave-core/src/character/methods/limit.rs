@@ -2,7 +2,7 @@ use std::num::NonZeroU8;
 
 use crate::{
     exaltation::{
-        exalt::{essence::EssenceError, Limit},
+        exalt::{essence::EssenceError, limit::VirtueFlaw, Limit},
         Exaltation,
     },
     Character, CharacterMutationError,
@@ -17,7 +17,15 @@ impl<'source> Character<'source> {
         }
     }
 
-    /// Adds Limit to the Exalt's Limit track, to a maximum of 10.
+    pub(crate) fn limit_mut(&mut self) -> Option<&mut Limit<'source>> {
+        match &mut self.exaltation {
+            Exaltation::Mortal(_) => None,
+            Exaltation::Exalt(exalt) => exalt.exalt_type.limit_mut(),
+        }
+    }
+
+    /// Adds Limit to the Exalt's Limit track, to a maximum of 10. Reaching 10
+    /// triggers a Limit Break, and the Exalt falls into their Virtue Flaw.
     pub fn gain_limit(&mut self, amount: NonZeroU8) -> Result<&mut Self, CharacterMutationError> {
         match &mut self.exaltation {
             Exaltation::Mortal(_) => {
@@ -34,18 +42,24 @@ impl<'source> Character<'source> {
         }
     }
 
-    /// Removes Limit from the Exalt's Limit track, to a minimum of 0.
+    /// Removes Limit from the Exalt's Limit track, to a minimum of 0. Limit
+    /// can't be reduced during a Limit Break.
     pub fn reduce_limit(&mut self, amount: NonZeroU8) -> Result<&mut Self, CharacterMutationError> {
         match &mut self.exaltation {
             Exaltation::Mortal(_) => {
                 Err(CharacterMutationError::EssenceError(EssenceError::Mortal))
             }
             Exaltation::Exalt(exalt) => {
-                exalt
+                let limit = exalt
                     .exalt_type
                     .limit_mut()
-                    .ok_or(CharacterMutationError::EssenceError(EssenceError::NoLimit))?
-                    .remove_limit(amount);
+                    .ok_or(CharacterMutationError::EssenceError(EssenceError::NoLimit))?;
+                if limit.is_broken() {
+                    return Err(CharacterMutationError::EssenceError(
+                        EssenceError::InLimitBreak,
+                    ));
+                }
+                limit.remove_limit(amount);
                 Ok(self)
             }
         }
@@ -70,4 +84,51 @@ impl<'source> Character<'source> {
             }
         }
     }
+
+    /// Sets the Solar's Virtue Flaw, the behavior they fall into when their
+    /// Limit breaks.
+    pub fn set_virtue_flaw(
+        &mut self,
+        virtue_flaw: &'source VirtueFlaw,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        match &mut self.exaltation {
+            Exaltation::Mortal(_) => {
+                Err(CharacterMutationError::EssenceError(EssenceError::Mortal))
+            }
+            Exaltation::Exalt(exalt) => {
+                exalt
+                    .exalt_type
+                    .limit_mut()
+                    .ok_or(CharacterMutationError::EssenceError(EssenceError::NoLimit))?
+                    .set_virtue_flaw(virtue_flaw);
+                Ok(self)
+            }
+        }
+    }
+
+    /// Ends the Exalt's Limit Break once their Virtue Flaw's resolution
+    /// condition is met, resetting the Limit track to 0. Virtue Flaws which
+    /// last a scene or a number of days also end on their own, when the
+    /// scene ends or after enough full nights of rest.
+    pub fn resolve_limit_break(&mut self) -> Result<&mut Self, CharacterMutationError> {
+        match &mut self.exaltation {
+            Exaltation::Mortal(_) => {
+                Err(CharacterMutationError::EssenceError(EssenceError::Mortal))
+            }
+            Exaltation::Exalt(exalt) => {
+                if exalt
+                    .exalt_type
+                    .limit_mut()
+                    .ok_or(CharacterMutationError::EssenceError(EssenceError::NoLimit))?
+                    .resolve_limit_break()
+                {
+                    Ok(self)
+                } else {
+                    Err(CharacterMutationError::EssenceError(
+                        EssenceError::NotInLimitBreak,
+                    ))
+                }
+            }
+        }
+    }
 }
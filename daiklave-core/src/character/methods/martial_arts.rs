@@ -1,12 +1,21 @@
 use crate::{
-    martial_arts::{charm::AddMartialArtsCharm, style::AddMartialArtsStyle, MartialArts},
+    charms::charm::Charm,
+    martial_arts::{
+        charm::{AddMartialArtsCharm, MartialArtsCharmKeyword},
+        style::AddMartialArtsStyle,
+        MartialArts, MartialArtsError,
+    },
+    weapons::weapon::{mundane::unarmed, Equipped},
     Character, CharacterMutationError,
 };
 
 impl<'view, 'source> Character<'source> {
     /// Accesses Martial Arts styles, abilities, and Charms.
     pub fn martial_arts(&'view self) -> MartialArts<'view, 'source> {
-        MartialArts(&self.exaltation)
+        MartialArts(
+            &self.exaltation,
+            self.martial_arts_form.as_ref().map(|name| name.as_str()),
+        )
     }
 
     /// Adds a Martial Arts style to the character.
@@ -28,6 +37,7 @@ impl<'view, 'source> Character<'source> {
         name: &str,
     ) -> Result<&mut Self, CharacterMutationError> {
         self.exaltation.remove_martial_arts_style(name)?;
+        self.correct_martial_arts_form();
         Ok(self)
     }
 
@@ -77,7 +87,9 @@ impl<'view, 'source> Character<'source> {
     }
 
     pub(crate) fn correct_martial_arts_charms(&mut self, force_remove: &[&str]) -> bool {
-        self.exaltation.correct_martial_arts_charms(force_remove)
+        let removed = self.exaltation.correct_martial_arts_charms(force_remove);
+        self.correct_martial_arts_form();
+        removed
     }
 
     /// Removes a Martial Arts Charm from the character.
@@ -87,10 +99,96 @@ impl<'view, 'source> Character<'source> {
     ) -> Result<&mut Self, CharacterMutationError> {
         self.exaltation
             .remove_martial_arts_charm(martial_arts_charm_name)?;
+        self.correct_martial_arts_form();
 
         // Evocations may be upgrades to Martial Arts Charms
         // Removing a Martial Arts charm may force removal of an Evocation
         self.correct_evocations(&[]);
         Ok(self)
     }
+
+    /// Activates a Martial Arts Form Charm, ending any other active Form.
+    /// The character's equipped weapons and worn armor must be allowed by
+    /// the Form's style.
+    pub fn activate_martial_arts_form(
+        &mut self,
+        name: &str,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let charm = match self.exaltation.get_martial_arts_charm(name) {
+            Some(Charm::MartialArts(charm)) => charm,
+            _ => {
+                return Err(CharacterMutationError::MartialArtsError(
+                    MartialArtsError::CharmNotFound,
+                ))
+            }
+        };
+        if !charm
+            .keywords()
+            .any(|keyword| keyword == MartialArtsCharmKeyword::Form)
+        {
+            return Err(CharacterMutationError::MartialArtsError(
+                MartialArtsError::NotAForm,
+            ));
+        }
+        self.check_martial_arts_equipment(charm.style())?;
+        self.martial_arts_form = Some(name.into());
+        Ok(self)
+    }
+
+    /// Ends the character's active Martial Arts Form.
+    pub fn end_martial_arts_form(&mut self) -> Result<&mut Self, CharacterMutationError> {
+        if self.martial_arts_form.take().is_none() {
+            return Err(CharacterMutationError::MartialArtsError(
+                MartialArtsError::NoActiveForm,
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Checks that the character's worn armor and weapons held in hand (or
+    /// wielded unarmed, if none) are allowed by a Martial Arts style.
+    fn check_martial_arts_equipment(&self, style_name: &str) -> Result<(), CharacterMutationError> {
+        let style = self.martial_arts().style(style_name).ok_or(
+            CharacterMutationError::MartialArtsError(MartialArtsError::StyleNotFound),
+        )?;
+        if !style.allows_armor(self.armor().worn().map(|armor| armor.weight_class())) {
+            return Err(CharacterMutationError::MartialArtsError(
+                MartialArtsError::ArmorNotAllowed,
+            ));
+        }
+        let weapons = self.weapons();
+        let mut in_hand = weapons
+            .iter()
+            .filter(|(_, equipped)| {
+                matches!(
+                    equipped,
+                    Some(Equipped::MainHand | Equipped::OffHand | Equipped::TwoHanded)
+                )
+            })
+            .filter_map(|(weapon_name, equipped)| weapons.get(weapon_name, equipped))
+            .peekable();
+        let allowed = if in_hand.peek().is_none() {
+            style.allows_weapon(&unarmed())
+        } else {
+            in_hand.all(|weapon| style.allows_weapon(&weapon))
+        };
+        if !allowed {
+            return Err(CharacterMutationError::MartialArtsError(
+                MartialArtsError::WeaponNotAllowed,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ends the active Form if its Charm has been lost, or if the character
+    /// is no longer using weapons and armor allowed by its style.
+    pub(crate) fn correct_martial_arts_form(&mut self) {
+        let still_valid = self
+            .martial_arts()
+            .active_form()
+            .is_some_and(|form| self.check_martial_arts_equipment(form.style()).is_ok());
+        if !still_valid {
+            self.martial_arts_form = None;
+        }
+    }
 }
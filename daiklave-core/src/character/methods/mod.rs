@@ -15,11 +15,16 @@ mod health;
 mod hearthstones;
 mod intimacies;
 mod languages;
+mod lifecycle;
 mod limit;
 mod martial_arts;
 mod merits;
 mod name;
+mod shaping;
+mod social;
 mod solar;
+mod sorcerous_working;
 mod sorcery;
+mod stunt;
 mod weapons;
 mod willpower;
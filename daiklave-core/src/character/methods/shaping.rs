@@ -0,0 +1,134 @@
+use crate::{
+    abilities::AbilityNameVanilla,
+    attributes::AttributeName,
+    sorcery::{
+        spell::{SpellMotesCost, SpellName},
+        SorceryCircle, SorceryError, SpellShaping,
+    },
+    Character, CharacterMutationError,
+};
+
+impl<'source> Character<'source> {
+    /// The dice pool for a shape sorcery action: Intelligence + Occult.
+    pub fn shape_sorcery_pool(&self) -> u8 {
+        self.attributes().get(AttributeName::Intelligence).dots()
+            + self
+                .abilities()
+                .get_vanilla(AbilityNameVanilla::Occult)
+                .dots()
+    }
+
+    /// Banks the successes of a shape sorcery action as sorcerous motes
+    /// toward a spell, plus the bonus motes of a shaping ritual if its
+    /// conditions were met. Shaping a different spell discards the motes
+    /// banked toward the previous one.
+    pub fn shape_sorcery(
+        &mut self,
+        spell: &SpellName,
+        successes: u8,
+        shaping_ritual: Option<SorceryCircle>,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let sorcery = self.sorcery().ok_or(CharacterMutationError::SorceryError(
+            SorceryError::SpellNotFound,
+        ))?;
+        let (known_spell, _) =
+            sorcery
+                .spells()
+                .get(spell)
+                .ok_or(CharacterMutationError::SorceryError(
+                    SorceryError::SpellNotFound,
+                ))?;
+        if known_spell.costs().motes_cost == SpellMotesCost::Ritual {
+            return Err(CharacterMutationError::SorceryError(
+                SorceryError::RitualSpell,
+            ));
+        }
+        let bonus_motes = if let Some(circle) = shaping_ritual {
+            sorcery
+                .shaping_ritual(circle)
+                .ok_or(CharacterMutationError::SorceryError(
+                    SorceryError::MissingShapingRitual,
+                ))?
+                .bonus_motes()
+        } else {
+            0
+        };
+
+        let banked = self
+            .spell_shaping
+            .as_ref()
+            .filter(|shaping| &shaping.spell == spell)
+            .map_or(0, |shaping| shaping.motes);
+        self.spell_shaping = Some(SpellShaping {
+            spell: spell.clone(),
+            motes: banked.saturating_add(successes).saturating_add(bonus_motes),
+        });
+        Ok(self)
+    }
+
+    /// Resolves a distraction while shaping. If the successes are less than
+    /// the difficulty, all banked sorcerous motes are lost.
+    pub fn distract_shaping(
+        &mut self,
+        difficulty: u8,
+        successes: u8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        if self.spell_shaping.is_none() {
+            return Err(CharacterMutationError::SorceryError(
+                SorceryError::NotShaping,
+            ));
+        }
+        if successes < difficulty {
+            self.spell_shaping = None;
+        }
+        Ok(self)
+    }
+
+    /// Interrupts shaping, losing all banked sorcerous motes.
+    pub fn interrupt_shaping(&mut self) -> Result<&mut Self, CharacterMutationError> {
+        if self.spell_shaping.take().is_none() {
+            return Err(CharacterMutationError::SorceryError(
+                SorceryError::NotShaping,
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Casts the spell being shaped, spending its Willpower cost. The
+    /// character must have banked at least as many sorcerous motes as the
+    /// spell requires; any excess motes are lost.
+    pub fn cast_spell(&mut self, spell: &SpellName) -> Result<&mut Self, CharacterMutationError> {
+        let motes = self
+            .spell_shaping
+            .as_ref()
+            .filter(|shaping| &shaping.spell == spell)
+            .ok_or(CharacterMutationError::SorceryError(
+                SorceryError::NotShaping,
+            ))?
+            .motes;
+        let cost = self
+            .sorcery()
+            .and_then(|sorcery| sorcery.spells().get(spell))
+            .ok_or(CharacterMutationError::SorceryError(
+                SorceryError::SpellNotFound,
+            ))?
+            .0
+            .costs();
+        if let SpellMotesCost::SorcerousMotes(required) = cost.motes_cost {
+            if motes < required.get() {
+                return Err(CharacterMutationError::SorceryError(
+                    SorceryError::InsufficientSorcerousMotes,
+                ));
+            }
+        }
+        if self.willpower.current < cost.willpower_cost.get() {
+            return Err(CharacterMutationError::SorceryError(
+                SorceryError::InsufficientWillpower,
+            ));
+        }
+
+        self.spend_willpower(cost.willpower_cost)?;
+        self.spell_shaping = None;
+        Ok(self)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{
+    abilities::AbilityNameVanilla, attributes::AttributeName, combat::attack::wound_penalty_dice,
+    Character,
+};
+
+impl<'source> Character<'source> {
+    /// The character's Resolve: (Wits + Integrity) / 2, rounded up, less
+    /// wound penalties.
+    pub fn resolve(&self) -> u8 {
+        self.social_defense(AttributeName::Wits, AbilityNameVanilla::Integrity)
+    }
+
+    /// The character's Guile: (Manipulation + Socialize) / 2, rounded up,
+    /// less wound penalties.
+    pub fn guile(&self) -> u8 {
+        self.social_defense(AttributeName::Manipulation, AbilityNameVanilla::Socialize)
+    }
+
+    fn social_defense(&self, attribute: AttributeName, ability: AbilityNameVanilla) -> u8 {
+        let pool =
+            self.attributes().get(attribute).dots() + self.abilities().get_vanilla(ability).dots();
+        pool.div_ceil(2)
+            .saturating_sub(wound_penalty_dice(self.health().current_wound_penalty()))
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    sorcery::{SorcerousWorking, SorceryError},
+    Character, CharacterMutationError,
+};
+
+impl<'source> Character<'source> {
+    /// Begins a sorcerous working. The character must have been initiated
+    /// into the working's circle of sorcery.
+    pub fn start_sorcerous_working(
+        &mut self,
+        working: &SorcerousWorking,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        if self
+            .sorcerous_workings
+            .iter()
+            .any(|existing| existing.name == working.name)
+        {
+            return Err(CharacterMutationError::SorceryError(
+                SorceryError::DuplicateWorking,
+            ));
+        }
+        if self
+            .sorcery()
+            .and_then(|sorcery| sorcery.shaping_ritual(working.circle))
+            .is_none()
+        {
+            return Err(CharacterMutationError::SorceryError(
+                SorceryError::MissingCircle,
+            ));
+        }
+        let mut working = working.clone();
+        working.extended_roll.rolls.clear();
+        self.sorcerous_workings.push(working);
+        Ok(self)
+    }
+
+    /// Records the roll for one interval of work on a sorcerous working. A
+    /// botch destroys all progress made so far, and records its consequence.
+    pub fn roll_sorcerous_working(
+        &mut self,
+        name: &str,
+        successes: u8,
+        botch: Option<&str>,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let working = self
+            .sorcerous_workings
+            .iter_mut()
+            .find(|working| working.name.as_str() == name)
+            .ok_or(CharacterMutationError::SorceryError(
+                SorceryError::WorkingNotFound,
+            ))?;
+        working
+            .extended_roll
+            .roll(successes, botch.map(|consequence| consequence.to_owned()))
+            .ok_or(CharacterMutationError::SorceryError(
+                SorceryError::WorkingFinished,
+            ))?;
+        Ok(self)
+    }
+
+    /// Removes a sorcerous working, whatever its status.
+    pub fn abandon_sorcerous_working(
+        &mut self,
+        name: &str,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let index = self
+            .sorcerous_workings
+            .iter()
+            .position(|working| working.name.as_str() == name)
+            .ok_or(CharacterMutationError::SorceryError(
+                SorceryError::WorkingNotFound,
+            ))?;
+        self.sorcerous_workings.remove(index);
+        Ok(self)
+    }
+}
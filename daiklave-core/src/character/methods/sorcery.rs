@@ -16,7 +16,13 @@ use crate::{
 impl<'view, 'source> Character<'source> {
     /// The character's Sorcery abilities, if any.
     pub fn sorcery(&'view self) -> Option<Sorcery<'view, 'source>> {
-        self.exaltation.sorcery()
+        self.exaltation.sorcery().map(|sorcery| {
+            Sorcery(
+                sorcery.0,
+                self.spell_shaping.as_ref(),
+                &self.sorcerous_workings,
+            )
+        })
     }
 
     /// If the character was not already a sorcerer, adds the first circle of
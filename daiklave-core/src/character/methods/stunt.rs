@@ -0,0 +1,15 @@
+use std::num::NonZeroU8;
+
+use crate::{stunt::StuntLevel, Character, CharacterMutationError};
+
+impl<'source> Character<'source> {
+    /// Awards a stunt to the character, restoring Willpower for two- and
+    /// three-point stunts. Returns the bonus dice the stunt grants to the
+    /// stunted action.
+    pub fn award_stunt(&mut self, level: StuntLevel) -> Result<u8, CharacterMutationError> {
+        if let Some(willpower) = NonZeroU8::new(level.willpower()) {
+            self.gain_willpower(willpower)?;
+        }
+        Ok(level.bonus_dice())
+    }
+}
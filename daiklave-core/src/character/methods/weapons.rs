@@ -43,6 +43,7 @@ impl<'view, 'source> Character<'source> {
     ) -> Result<&mut Self, CharacterMutationError> {
         self.check_equip_weapon(name, hand)?;
         self.exaltation.equip_weapon(name, hand)?;
+        self.correct_martial_arts_form();
         Ok(self)
     }
 
@@ -89,6 +90,7 @@ impl<'view, 'source> Character<'source> {
         equipped: Equipped,
     ) -> Result<&mut Self, CharacterMutationError> {
         self.exaltation.unequip_weapon(weapon_name, equipped)?;
+        self.correct_martial_arts_form();
         Ok(self)
     }
 
@@ -98,6 +100,7 @@ impl<'view, 'source> Character<'source> {
         weapon_name: &'view str,
     ) -> Result<&mut Self, CharacterMutationError> {
         self.exaltation.remove_mundane_weapon(weapon_name)?;
+        self.correct_martial_arts_form();
         Ok(self)
     }
 }
@@ -24,24 +24,29 @@ use crate::{
     abilities::AbilitiesVanilla,
     attributes::Attributes,
     book_reference::BookReference,
-    craft::Craft,
+    craft::{Craft, CraftProject},
     exaltation::Exaltation,
-    experience::ExperiencePool,
-    health::Health,
+    experience::{ExperienceLedger, ExperiencePoolName, RecordExperience},
+    health::{Health, HealthLevels},
     hearthstones::{hearthstone::GeomancyLevel, UnslottedHearthstone},
-    intimacies::intimacy::{IntimacyLevel, IntimacyTypeMemo},
+    intimacies::intimacy::{IntimacyChange, IntimacyLevel, IntimacyTypeMemo},
     languages::language::LanguageMutation,
+    martial_arts::charm::MartialArtsCharmName,
     merits::merit::{NonStackableMeritInstance, StackableMeritInstance},
+    sorcery::{SorcerousWorking, SpellShaping},
     willpower::Willpower,
 };
 
 use self::mutation::{
-    AttuneArtifact, CommitMotes, EquipArmor, EquipWeapon, GainExaltExperience, GainExperience,
-    GainLimit, GainWillpower, HealDamage, RecoverMotes, ReduceLimit, RemoveCharm, RemoveFlaw,
-    RemoveMundaneArmor, RemoveMundaneWeapon, SetAttribute, SetConcept, SetEssenceRating,
-    SetHealthTrack, SetLimitTrigger, SetName, SetWillpowerRating, SlotHearthstone,
-    SpendExaltExperience, SpendExperience, SpendMotes, SpendWillpower, TakeDamage, UnequipWeapon,
-    UnslotHearthstone,
+    AbandonCraftProject, AbandonSorcerousWorking, ActivateMartialArtsForm, AttuneArtifact,
+    CastSpell, ClaimCraftedArtifact, CommitMotes, CommitSceneMotes, DistractShaping, EquipArmor,
+    EquipWeapon, GainExaltExperience, GainExperience, GainLimit, GainWillpower, HealDamage,
+    HealNaturally, RecoverMotes, ReduceLimit, RemoveCharm, RemoveFlaw, RemoveHealthLevels,
+    RemoveMundaneArmor, RemoveMundaneWeapon, RollCraftProject, RollSorcerousWorking, SetAttribute,
+    SetConcept, SetEssenceProgression, SetEssenceRating, SetHealthTrack, SetLimitTrigger, SetName,
+    SetVirtueFlaw, SetWillpowerRating, ShapeSorcery, SlotHearthstone, SpendExaltExperience,
+    SpendExperience, SpendMotes, SpendWillpower, StartCraftProject, StartSorcerousWorking,
+    TakeDamage, UnequipWeapon, UnslotHearthstone,
 };
 
 /// A borrowed instance of a Character which references a CharacterEventSource
@@ -53,6 +58,7 @@ pub struct Character<'source> {
     pub(crate) exaltation: Exaltation<'source>,
     pub(crate) willpower: Willpower,
     pub(crate) health: Health,
+    pub(crate) health_levels: HealthLevels,
     pub(crate) attributes: Attributes,
     pub(crate) abilities: AbilitiesVanilla<'source>,
     pub(crate) craft: Craft<'source>,
@@ -65,7 +71,13 @@ pub struct Character<'source> {
     pub(crate) native_language: &'source LanguageMutation,
     pub(crate) other_languages: HashSet<&'source LanguageMutation>,
     pub(crate) intimacies: HashMap<&'source IntimacyTypeMemo, IntimacyLevel>,
-    pub(crate) experience: ExperiencePool,
+    pub(crate) intimacy_history: Vec<IntimacyChange>,
+    pub(crate) essence_progression: bool,
+    pub(crate) craft_projects: Vec<CraftProject>,
+    pub(crate) spell_shaping: Option<SpellShaping>,
+    pub(crate) sorcerous_workings: Vec<SorcerousWorking>,
+    pub(crate) martial_arts_form: Option<MartialArtsCharmName>,
+    pub(crate) experience: ExperienceLedger,
 }
 
 impl<'source> Character<'source> {
@@ -90,11 +102,19 @@ impl<'source> Character<'source> {
                 first,
                 amount,
             }) => self.commit_motes(effect_name.as_str(), *first, *amount),
+            CharacterMutation::CommitSceneMotes(CommitSceneMotes(CommitMotes {
+                effect_name,
+                first,
+                amount,
+            })) => self.commit_scene_motes(effect_name.as_str(), *first, *amount),
             CharacterMutation::RecoverMotes(RecoverMotes(amount)) => self.recover_motes(*amount),
             CharacterMutation::UncommitMotes(uncommit_motes) => self.uncommit_motes(uncommit_motes),
             CharacterMutation::SetEssenceRating(SetEssenceRating(rating)) => {
                 self.set_essence_rating(*rating)
             }
+            CharacterMutation::SetEssenceProgression(SetEssenceProgression(enabled)) => {
+                self.set_essence_progression(*enabled)
+            }
             CharacterMutation::SetWillpowerRating(SetWillpowerRating(dots)) => {
                 self.set_willpower_rating(*dots)
             }
@@ -159,6 +179,10 @@ impl<'source> Character<'source> {
             CharacterMutation::SetLimitTrigger(SetLimitTrigger(trigger)) => {
                 self.set_limit_trigger(trigger)
             }
+            CharacterMutation::SetVirtueFlaw(SetVirtueFlaw(virtue_flaw)) => {
+                self.set_virtue_flaw(virtue_flaw)
+            }
+            CharacterMutation::ResolveLimitBreak => self.resolve_limit_break(),
             CharacterMutation::GainExperience(GainExperience(amount)) => {
                 self.gain_base_experience(*amount)
             }
@@ -171,7 +195,51 @@ impl<'source> Character<'source> {
             CharacterMutation::SpendExaltExperience(SpendExaltExperience(amount)) => {
                 self.spend_exalt_experience(*amount)
             }
+            CharacterMutation::RecordExperience(RecordExperience(entry)) => {
+                self.record_experience(entry)
+            }
+            CharacterMutation::AwardSessionExperience(award_session_experience) => {
+                self.award_session_experience(award_session_experience)
+            }
+            CharacterMutation::StartCraftProject(StartCraftProject(project)) => {
+                self.start_craft_project(project)
+            }
+            CharacterMutation::RollCraftProject(RollCraftProject { name, successes }) => {
+                self.roll_craft_project(name, *successes)
+            }
+            CharacterMutation::AbandonCraftProject(AbandonCraftProject(name)) => {
+                self.abandon_craft_project(name)
+            }
+            CharacterMutation::ClaimCraftedArtifact(ClaimCraftedArtifact { project, artifact }) => {
+                self.claim_crafted_artifact(project, artifact)
+            }
             CharacterMutation::RemoveSorcery => self.remove_sorcery(),
+            CharacterMutation::ActivateMartialArtsForm(ActivateMartialArtsForm(name)) => {
+                self.activate_martial_arts_form(name)
+            }
+            CharacterMutation::EndMartialArtsForm => self.end_martial_arts_form(),
+            CharacterMutation::ShapeSorcery(ShapeSorcery {
+                spell,
+                successes,
+                shaping_ritual,
+            }) => self.shape_sorcery(spell, *successes, *shaping_ritual),
+            CharacterMutation::DistractShaping(DistractShaping {
+                difficulty,
+                successes,
+            }) => self.distract_shaping(*difficulty, *successes),
+            CharacterMutation::InterruptShaping => self.interrupt_shaping(),
+            CharacterMutation::CastSpell(CastSpell(spell)) => self.cast_spell(spell),
+            CharacterMutation::StartSorcerousWorking(StartSorcerousWorking(working)) => {
+                self.start_sorcerous_working(working)
+            }
+            CharacterMutation::RollSorcerousWorking(RollSorcerousWorking {
+                name,
+                successes,
+                botch,
+            }) => self.roll_sorcerous_working(name, *successes, botch.as_deref()),
+            CharacterMutation::AbandonSorcerousWorking(AbandonSorcerousWorking(name)) => {
+                self.abandon_sorcerous_working(name)
+            }
             CharacterMutation::GainWillpower(GainWillpower(amount)) => self.gain_willpower(*amount),
             CharacterMutation::SpendWillpower(SpendWillpower(amount)) => {
                 self.spend_willpower(*amount)
@@ -182,6 +250,45 @@ impl<'source> Character<'source> {
             CharacterMutation::AddSorcery(add_sorcery) => self.add_sorcery(add_sorcery),
             CharacterMutation::AddMerit(add_merit) => self.add_merit(add_merit),
             CharacterMutation::RemoveMerit(remove_merit) => self.remove_merit(remove_merit),
+            CharacterMutation::AwardStunt(award_stunt) => {
+                self.award_stunt(award_stunt.level)?;
+                Ok(self)
+            }
+            CharacterMutation::EndScene => {
+                self.end_scene()?;
+                Ok(self)
+            }
+            CharacterMutation::EndSession => {
+                self.end_session()?;
+                Ok(self)
+            }
+            CharacterMutation::Rest(rest) => {
+                self.rest(rest.0)?;
+                Ok(self)
+            }
+            CharacterMutation::EndStory => {
+                self.end_story()?;
+                Ok(self)
+            }
+            CharacterMutation::AddHealthLevels(add_health_levels) => {
+                self.add_health_levels(add_health_levels)
+            }
+            CharacterMutation::RemoveHealthLevels(RemoveHealthLevels(source)) => {
+                self.remove_health_levels(source)
+            }
+            CharacterMutation::StrengthenIntimacy(strengthen_intimacy) => {
+                self.strengthen_intimacy(strengthen_intimacy)
+            }
+            CharacterMutation::WeakenIntimacy(weaken_intimacy) => {
+                self.weaken_intimacy(weaken_intimacy)
+            }
+            CharacterMutation::ChangeTieEmotion(change_tie_emotion) => {
+                self.change_tie_emotion(change_tie_emotion)
+            }
+            CharacterMutation::HealNaturally(HealNaturally(hours)) => {
+                self.heal_naturally(*hours)?;
+                Ok(self)
+            }
             CharacterMutation::AddLanguage(add_language) => self.add_language(add_language),
             CharacterMutation::RemoveLanguage(remove_language) => {
                 self.remove_language(remove_language)
@@ -227,7 +334,10 @@ impl<'source> From<&'source CharacterMemo> for Character<'source> {
                 .flaws
                 .iter()
                 .map(|(name, (book_reference, description))| {
-                    (name.as_str(), (*book_reference, description.as_str()))
+                    (
+                        name.as_str(),
+                        (book_reference.clone(), description.as_str()),
+                    )
                 })
                 .collect(),
             native_language: &memo.native_language,
@@ -237,7 +347,23 @@ impl<'source> From<&'source CharacterMemo> for Character<'source> {
                 .iter()
                 .map(|(intimacy_type, &level)| (intimacy_type, level))
                 .collect(),
-            experience: memo.experience,
+            experience: {
+                let mut ledger = memo.experience.clone();
+                if let Some(pool) = memo.exaltation.legacy_experience() {
+                    ledger.open(ExperiencePoolName::Exalt, pool);
+                }
+                ledger
+            },
+            health_levels: memo
+                .health_levels
+                .clone()
+                .unwrap_or_else(|| HealthLevels::from_health(&memo.health)),
+            intimacy_history: memo.intimacy_history.clone(),
+            essence_progression: memo.essence_progression,
+            craft_projects: memo.craft_projects.clone(),
+            spell_shaping: memo.spell_shaping.clone(),
+            sorcerous_workings: memo.sorcerous_workings.clone(),
+            martial_arts_form: memo.martial_arts_form.clone(),
         }
     }
 }
@@ -6,14 +6,18 @@ use crate::{
     attributes::AttributeError,
     charms::CharmError,
     concept::ConceptError,
+    craft::CraftError,
     exaltation::exalt::{essence::EssenceError, exalt_type::solar::SolarError},
     experience::ExperienceError,
+    health::HealthError,
     hearthstones::HearthstoneError,
     intimacies::intimacy::IntimacyError,
     languages::LanguageError,
     martial_arts::MartialArtsError,
     merits::merit::MeritError,
+    quick_character::QuickCharacterError,
     sorcery::SorceryError,
+    spirit::SpiritError,
     weapons::WeaponError,
 };
 
@@ -39,12 +43,18 @@ pub enum CharacterMutationError {
     /// Error occurring while trying to modify a character's concept
     #[error("Concept error: {0:?}")]
     ConceptError(#[from] ConceptError),
+    /// Error related to crafting projects
+    #[error("Crafting error: {0:?}")]
+    CraftError(#[from] CraftError),
     /// Error related to Essence rating or mote pools
     #[error("Essence error: {0:?}")]
     EssenceError(#[from] EssenceError),
     /// Error related to character Experience
     #[error("Experience error: {0:?}")]
     ExperienceError(#[from] ExperienceError),
+    /// Error related to health levels
+    #[error("Health error: {0:?}")]
+    HealthError(#[from] HealthError),
     /// Error related to hearthstones
     #[error("Hearthstone error: {0:?}")]
     HearthstoneError(#[from] HearthstoneError),
@@ -60,12 +70,18 @@ pub enum CharacterMutationError {
     /// Error related to merits
     #[error("Merit error: {0:?}")]
     MeritError(#[from] MeritError),
+    /// Error related to Quick Characters
+    #[error("Quick Character error: {0:?}")]
+    QuickCharacterError(#[from] QuickCharacterError),
     /// Error specific to the Solar Exalted
     #[error("Solar error: {0:?}")]
     SolarError(#[from] SolarError),
     /// Error related to Sorcery
     #[error("Sorcery error: {0:?}")]
     SorceryError(#[from] SorceryError),
+    /// Error related to spirits
+    #[error("Spirit error: {0:?}")]
+    SpiritError(#[from] SpiritError),
     /// Error related to weapons
     #[error("Weapons error: {0:?}")]
     WeaponError(#[from] WeaponError),
@@ -6,25 +6,42 @@ pub use crate::artifact::AttuneArtifact;
 pub use crate::attributes::SetAttribute;
 pub use crate::charms::charm::{AddCharm, RemoveCharm};
 pub use crate::concept::{RemoveConcept, SetConcept};
+pub use crate::craft::{
+    AbandonCraftProject, ClaimCraftedArtifact, RollCraftProject, StartCraftProject,
+};
 pub use crate::exaltation::exalt::essence::{
-    CommitMotes, RecoverMotes, SetEssenceRating, SpendMotes, UncommitMotes,
+    CommitMotes, CommitSceneMotes, RecoverMotes, SetEssenceProgression, SetEssenceRating,
+    SpendMotes, UncommitMotes,
 };
 pub use crate::exaltation::exalt::exalt_type::solar::SetSolar;
-pub use crate::exaltation::exalt::limit::{GainLimit, ReduceLimit, SetLimitTrigger};
+pub use crate::exaltation::exalt::limit::{
+    GainLimit, ReduceLimit, ResolveLimitBreak, SetLimitTrigger, SetVirtueFlaw,
+};
 pub use crate::exaltation::mortal::SetMortal;
 pub use crate::experience::{
-    GainExaltExperience, GainExperience, SpendExaltExperience, SpendExperience,
+    AwardSessionExperience, GainExaltExperience, GainExperience, RecordExperience,
+    SpendExaltExperience, SpendExperience,
 };
 pub use crate::flaws::flaw::{AddFlaw, RemoveFlaw};
-pub use crate::health::{HealDamage, SetHealthTrack, TakeDamage};
+pub use crate::health::{
+    AddHealthLevels, HealDamage, HealNaturally, RemoveHealthLevels, SetHealthTrack, TakeDamage,
+};
 pub use crate::hearthstones::hearthstone::{SlotHearthstone, UnslotHearthstone};
-pub use crate::intimacies::intimacy::{AddIntimacy, RemoveIntimacy};
+pub use crate::intimacies::intimacy::{
+    AddIntimacy, ChangeTieEmotion, RemoveIntimacy, StrengthenIntimacy, WeakenIntimacy,
+};
 pub use crate::languages::language::SetNativeLanguage;
 pub use crate::languages::language::{AddLanguage, RemoveLanguage};
+pub use crate::lifecycle::{EndScene, EndSession, EndStory, Rest};
+pub use crate::martial_arts::{ActivateMartialArtsForm, EndMartialArtsForm};
 pub use crate::merits::merit::AddMerit;
 use crate::merits::merit::RemoveMerit;
 pub use crate::name::SetName;
-pub use crate::sorcery::{AddSorcery, RemoveSorcery};
+pub use crate::sorcery::{
+    AbandonSorcerousWorking, AddSorcery, CastSpell, DistractShaping, InterruptShaping,
+    RemoveSorcery, RollSorcerousWorking, ShapeSorcery, StartSorcerousWorking,
+};
+pub use crate::stunt::AwardStunt;
 pub use crate::weapons::weapon::mundane::{AddMundaneWeapon, RemoveMundaneWeapon};
 pub use crate::weapons::weapon::{EquipWeapon, UnequipWeapon};
 pub use crate::willpower::{GainWillpower, SetWillpowerRating, SpendWillpower};
@@ -53,6 +70,8 @@ pub enum CharacterMutation {
     SpendMotes(SpendMotes),
     /// Commit motes into a persistent effect, starting with one pool
     CommitMotes(CommitMotes),
+    /// Commit motes into an effect lasting until the end of the scene
+    CommitSceneMotes(CommitSceneMotes),
     /// Recover motes, always starting from peripheral
     RecoverMotes(RecoverMotes),
     /// Uncommit motes from a peristent effect
@@ -60,6 +79,9 @@ pub enum CharacterMutation {
     /// Set the Essence rating of the character. Note: also ends all mote
     /// commitments and recovers all motes.
     SetEssenceRating(SetEssenceRating),
+    /// Opts in to (or out of) automatic Essence progression from spent
+    /// experience.
+    SetEssenceProgression(SetEssenceProgression),
     /// Increases the character's current available willpower.
     GainWillpower(GainWillpower),
     /// Decreases the character's current available willpower, to a minimum of
@@ -121,6 +143,25 @@ pub enum CharacterMutation {
     /// Removes the currently highest-known level of sorcery from the
     /// character.
     RemoveSorcery,
+    /// Banks sorcerous motes toward a spell with a shape sorcery action.
+    ShapeSorcery(ShapeSorcery),
+    /// Resolves a distraction while shaping, possibly losing sorcerous motes.
+    DistractShaping(DistractShaping),
+    /// Interrupts shaping, losing all sorcerous motes.
+    InterruptShaping,
+    /// Casts the spell being shaped, consuming its sorcerous motes and
+    /// Willpower cost.
+    CastSpell(CastSpell),
+    /// Begins a sorcerous working
+    StartSorcerousWorking(StartSorcerousWorking),
+    /// Records a roll toward a sorcerous working
+    RollSorcerousWorking(RollSorcerousWorking),
+    /// Removes a sorcerous working
+    AbandonSorcerousWorking(AbandonSorcerousWorking),
+    /// Activates a Martial Arts Form Charm, ending any other active Form.
+    ActivateMartialArtsForm(ActivateMartialArtsForm),
+    /// Ends the character's active Martial Arts Form.
+    EndMartialArtsForm,
     /// Adds a Charm to the character.
     AddCharm(AddCharm),
     /// Removes a Charm from the character. Note that this may cause cascading
@@ -140,6 +181,10 @@ pub enum CharacterMutation {
     ReduceLimit(ReduceLimit),
     /// Sets the Exalt's Limit trigger.
     SetLimitTrigger(SetLimitTrigger),
+    /// Sets the Solar's Virtue Flaw.
+    SetVirtueFlaw(SetVirtueFlaw),
+    /// Ends a Limit Break, resetting the Limit track to 0.
+    ResolveLimitBreak,
     /// Adds normal, non-Exalt experience
     GainExperience(GainExperience),
     /// Spends normal, non-Exalt experience
@@ -148,10 +193,48 @@ pub enum CharacterMutation {
     GainExaltExperience(GainExaltExperience),
     /// Spends Exalt experience
     SpendExaltExperience(SpendExaltExperience),
+    /// Records an entry in the experience ledger, applying its linked
+    /// mutation (if any)
+    RecordExperience(RecordExperience),
+    /// Awards experience at the end of a session, broken down by reason
+    AwardSessionExperience(AwardSessionExperience),
+    /// Begins a crafting project
+    StartCraftProject(StartCraftProject),
+    /// Records a roll toward a crafting project, awarding crafting
+    /// experience if it completes
+    RollCraftProject(RollCraftProject),
+    /// Removes a crafting project
+    AbandonCraftProject(AbandonCraftProject),
+    /// Turns a completed superior or legendary project into an artifact
+    ClaimCraftedArtifact(ClaimCraftedArtifact),
     /// Adds a merit to the character.
     AddMerit(AddMerit),
     /// Removes a merit from the character.
     RemoveMerit(RemoveMerit),
+    /// Awards a stunt, restoring Willpower for two- and three-point stunts.
+    AwardStunt(AwardStunt),
+    /// Ends the current scene, releasing scene-length mote commitments.
+    EndScene,
+    /// Ends the current session, ending the scene and reducing Limit.
+    EndSession,
+    /// Rests for some number of hours, recovering motes and possibly
+    /// Willpower.
+    Rest(Rest),
+    /// Ends the current story, ending the session and restoring motes and
+    /// Willpower.
+    EndStory,
+    /// Heals damage naturally over some number of hours of rest.
+    HealNaturally(HealNaturally),
+    /// Adds health levels from a source, such as the Ox-Body Technique.
+    AddHealthLevels(AddHealthLevels),
+    /// Removes the health levels granted by a source.
+    RemoveHealthLevels(RemoveHealthLevels),
+    /// Strengthens an Intimacy by one level.
+    StrengthenIntimacy(StrengthenIntimacy),
+    /// Weakens an Intimacy by one level, removing it if it was Minor.
+    WeakenIntimacy(WeakenIntimacy),
+    /// Changes the emotional context of a Tie.
+    ChangeTieEmotion(ChangeTieEmotion),
 }
 
 impl<'view, 'source> CharacterMutation {
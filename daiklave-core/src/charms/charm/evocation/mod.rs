@@ -74,7 +74,7 @@ impl<'source> Evocation {
 
     /// The book reference for this Evocation.
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.book_reference
+        self.book_reference.clone()
     }
 
     /// A short summary of this Evocation's effects.
@@ -21,7 +21,7 @@ pub use add::AddCharm;
 
 use self::evocation::Evocation;
 
-pub use spirit::{EclipseCharm, SpiritCharmKeyword};
+pub use spirit::{EclipseCharm, SpiritCharmKeyword, SpiritOnlyCharm};
 
 /// A Charm possessed by a character.
 pub enum Charm<'source> {
@@ -36,6 +36,8 @@ pub enum Charm<'source> {
     Solar(SolarCharm<'source>),
     /// A Spell.
     Spell(Spell<'source>),
+    /// A Spirit charm without the Eclipse keyword, usable only by spirits.
+    SpiritOnly(&'source SpiritOnlyCharm),
 }
 
 impl<'source> Charm<'source> {
@@ -1,6 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 use super::{spirit_only::AddSpiritOnlyCharm, AddEclipseCharm};
 
 /// A Spirit charm (which may be Eclipse or not) to be added to a character.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AddSpiritCharm {
     /// A Spirit charm with the Eclipse keyword, which may be added by Eclipse
     /// caste Solars as well as non-Exalt beings.
@@ -8,3 +11,15 @@ pub enum AddSpiritCharm {
     /// A charm which can only be added to non-Exalts.
     SpiritOnly(AddSpiritOnlyCharm),
 }
+
+impl From<AddEclipseCharm> for AddSpiritCharm {
+    fn from(add_eclipse_charm: AddEclipseCharm) -> Self {
+        Self::Eclipse(add_eclipse_charm)
+    }
+}
+
+impl From<AddSpiritOnlyCharm> for AddSpiritCharm {
+    fn from(add_spirit_only_charm: AddSpiritOnlyCharm) -> Self {
+        Self::SpiritOnly(add_spirit_only_charm)
+    }
+}
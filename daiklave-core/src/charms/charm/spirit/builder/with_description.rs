@@ -91,8 +91,8 @@ impl SpiritCharmBuilderWithDescription {
     pub fn non_eclipse(self) -> AddSpiritOnlyCharm {
         let (name, inner) = self.build_inner();
         AddSpiritOnlyCharm {
-            _name: name.into(),
-            _charm: SpiritOnlyCharm(inner),
+            name: name.into(),
+            charm: SpiritOnlyCharm(inner),
         }
     }
 
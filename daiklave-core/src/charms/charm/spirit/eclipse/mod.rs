@@ -18,7 +18,7 @@ pub struct EclipseCharm(pub(crate) SpiritCharmInner);
 impl EclipseCharm {
     /// The book reference for the Eclipse Charm.
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.0.book_reference
+        self.0.book_reference.clone()
     }
 
     /// A short summary of the Charm's effect.
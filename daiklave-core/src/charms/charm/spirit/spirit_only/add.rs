@@ -1,9 +1,12 @@
+use serde::{Deserialize, Serialize};
+
 use crate::charms::charm::spirit::SpiritCharmName;
 
 use super::SpiritOnlyCharm;
 
 /// A charm which can only be added to non-Exalts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AddSpiritOnlyCharm {
-    pub(crate) _name: SpiritCharmName,
-    pub(crate) _charm: SpiritOnlyCharm,
+    pub(crate) name: SpiritCharmName,
+    pub(crate) charm: SpiritOnlyCharm,
 }
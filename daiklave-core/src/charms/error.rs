@@ -3,6 +3,10 @@ use thiserror::Error;
 /// An error related to Charms
 #[derive(Debug, Error)]
 pub enum CharmError {
+    /// Exalts dissonant with an artifact's Magic Material can only learn its
+    /// Evocations which have the Dissonant keyword
+    #[error("Dissonant with the artifact's Magic Material")]
+    Dissonant,
     /// Can't have the same Charm more than once. If a Charm can be purchased
     /// more than once, should be recorded as "Charm Name", "Charm Name (x2)",
     /// etc.
@@ -11,6 +15,9 @@ pub enum CharmError {
     /// Mortals cannot have Charms (except possibly Terrestrial spells)
     #[error("Mortals cannot have non-Spell Charms")]
     Mortal,
+    /// Evocations of an artifact can only be learned while attuned to it
+    #[error("Must be attuned to the artifact")]
+    NotAttuned,
     /// Can't remove a Charm that isn't present
     #[error("Charm not found")]
     NotFound,
@@ -98,4 +98,17 @@ impl<'view, 'source> Charms<'view, 'source> {
                 .map(|(spell, _)| Charm::Spell(spell)),
         }
     }
+
+    /// Returns true if the character knows the Charm but cannot currently
+    /// use it. Evocations are disabled while the character lacks (or is not
+    /// attuned to) the artifact or hearthstone they are drawn from, and when
+    /// any Evocation they build on is disabled.
+    pub fn is_disabled(&self, charm_id: CharmName) -> bool {
+        match charm_id {
+            CharmName::Evocation(evocation_id) => {
+                self.0.disabled_evocations().contains(evocation_id)
+            }
+            _ => false,
+        }
+    }
 }
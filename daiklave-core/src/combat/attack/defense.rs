@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// The static defense a defender uses against an attack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Defense {
+    /// Blocking the attack with a weapon (or bare hands).
+    Parry,
+    /// Getting out of the way.
+    Evasion,
+}
+
+/// Whether an attack is meant to reduce the target's Initiative or to deal
+/// lasting damage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AttackType {
+    /// An attack that strips Initiative from the target and grants it to the
+    /// attacker.
+    Withering,
+    /// An attack that converts the attacker's Initiative into health damage,
+    /// then resets their Initiative.
+    Decisive,
+}
@@ -0,0 +1,337 @@
+mod defense;
+mod outcome;
+mod profile;
+mod roll;
+
+pub use defense::{AttackType, Defense};
+pub use outcome::{AttackOutcome, AttackStep};
+pub(crate) use profile::{best_martial_arts_dots, wound_penalty_dice};
+pub use profile::{AttackProfile, DefenseProfile};
+pub use roll::{count_successes, DiceRoller};
+
+use std::num::NonZeroU8;
+
+use crate::health::TakeDamage;
+
+use super::{
+    Combat, CombatError, CombatMutation, CoverLevel, StatusEffect, StatusName, CRASH_RESET,
+};
+
+pub(crate) fn apply_modifier(value: u8, modifier: i8) -> u8 {
+    if modifier >= 0 {
+        value.saturating_add(modifier as u8)
+    } else {
+        value.saturating_sub(modifier.unsigned_abs())
+    }
+}
+
+/// An attack that has been checked and rolled against the defender's
+/// Defense, shared by attacks and gambits. If it hit, the threshold
+/// successes are set; otherwise the outcome is already complete.
+pub(crate) struct AttackRoll {
+    pub(crate) outcome: AttackOutcome,
+    pub(crate) threshold: Option<u8>,
+    pub(crate) attacker_initiative: i8,
+    pub(crate) defender_initiative: i8,
+    after_attack: Vec<CombatMutation>,
+}
+
+impl AttackRoll {
+    /// Completes the outcome with the mutations every attack causes,
+    /// whether or not it hit.
+    pub(crate) fn finish(mut self) -> AttackOutcome {
+        self.outcome.combat_mutations.extend(self.after_attack);
+        self.outcome
+    }
+}
+
+/// A single attack by one combatant against another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attack {
+    pub(crate) attacker: AttackProfile,
+    pub(crate) defender: DefenseProfile,
+    pub(crate) attack_type: AttackType,
+    pub(crate) defense: Defense,
+    pub(crate) protector: Option<DefenseProfile>,
+}
+
+impl Attack {
+    /// Sets up an attack. The attacker and defender must both be
+    /// combatants (by name) when the attack is resolved.
+    pub fn new(
+        attacker: AttackProfile,
+        defender: DefenseProfile,
+        attack_type: AttackType,
+        defense: Defense,
+    ) -> Self {
+        Self {
+            attacker,
+            defender,
+            attack_type,
+            defense,
+            protector: None,
+        }
+    }
+
+    /// Adds the defense profile of the combatant defending the defender,
+    /// if any. This is required to resolve the attack while the defender
+    /// is being protected.
+    pub fn with_protector(mut self, protector: DefenseProfile) -> Self {
+        self.protector = Some(protector);
+        self
+    }
+
+    /// Checks that the attack can be made, then rolls it against the
+    /// Defense it must meet, including status modifiers and any protector.
+    pub(crate) fn roll(
+        &self,
+        combat: &Combat,
+        roller: &mut impl DiceRoller,
+    ) -> Result<AttackRoll, CombatError> {
+        let attacker = combat
+            .combatant(self.attacker.name())
+            .ok_or(CombatError::NotFound)?;
+        let defender = combat
+            .combatant(self.defender.name())
+            .ok_or(CombatError::NotFound)?;
+        let attacker_initiative = attacker.initiative().ok_or(CombatError::NotJoined)?;
+        let defender_initiative = defender.initiative().ok_or(CombatError::NotJoined)?;
+
+        if defender.status(StatusName::Cover) == Some(&StatusEffect::Cover(CoverLevel::Full)) {
+            return Err(CombatError::Untargetable);
+        }
+        combat.check_attack_range(
+            self.attacker.name(),
+            self.defender.name(),
+            self.attacker.attack_range,
+        )?;
+        let protection = combat
+            .protector(defender.name())
+            .map(|protector| {
+                self.protector
+                    .as_ref()
+                    .filter(|profile| profile.name() == protector.name())
+                    .map(|profile| (protector, profile))
+                    .ok_or(CombatError::NoProtector)
+            })
+            .transpose()?;
+
+        let mut outcome = AttackOutcome {
+            steps: Vec::new(),
+            hit: false,
+            combat_mutations: Vec::new(),
+            damage: None,
+            disarm: None,
+        };
+
+        let base_pool = match self.attack_type {
+            AttackType::Withering => self.attacker.withering_pool,
+            AttackType::Decisive => {
+                if attacker_initiative < 1 {
+                    return Err(CombatError::Crashed);
+                }
+                self.attacker.decisive_pool
+            }
+        };
+        let pool = apply_modifier(base_pool, attacker.pool_modifier());
+        let dice = roller.roll_dice(pool);
+        let successes = count_successes(&dice, true);
+        outcome.steps.push(AttackStep::AttackRoll {
+            pool,
+            dice,
+            successes,
+        });
+
+        let base_defense = match self.defense {
+            Defense::Parry => self.defender.parry,
+            Defense::Evasion => self.defender.evasion,
+        };
+        let mut defense = apply_modifier(base_defense, defender.defense_modifier());
+
+        // A protector's Parry stands in for the defender's, if higher, and
+        // they take the onslaught penalty
+        let mut defended_by = defender.name();
+        if let Some((protector, profile)) = protection {
+            let parry = apply_modifier(profile.parry, protector.defense_modifier());
+            if parry > defense {
+                outcome
+                    .steps
+                    .push(AttackStep::DefendedBy(protector.name().to_owned()));
+                defense = parry;
+                defended_by = protector.name();
+            }
+        }
+
+        // Every attack adds to the onslaught penalty of whoever defended,
+        // and uses up the attacker's aim
+        let mut after_attack = vec![CombatMutation::AddStatus {
+            name: defended_by.to_owned(),
+            status: StatusEffect::Onslaught(1),
+        }];
+        if attacker.status(StatusName::Aiming).is_some() {
+            after_attack.push(CombatMutation::RemoveStatus {
+                name: self.attacker.name.clone(),
+                status: StatusName::Aiming,
+            });
+        }
+
+        let threshold = if successes < defense {
+            outcome.steps.push(AttackStep::Miss { defense });
+            // Missed decisive attacks, including gambits, cost Initiative
+            if self.attack_type == AttackType::Decisive {
+                let lost = if attacker_initiative >= 11 { 3 } else { 2 };
+                outcome.steps.push(AttackStep::InitiativeLost(lost));
+                outcome
+                    .combat_mutations
+                    .push(CombatMutation::LoseInitiative {
+                        name: self.attacker.name.clone(),
+                        amount: NonZeroU8::new(lost).unwrap(),
+                        attacker: None,
+                    });
+            }
+            None
+        } else {
+            let threshold = successes - defense;
+            outcome.hit = true;
+            outcome.steps.push(AttackStep::Hit { defense, threshold });
+            Some(threshold)
+        };
+
+        Ok(AttackRoll {
+            outcome,
+            threshold,
+            attacker_initiative,
+            defender_initiative,
+            after_attack,
+        })
+    }
+
+    /// Rolls and resolves the attack against the current state of combat,
+    /// including the attacker's pool modifiers and the defender's Defense
+    /// modifiers from status effects. The attack must be able to reach the
+    /// defender at their current range, if one is set. If another combatant
+    /// is defending the defender, the attack needs their defense profile.
+    /// The combat itself is not modified; apply the outcome's mutations to do
+    /// so.
+    pub fn resolve(
+        &self,
+        combat: &Combat,
+        roller: &mut impl DiceRoller,
+    ) -> Result<AttackOutcome, CombatError> {
+        let mut roll = self.roll(combat, roller)?;
+        if let Some(threshold) = roll.threshold {
+            match self.attack_type {
+                AttackType::Withering => self.resolve_withering(
+                    threshold,
+                    roll.defender_initiative,
+                    roller,
+                    &mut roll.outcome,
+                ),
+                AttackType::Decisive => {
+                    self.resolve_decisive(roll.attacker_initiative, roller, &mut roll.outcome)
+                }
+            }
+        }
+        Ok(roll.finish())
+    }
+
+    fn resolve_withering(
+        &self,
+        threshold: u8,
+        defender_initiative: i8,
+        roller: &mut impl DiceRoller,
+        outcome: &mut AttackOutcome,
+    ) {
+        let raw_damage = self.attacker.damage.saturating_add(threshold);
+        outcome.steps.push(AttackStep::RawDamage(raw_damage));
+
+        let post_soak = raw_damage.saturating_sub(self.defender.soak);
+        outcome.steps.push(AttackStep::Soak {
+            soak: self.defender.soak,
+            post_soak,
+        });
+
+        let pool = if post_soak < self.attacker.overwhelming {
+            outcome
+                .steps
+                .push(AttackStep::Overwhelming(self.attacker.overwhelming));
+            self.attacker.overwhelming
+        } else {
+            post_soak
+        };
+
+        let dice = roller.roll_dice(pool);
+        let damage = count_successes(&dice, false);
+        outcome.steps.push(AttackStep::DamageRoll {
+            pool,
+            dice,
+            successes: damage,
+        });
+
+        if let Some(amount) = NonZeroU8::new(damage) {
+            outcome.steps.push(AttackStep::InitiativeDamage(damage));
+            outcome
+                .combat_mutations
+                .push(CombatMutation::LoseInitiative {
+                    name: self.defender.name.clone(),
+                    amount,
+                    attacker: Some(self.attacker.name.clone()),
+                });
+
+            if defender_initiative >= 1 && i16::from(defender_initiative) - i16::from(damage) < 1 {
+                outcome.steps.push(AttackStep::Crash);
+            }
+
+            let gained = damage.saturating_add(1);
+            outcome.steps.push(AttackStep::InitiativeGained(gained));
+            outcome
+                .combat_mutations
+                .push(CombatMutation::GainInitiative {
+                    name: self.attacker.name.clone(),
+                    amount: NonZeroU8::new(gained).unwrap(),
+                });
+        }
+    }
+
+    fn resolve_decisive(
+        &self,
+        attacker_initiative: i8,
+        roller: &mut impl DiceRoller,
+        outcome: &mut AttackOutcome,
+    ) {
+        // Initiative is at least 1 here, having been checked before rolling
+        let raw_damage = attacker_initiative as u8;
+        if raw_damage <= self.defender.hardness {
+            outcome
+                .steps
+                .push(AttackStep::Hardness(self.defender.hardness));
+        } else {
+            let dice = roller.roll_dice(raw_damage);
+            let damage = count_successes(&dice, false);
+            outcome.steps.push(AttackStep::DamageRoll {
+                pool: raw_damage,
+                dice,
+                successes: damage,
+            });
+
+            if let Some(amount) = NonZeroU8::new(damage) {
+                outcome.steps.push(AttackStep::HealthDamage {
+                    level: self.attacker.damage_level,
+                    amount: damage,
+                });
+                outcome.damage = Some(TakeDamage {
+                    level: self.attacker.damage_level,
+                    amount,
+                });
+            }
+        }
+
+        outcome.steps.push(AttackStep::InitiativeReset(CRASH_RESET));
+        outcome
+            .combat_mutations
+            .push(CombatMutation::SetInitiative {
+                name: self.attacker.name.clone(),
+                initiative: CRASH_RESET,
+            });
+    }
+}
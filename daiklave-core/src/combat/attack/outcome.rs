@@ -0,0 +1,158 @@
+use crate::{
+    combat::CombatMutation,
+    health::{DamageLevel, TakeDamage},
+    weapons::weapon::UnequipWeapon,
+};
+
+/// A single step in resolving an attack, in the order it occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttackStep {
+    /// The attack roll.
+    AttackRoll {
+        /// The number of dice rolled.
+        pool: u8,
+        /// The face of each die.
+        dice: Vec<u8>,
+        /// The successes rolled, with 10s counting double.
+        successes: u8,
+    },
+    /// The defender's protector parried in their place.
+    DefendedBy(String),
+    /// The attack failed to meet the defender's Defense.
+    Miss {
+        /// The defender's Defense.
+        defense: u8,
+    },
+    /// The attack met or exceeded the defender's Defense.
+    Hit {
+        /// The defender's Defense.
+        defense: u8,
+        /// The successes in excess of the Defense.
+        threshold: u8,
+    },
+    /// Withering raw damage: the attacker's damage plus threshold
+    /// successes.
+    RawDamage(u8),
+    /// The defender's soak is subtracted from raw damage.
+    Soak {
+        /// The defender's soak.
+        soak: u8,
+        /// The damage remaining after soak.
+        post_soak: u8,
+    },
+    /// The post-soak damage was raised to the attacker's Overwhelming.
+    Overwhelming(u8),
+    /// The damage roll.
+    DamageRoll {
+        /// The number of dice rolled.
+        pool: u8,
+        /// The face of each die.
+        dice: Vec<u8>,
+        /// The successes rolled. 10s do not count double.
+        successes: u8,
+    },
+    /// Initiative removed from the defender by a withering attack.
+    InitiativeDamage(u8),
+    /// Initiative gained by the attacker from a withering attack.
+    InitiativeGained(u8),
+    /// The withering attack crashed the defender.
+    Crash,
+    /// The decisive attack's raw damage did not exceed the defender's
+    /// Hardness.
+    Hardness(u8),
+    /// Health levels of damage dealt by a decisive attack.
+    HealthDamage {
+        /// The type of damage.
+        level: DamageLevel,
+        /// The number of health levels.
+        amount: u8,
+    },
+    /// The attacker's Initiative reset after a successful decisive attack.
+    InitiativeReset(i8),
+    /// Initiative lost by the attacker after a failed decisive attack, or
+    /// spent on a gambit.
+    InitiativeLost(u8),
+    /// The gambit's Initiative roll, made after the attack hits.
+    GambitRoll {
+        /// The number of dice rolled.
+        pool: u8,
+        /// The face of each die.
+        dice: Vec<u8>,
+        /// The successes rolled. 10s do not count double.
+        successes: u8,
+    },
+    /// Whether the gambit's Initiative roll exceeded its difficulty.
+    Gambit {
+        /// The gambit's difficulty.
+        difficulty: u8,
+        /// True if the gambit succeeded.
+        succeeded: bool,
+    },
+    /// The grappler's roll to establish control of a grapple.
+    ControlRoll {
+        /// The number of dice rolled.
+        pool: u8,
+        /// The face of each die.
+        dice: Vec<u8>,
+        /// The successes rolled, with 10s counting double.
+        successes: u8,
+    },
+    /// The victim's roll opposing the grappler's control roll.
+    ResistRoll {
+        /// The number of dice rolled.
+        pool: u8,
+        /// The face of each die.
+        dice: Vec<u8>,
+        /// The successes rolled, with 10s counting double.
+        successes: u8,
+    },
+    /// The control rounds won by the grappler. If zero, the victim slips
+    /// free.
+    ControlRounds(u8),
+}
+
+/// The result of resolving an attack or gambit: a step-by-step log of what
+/// happened, the Initiative changes to apply to the combat (for both
+/// attacker and defender, in order), and any health damage or disarm to
+/// apply to the defender.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackOutcome {
+    pub(crate) steps: Vec<AttackStep>,
+    pub(crate) hit: bool,
+    pub(crate) combat_mutations: Vec<CombatMutation>,
+    pub(crate) damage: Option<TakeDamage>,
+    pub(crate) disarm: Option<UnequipWeapon>,
+}
+
+impl AttackOutcome {
+    /// The steps of the attack, in order.
+    pub fn steps(&self) -> impl Iterator<Item = &AttackStep> + '_ {
+        self.steps.iter()
+    }
+
+    /// True if the attack hit.
+    pub fn is_hit(&self) -> bool {
+        self.hit
+    }
+
+    /// The Initiative mutations for both combatants. These must be applied
+    /// in order, so that Initiative Break and Initiative Shift are judged
+    /// against the attacker's Initiative before the attack.
+    pub fn combat_mutations(&self) -> impl Iterator<Item = &CombatMutation> + '_ {
+        self.combat_mutations.iter()
+    }
+
+    /// The health damage dealt to the defender by a decisive attack, if any.
+    /// This can be converted into a mutation for a character, Quick
+    /// Character, or battle group.
+    pub fn damage(&self) -> Option<TakeDamage> {
+        self.damage
+    }
+
+    /// The weapon knocked out of the defender's hands by a successful
+    /// disarm gambit, if any. Neither the combat nor the outcome's combat
+    /// mutations unequip it; apply this to the defending character to do so.
+    pub fn disarm(&self) -> Option<&UnequipWeapon> {
+        self.disarm.as_ref()
+    }
+}
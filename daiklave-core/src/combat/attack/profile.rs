@@ -0,0 +1,369 @@
+use crate::{
+    abilities::AbilityNameVanilla,
+    attributes::AttributeName,
+    combat::CombatError,
+    health::{DamageLevel, WoundPenalty},
+    quick_character::{QuickCharacter, QuickStatName},
+    weapons::weapon::{AttackRange, Equipped, Weapon, WeaponName, WeaponTag},
+    Character,
+};
+
+pub(crate) fn wound_penalty_dice(wound_penalty: WoundPenalty) -> u8 {
+    match wound_penalty {
+        WoundPenalty::Zero => 0,
+        WoundPenalty::MinusOne => 1,
+        WoundPenalty::MinusTwo => 2,
+        WoundPenalty::MinusFour | WoundPenalty::Incapacitated => 4,
+    }
+}
+
+/// The highest Martial Arts rating among the character's styles. If a weapon
+/// is specified, only styles which allow that weapon are considered.
+pub(crate) fn best_martial_arts_dots(character: &Character<'_>, weapon: Option<&Weapon<'_>>) -> u8 {
+    let martial_arts = character.martial_arts();
+    martial_arts
+        .iter()
+        .filter_map(|style_name| martial_arts.style(style_name))
+        .filter(|style| weapon.is_none_or(|weapon| style.allows_weapon(weapon)))
+        .map(|style| style.ability().dots())
+        .max()
+        .unwrap_or_default()
+}
+
+/// The offensive statistics for a single attack with a specific weapon at a
+/// specific range, including wound penalties.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackProfile {
+    pub(crate) name: String,
+    pub(crate) withering_pool: u8,
+    pub(crate) decisive_pool: u8,
+    pub(crate) damage: u8,
+    pub(crate) overwhelming: u8,
+    pub(crate) damage_level: DamageLevel,
+    pub(crate) attack_range: AttackRange,
+}
+
+impl AttackProfile {
+    /// Creates an attack profile for an NPC with the specified attack pool
+    /// and raw damage. Decisive attacks use the same pool unless
+    /// specified. Defaults to a lethal melee attack with an Overwhelming of
+    /// 1.
+    pub fn npc(name: impl Into<String>, pool: u8, damage: u8) -> Self {
+        Self {
+            name: name.into(),
+            withering_pool: pool,
+            decisive_pool: pool,
+            damage,
+            overwhelming: 1,
+            damage_level: DamageLevel::Lethal,
+            attack_range: AttackRange::Melee,
+        }
+    }
+
+    /// Creates an attack profile for a Quick Character using one of its
+    /// named attacks.
+    pub fn quick_character(quick_character: &QuickCharacter<'_>, attack: &str) -> Option<Self> {
+        let (accuracy, damage) = quick_character.attack(attack)?;
+        let wound_penalty = wound_penalty_dice(quick_character.health().current_wound_penalty());
+        Some(Self::npc(
+            quick_character.name(),
+            accuracy.saturating_sub(wound_penalty),
+            damage,
+        ))
+    }
+
+    /// Creates an attack profile for a character using the specified
+    /// weapon. The decisive pool is Dexterity + the weapon's Ability; the
+    /// withering pool adds the weapon's accuracy at that range. Raw damage
+    /// is Strength (or 4, for Crossbow and Flame weapons) plus the weapon's
+    /// damage. Returns an error if the character doesn't have the weapon or
+    /// it can't attack at that range.
+    pub fn character(
+        character: &Character<'_>,
+        weapon_name: WeaponName<'_>,
+        equipped: Option<Equipped>,
+        attack_range: AttackRange,
+    ) -> Result<Self, CombatError> {
+        let weapon = character
+            .weapons()
+            .get(weapon_name, equipped)
+            .ok_or(CombatError::WeaponNotFound)?;
+
+        let abilities = character.abilities();
+        let ability_dots = weapon
+            .tags()
+            .filter_map(|tag| match (attack_range, tag) {
+                (AttackRange::Melee, WeaponTag::Brawl) => {
+                    Some(abilities.get_vanilla(AbilityNameVanilla::Brawl).dots())
+                }
+                (AttackRange::Melee, WeaponTag::Melee) => {
+                    Some(abilities.get_vanilla(AbilityNameVanilla::Melee).dots())
+                }
+                (_, WeaponTag::MartialArts) => {
+                    Some(best_martial_arts_dots(character, Some(&weapon)))
+                }
+                (AttackRange::Ranged(_), WeaponTag::Archery(_)) => {
+                    Some(abilities.get_vanilla(AbilityNameVanilla::Archery).dots())
+                }
+                (AttackRange::Ranged(_), WeaponTag::Thrown(_)) => {
+                    Some(abilities.get_vanilla(AbilityNameVanilla::Thrown).dots())
+                }
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default();
+
+        Self::weapon_attack(character, &weapon, attack_range, ability_dots)
+    }
+
+    /// Creates an attack profile for a character using the specified weapon
+    /// with a Martial Arts style, using that style's rating as the
+    /// weapon's Ability. Returns an error if the character doesn't know the
+    /// style, or the style doesn't allow the weapon.
+    pub fn martial_arts(
+        character: &Character<'_>,
+        style_name: &str,
+        weapon_name: WeaponName<'_>,
+        equipped: Option<Equipped>,
+        attack_range: AttackRange,
+    ) -> Result<Self, CombatError> {
+        let style = character
+            .martial_arts()
+            .style(style_name)
+            .ok_or(CombatError::StyleNotFound)?;
+        let weapon = character
+            .weapons()
+            .get(weapon_name, equipped)
+            .ok_or(CombatError::WeaponNotFound)?;
+        if !style.allows_weapon(&weapon) {
+            return Err(CombatError::WeaponNotAllowed);
+        }
+
+        Self::weapon_attack(character, &weapon, attack_range, style.ability().dots())
+    }
+
+    fn weapon_attack(
+        character: &Character<'_>,
+        weapon: &Weapon<'_>,
+        attack_range: AttackRange,
+        ability_dots: u8,
+    ) -> Result<Self, CombatError> {
+        let accuracy = weapon
+            .accuracy(attack_range)
+            .ok_or(CombatError::OutOfRange)?;
+        let weapon_damage = weapon.damage(attack_range).ok_or(CombatError::OutOfRange)?;
+
+        let strength = if weapon
+            .tags()
+            .any(|tag| matches!(tag, WeaponTag::Crossbow | WeaponTag::Flame))
+        {
+            4
+        } else {
+            character.attributes().get(AttributeName::Strength).dots()
+        };
+
+        let damage_level = if weapon.tags().any(|tag| tag == WeaponTag::Bashing) {
+            DamageLevel::Bashing
+        } else {
+            DamageLevel::Lethal
+        };
+
+        let wound_penalty = wound_penalty_dice(character.health().current_wound_penalty());
+        let decisive_pool = (character.attributes().get(AttributeName::Dexterity).dots()
+            + ability_dots)
+            .saturating_sub(wound_penalty);
+        let withering_pool = if accuracy >= 0 {
+            decisive_pool.saturating_add(accuracy as u8)
+        } else {
+            decisive_pool.saturating_sub(accuracy.unsigned_abs())
+        };
+
+        Ok(Self {
+            name: character.name().to_owned(),
+            withering_pool,
+            decisive_pool,
+            damage: strength + weapon_damage,
+            overwhelming: weapon.overwhelming().max(1),
+            damage_level,
+            attack_range,
+        })
+    }
+
+    /// Sets the pool used for decisive attacks.
+    pub fn with_decisive_pool(mut self, decisive_pool: u8) -> Self {
+        self.decisive_pool = decisive_pool;
+        self
+    }
+
+    /// Sets the minimum post-soak damage of withering attacks.
+    pub fn with_overwhelming(mut self, overwhelming: u8) -> Self {
+        self.overwhelming = overwhelming.max(1);
+        self
+    }
+
+    /// Sets the type of damage dealt by decisive attacks.
+    pub fn with_damage_level(mut self, damage_level: DamageLevel) -> Self {
+        self.damage_level = damage_level;
+        self
+    }
+
+    /// Sets the range the attack is made at.
+    pub fn with_attack_range(mut self, attack_range: AttackRange) -> Self {
+        self.attack_range = attack_range;
+        self
+    }
+
+    /// The name of the attacker.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The range the attack is made at.
+    pub fn attack_range(&self) -> AttackRange {
+        self.attack_range
+    }
+
+    /// The dice rolled for a withering attack.
+    pub fn withering_pool(&self) -> u8 {
+        self.withering_pool
+    }
+
+    /// The dice rolled for a decisive attack.
+    pub fn decisive_pool(&self) -> u8 {
+        self.decisive_pool
+    }
+
+    /// The raw damage of a withering attack, before extra successes.
+    pub fn damage(&self) -> u8 {
+        self.damage
+    }
+}
+
+/// The defensive statistics of the target of an attack, including wound
+/// penalties.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefenseProfile {
+    pub(crate) name: String,
+    pub(crate) parry: u8,
+    pub(crate) evasion: u8,
+    pub(crate) soak: u8,
+    pub(crate) hardness: u8,
+}
+
+impl DefenseProfile {
+    /// Creates a defense profile for an NPC with flat statistics.
+    pub fn npc(name: impl Into<String>, parry: u8, evasion: u8, soak: u8, hardness: u8) -> Self {
+        Self {
+            name: name.into(),
+            parry,
+            evasion,
+            soak,
+            hardness,
+        }
+    }
+
+    /// Creates a defense profile for a Quick Character from its flat
+    /// statistics.
+    pub fn quick_character(quick_character: &QuickCharacter<'_>) -> Self {
+        let stats = quick_character.stats();
+        let wound_penalty = wound_penalty_dice(quick_character.health().current_wound_penalty());
+        Self::npc(
+            quick_character.name(),
+            stats
+                .get(QuickStatName::Parry)
+                .saturating_sub(wound_penalty),
+            stats
+                .get(QuickStatName::Evasion)
+                .saturating_sub(wound_penalty),
+            stats.get(QuickStatName::Soak),
+            stats.get(QuickStatName::Hardness),
+        )
+    }
+
+    /// Creates a defense profile for a character parrying with the
+    /// specified weapon. Parry is half (rounded up) of Dexterity + the
+    /// weapon's Ability, plus the weapon's defense; Evasion is half (rounded
+    /// up) of Dexterity + Dodge, minus the worn armor's mobility penalty.
+    /// Soak is Stamina plus armor soak, and Hardness comes from armor.
+    pub fn character(
+        character: &Character<'_>,
+        parry_weapon: WeaponName<'_>,
+        equipped: Option<Equipped>,
+    ) -> Result<Self, CombatError> {
+        let weapon = character
+            .weapons()
+            .get(parry_weapon, equipped)
+            .ok_or(CombatError::WeaponNotFound)?;
+        let abilities = character.abilities();
+        let dexterity = character.attributes().get(AttributeName::Dexterity).dots();
+        let wound_penalty = wound_penalty_dice(character.health().current_wound_penalty());
+
+        let parry = match weapon.parry_mod() {
+            Some(parry_mod) => {
+                let ability_dots = weapon
+                    .tags()
+                    .filter_map(|tag| match tag {
+                        WeaponTag::Brawl => {
+                            Some(abilities.get_vanilla(AbilityNameVanilla::Brawl).dots())
+                        }
+                        WeaponTag::Melee => {
+                            Some(abilities.get_vanilla(AbilityNameVanilla::Melee).dots())
+                        }
+                        WeaponTag::MartialArts => {
+                            Some(best_martial_arts_dots(character, Some(&weapon)))
+                        }
+                        _ => None,
+                    })
+                    .max()
+                    .unwrap_or_default();
+                let base = (dexterity + ability_dots).div_ceil(2);
+                (i16::from(base) + i16::from(parry_mod) - i16::from(wound_penalty)).max(0) as u8
+            }
+            None => 0,
+        };
+
+        let armor = character.armor().worn();
+        let dodge = abilities.get_vanilla(AbilityNameVanilla::Dodge).dots();
+        let mobility_penalty = armor.as_ref().map_or(0, |armor| armor.mobility_penalty());
+        let evasion = (i16::from((dexterity + dodge).div_ceil(2)) + i16::from(mobility_penalty)
+            - i16::from(wound_penalty))
+        .max(0) as u8;
+
+        let stamina = character.attributes().get(AttributeName::Stamina).dots();
+        let soak = stamina + armor.as_ref().map_or(0, |armor| armor.soak_bonus());
+        let hardness = armor.as_ref().map_or(0, |armor| armor.hardness());
+
+        Ok(Self {
+            name: character.name().to_owned(),
+            parry,
+            evasion,
+            soak,
+            hardness,
+        })
+    }
+
+    /// The name of the defender.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The defender's Parry.
+    pub fn parry(&self) -> u8 {
+        self.parry
+    }
+
+    /// The defender's Evasion.
+    pub fn evasion(&self) -> u8 {
+        self.evasion
+    }
+
+    /// The defender's total soak.
+    pub fn soak(&self) -> u8 {
+        self.soak
+    }
+
+    /// The defender's Hardness.
+    pub fn hardness(&self) -> u8 {
+        self.hardness
+    }
+}
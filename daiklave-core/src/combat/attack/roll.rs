@@ -0,0 +1,27 @@
+/// A source of dice results. The core library does not generate random
+/// numbers itself; callers provide a roller, which may be a random number
+/// generator, dice rolled at the table, or a fixed sequence for testing.
+pub trait DiceRoller {
+    /// Rolls the specified number of ten-sided dice, returning each die's
+    /// face (from 1 to 10).
+    fn roll_dice(&mut self, dice: u8) -> Vec<u8>;
+}
+
+impl<F> DiceRoller for F
+where
+    F: FnMut(u8) -> Vec<u8>,
+{
+    fn roll_dice(&mut self, dice: u8) -> Vec<u8> {
+        self(dice)
+    }
+}
+
+/// Counts the successes on a roll. Each 7 or higher is a success, and 10s
+/// count twice if `double_tens` is true.
+pub fn count_successes(dice: &[u8], double_tens: bool) -> u8 {
+    dice.iter().fold(0, |successes, face| match face {
+        10 if double_tens => successes.saturating_add(2),
+        7..=10 => successes.saturating_add(1),
+        _ => successes,
+    })
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    abilities::AbilityNameVanilla, attributes::AttributeName, quick_character::QuickCharacter,
+    Character,
+};
+
+use super::status::{ActiveStatus, StatusEffect, StatusName};
+
+/// Whether a combatant is a full character or a non-player character
+/// represented only by its Join Battle pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CombatantKind {
+    /// A full character, referenced by name.
+    Character,
+    /// A named NPC, such as a Quick Character or battle group.
+    Npc,
+}
+
+/// A participant in combat, tracking their Join Battle roll, Initiative,
+/// and Crash state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Combatant {
+    pub(crate) name: String,
+    pub(crate) kind: CombatantKind,
+    pub(crate) join_battle_pool: u8,
+    pub(crate) join_battle_result: Option<u8>,
+    pub(crate) initiative: Option<i8>,
+    pub(crate) crashed_turns: u8,
+    pub(crate) initiative_shift_pending: bool,
+    pub(crate) statuses: Vec<ActiveStatus>,
+}
+
+impl Combatant {
+    /// The name of the combatant.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Whether the combatant is a full character or an NPC.
+    pub fn kind(&self) -> CombatantKind {
+        self.kind
+    }
+
+    /// The dice pool the combatant rolls to Join Battle.
+    pub fn join_battle_pool(&self) -> u8 {
+        self.join_battle_pool
+    }
+
+    /// The successes the combatant rolled to Join Battle, if they have.
+    pub fn join_battle_result(&self) -> Option<u8> {
+        self.join_battle_result
+    }
+
+    /// The combatant's current Initiative, or None if they have not yet
+    /// joined battle.
+    pub fn initiative(&self) -> Option<i8> {
+        self.initiative
+    }
+
+    /// True if the combatant's Initiative has fallen below 1.
+    pub fn is_crashed(&self) -> bool {
+        self.initiative.is_some_and(|initiative| initiative < 1)
+    }
+
+    /// The number of consecutive turns the combatant has ended in Crash.
+    pub fn crashed_turns(&self) -> u8 {
+        self.crashed_turns
+    }
+
+    /// True if the combatant crashed an opponent with higher Initiative and
+    /// must roll Join Battle again for their Initiative Shift.
+    pub fn initiative_shift_pending(&self) -> bool {
+        self.initiative_shift_pending
+    }
+
+    /// Iterates over the status effects currently affecting the combatant.
+    pub fn statuses(&self) -> impl Iterator<Item = &StatusEffect> + '_ {
+        self.statuses.iter().map(|active| &active.effect)
+    }
+
+    /// Gets a specific status effect, if the combatant has it.
+    pub fn status(&self, name: StatusName) -> Option<&StatusEffect> {
+        self.statuses().find(|effect| effect.name() == name)
+    }
+
+    /// The net bonus or penalty to the combatant's action pools from all
+    /// status effects.
+    pub fn pool_modifier(&self) -> i8 {
+        self.statuses().fold(0, |total, effect| {
+            total.saturating_add(effect.pool_modifier())
+        })
+    }
+
+    /// The net bonus or penalty to the combatant's Parry and Evasion from
+    /// all status effects.
+    pub fn defense_modifier(&self) -> i8 {
+        self.statuses().fold(0, |total, effect| {
+            total.saturating_add(effect.defense_modifier())
+        })
+    }
+
+    pub(crate) fn set_initiative(&mut self, initiative: i8) {
+        self.initiative = Some(initiative);
+        if initiative >= 1 {
+            self.crashed_turns = 0;
+        }
+    }
+}
+
+/// A mutation to add a participant to combat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddCombatant {
+    pub(crate) name: String,
+    pub(crate) kind: CombatantKind,
+    pub(crate) join_battle_pool: u8,
+}
+
+impl AddCombatant {
+    /// Adds a named NPC with the specified Join Battle pool.
+    pub fn npc(name: impl Into<String>, join_battle_pool: u8) -> Self {
+        Self {
+            name: name.into(),
+            kind: CombatantKind::Npc,
+            join_battle_pool,
+        }
+    }
+
+    /// Adds a character by name, with a Join Battle pool of Wits +
+    /// Awareness.
+    pub fn character(character: &Character<'_>) -> Self {
+        let join_battle_pool = character.attributes().get(AttributeName::Wits).dots()
+            + character
+                .abilities()
+                .get_vanilla(AbilityNameVanilla::Awareness)
+                .dots();
+
+        Self {
+            name: character.name().to_owned(),
+            kind: CombatantKind::Character,
+            join_battle_pool,
+        }
+    }
+
+    /// Adds a Quick Character using its "Join Battle" action pool, if it has
+    /// one.
+    pub fn quick_character(quick_character: &QuickCharacter<'_>) -> Self {
+        Self::npc(
+            quick_character.name(),
+            quick_character
+                .action_pool("Join Battle")
+                .unwrap_or_default(),
+        )
+    }
+}
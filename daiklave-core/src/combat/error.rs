@@ -0,0 +1,86 @@
+use thiserror::Error;
+
+/// An error related to combat and Initiative tracking.
+#[derive(Debug, Error)]
+pub enum CombatError {
+    /// A combatant can only take one grapple action per turn.
+    #[error("Combatant has already taken a grapple action this turn")]
+    AlreadyActed,
+    /// Combatants can't start a new grapple while already in one.
+    #[error("Combatant is already in a grapple")]
+    AlreadyGrappling,
+    /// Join Battle can only be rolled once per combat.
+    #[error("Combatant has already joined battle")]
+    AlreadyJoined,
+    /// Combat can't be started twice.
+    #[error("Combat has already started")]
+    AlreadyStarted,
+    /// Crashed combatants cannot make decisive attacks.
+    #[error("Combatant is in Crash")]
+    Crashed,
+    /// Combatant names must be unique.
+    #[error("Combatants must have unique names")]
+    DuplicateCombatant,
+    /// Custom gambit names must be unique.
+    #[error("A custom gambit with that name already exists")]
+    DuplicateGambit,
+    /// Combatants at Close range must disengage before moving away.
+    #[error("Must disengage to move away from Close range")]
+    Engaged,
+    /// The custom gambit has not been registered with the combat.
+    #[error("Gambit not found")]
+    GambitNotFound,
+    /// The grapple doesn't have enough control rounds left for that action.
+    #[error("Insufficient control rounds")]
+    InsufficientControl,
+    /// Can't spend more Initiative than the combatant has.
+    #[error("Insufficient Initiative")]
+    InsufficientInitiative,
+    /// Can't move closer than Close or farther than Extreme range, or
+    /// disengage from someone not at Close range.
+    #[error("Invalid move")]
+    InvalidMove,
+    /// The defender is being protected by another combatant, whose defense
+    /// profile the attack needs.
+    #[error("Attack needs the defender's protector")]
+    NoProtector,
+    /// The combatant is not owed an Initiative Shift.
+    #[error("No pending Initiative Shift")]
+    NoInitiativeShift,
+    /// The range between the two combatants has not been set.
+    #[error("No range set between combatants")]
+    NoRange,
+    /// The named combatant is not part of this combat.
+    #[error("Combatant not found")]
+    NotFound,
+    /// The combatant is not controlling (or held in) a grapple.
+    #[error("Combatant is not in a grapple")]
+    NotGrappling,
+    /// Every combatant needs an Initiative before combat can start.
+    #[error("Combatant has not joined battle")]
+    NotJoined,
+    /// Turn order and rounds only exist once combat has started.
+    #[error("Combat has not started")]
+    NotStarted,
+    /// Grapple actions can only be taken on the grappler's turn.
+    #[error("It is not the combatant's turn")]
+    NotTheirTurn,
+    /// The weapon can't be used to attack at the requested range.
+    #[error("Weapon cannot attack at that range")]
+    OutOfRange,
+    /// The character does not know the Martial Arts style.
+    #[error("Martial Arts style not found")]
+    StyleNotFound,
+    /// Rushes can only target enemies at Short or Medium range.
+    #[error("Target is too far away to rush")]
+    TooFarToRush,
+    /// The target is in full cover.
+    #[error("Target cannot be attacked")]
+    Untargetable,
+    /// The Martial Arts style does not allow the weapon.
+    #[error("Weapon not usable with that style")]
+    WeaponNotAllowed,
+    /// The character does not have the weapon.
+    #[error("Weapon not found")]
+    WeaponNotFound,
+}
@@ -0,0 +1,246 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use crate::weapons::weapon::UnequipWeapon;
+
+use super::{
+    attack::{
+        count_successes, Attack, AttackOutcome, AttackProfile, AttackStep, AttackType, Defense,
+        DefenseProfile, DiceRoller,
+    },
+    Combat, CombatError, CombatMutation, StatusEffect,
+};
+
+/// A gambit defined by the storyteller, with its own difficulty. Custom
+/// gambits have no automatic effect; the storyteller applies it by hand if
+/// the gambit succeeds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomGambit {
+    pub(crate) name: String,
+    pub(crate) difficulty: NonZeroU8,
+    pub(crate) description: String,
+}
+
+impl CustomGambit {
+    /// Creates a new custom gambit.
+    pub fn new(
+        name: impl Into<String>,
+        difficulty: NonZeroU8,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            difficulty,
+            description: description.into(),
+        }
+    }
+
+    /// The name of the gambit.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The gambit's difficulty.
+    pub fn difficulty(&self) -> u8 {
+        self.difficulty.get()
+    }
+
+    /// What the gambit does if it succeeds.
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+}
+
+/// The gambits a combatant can attempt. Grapples have their own resolver;
+/// see [GrappleGambit](super::GrappleGambit).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GambitKind {
+    /// Knocks a weapon out of the defender's hands. Difficulty 3. The
+    /// combat doesn't own the defender's character sheet, so a successful
+    /// disarm is returned as [AttackOutcome::disarm] for the caller to apply
+    /// to the defender.
+    Disarm(UnequipWeapon),
+    /// Draws the defender's attention, granting an ally Initiative equal to
+    /// the successes in excess of the difficulty. Difficulty 3.
+    Distract {
+        /// The combatant receiving the Initiative.
+        ally: String,
+    },
+    /// Knocks the defender off their mount, leaving them prone. Difficulty
+    /// 4.
+    Unhorse,
+    /// A gambit registered with the combat by the storyteller, by name.
+    Custom(String),
+}
+
+impl GambitKind {
+    /// The difficulty of the gambit. Custom gambits must be registered with
+    /// the combat.
+    pub fn difficulty(&self, combat: &Combat) -> Result<u8, CombatError> {
+        match self {
+            GambitKind::Disarm(_) | GambitKind::Distract { .. } => Ok(3),
+            GambitKind::Unhorse => Ok(4),
+            GambitKind::Custom(name) => combat
+                .custom_gambit(name.as_str())
+                .map(CustomGambit::difficulty)
+                .ok_or(CombatError::GambitNotFound),
+        }
+    }
+}
+
+/// A decisive gambit by one combatant against another: a decisive attack
+/// roll which, if it hits, is followed by an Initiative roll. If the
+/// Initiative roll's successes exceed the gambit's difficulty, the gambit
+/// succeeds. Either way, a gambit that hits costs Initiative equal to its
+/// difficulty + 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gambit {
+    pub(crate) attack: Attack,
+    pub(crate) kind: GambitKind,
+}
+
+impl Gambit {
+    /// Sets up a gambit. The attacker and defender must both be combatants
+    /// (by name) when the gambit is resolved.
+    pub fn new(
+        attacker: AttackProfile,
+        defender: DefenseProfile,
+        defense: Defense,
+        kind: GambitKind,
+    ) -> Self {
+        Self {
+            attack: Attack::new(attacker, defender, AttackType::Decisive, defense),
+            kind,
+        }
+    }
+
+    /// Adds the defense profile of the combatant defending the defender,
+    /// if any. This is required to resolve the gambit while the defender is
+    /// being protected.
+    pub fn with_protector(mut self, protector: DefenseProfile) -> Self {
+        self.attack = self.attack.with_protector(protector);
+        self
+    }
+
+    /// Rolls and resolves the gambit against the current state of combat.
+    /// Like an attack, the gambit must be able to reach the defender at
+    /// their current range, if one is set. The combat itself is not
+    /// modified; apply the outcome's mutations to do so, and apply its
+    /// disarm (if any) to the defender.
+    pub fn resolve(
+        &self,
+        combat: &Combat,
+        roller: &mut impl DiceRoller,
+    ) -> Result<AttackOutcome, CombatError> {
+        let difficulty = self.kind.difficulty(combat)?;
+        if let GambitKind::Distract { ally } = &self.kind {
+            combat.check_pair(self.attack.attacker.name(), ally.as_str())?;
+        }
+
+        let (mut outcome, extra) = roll_gambit(&self.attack, difficulty, combat, roller)?;
+        let Some(extra) = extra else {
+            return Ok(outcome);
+        };
+
+        match &self.kind {
+            GambitKind::Disarm(unequip) => {
+                outcome.disarm = Some(unequip.clone());
+            }
+            GambitKind::Distract { ally } => {
+                outcome
+                    .combat_mutations
+                    .push(CombatMutation::GainInitiative {
+                        name: ally.clone(),
+                        amount: extra,
+                    });
+            }
+            GambitKind::Unhorse => {
+                outcome.combat_mutations.push(CombatMutation::AddStatus {
+                    name: self.attack.defender.name.clone(),
+                    status: StatusEffect::Prone,
+                });
+            }
+            GambitKind::Custom(_) => {}
+        }
+        Ok(outcome)
+    }
+}
+
+/// Makes the attack and Initiative rolls for a gambit. If the gambit
+/// succeeds, also returns the Initiative roll's successes in excess of the
+/// difficulty.
+pub(crate) fn roll_gambit(
+    attack: &Attack,
+    difficulty: u8,
+    combat: &Combat,
+    roller: &mut impl DiceRoller,
+) -> Result<(AttackOutcome, Option<NonZeroU8>), CombatError> {
+    let mut roll = attack.roll(combat, roller)?;
+    if roll.threshold.is_none() {
+        return Ok((roll.finish(), None));
+    }
+    let outcome = &mut roll.outcome;
+    let attacker_initiative = roll.attacker_initiative;
+
+    // Initiative is at least 1 here, having been checked before rolling
+    let pool = attacker_initiative as u8;
+    let dice = roller.roll_dice(pool);
+    let successes = count_successes(&dice, false);
+    outcome.steps.push(AttackStep::GambitRoll {
+        pool,
+        dice,
+        successes,
+    });
+    let extra = NonZeroU8::new(successes.saturating_sub(difficulty));
+    outcome.steps.push(AttackStep::Gambit {
+        difficulty,
+        succeeded: extra.is_some(),
+    });
+
+    let cost = difficulty.saturating_add(1);
+    outcome.steps.push(AttackStep::InitiativeLost(cost));
+    outcome
+        .combat_mutations
+        .push(CombatMutation::LoseInitiative {
+            name: attack.attacker.name.clone(),
+            amount: NonZeroU8::new(cost).unwrap(),
+            attacker: None,
+        });
+
+    Ok((roll.finish(), extra))
+}
+
+impl Combat {
+    /// Gets a custom gambit registered with the combat by name.
+    pub fn custom_gambit(&self, name: &str) -> Option<&CustomGambit> {
+        self.custom_gambits
+            .iter()
+            .find(|gambit| gambit.name == name)
+    }
+
+    /// Iterates over the custom gambits registered with the combat.
+    pub fn custom_gambits(&self) -> impl Iterator<Item = &CustomGambit> + '_ {
+        self.custom_gambits.iter()
+    }
+
+    /// Registers a custom gambit. Gambit names must be unique.
+    pub fn add_custom_gambit(&mut self, gambit: &CustomGambit) -> Result<&mut Self, CombatError> {
+        if self.custom_gambit(gambit.name()).is_some() {
+            return Err(CombatError::DuplicateGambit);
+        }
+        self.custom_gambits.push(gambit.clone());
+        Ok(self)
+    }
+
+    /// Removes a custom gambit.
+    pub fn remove_custom_gambit(&mut self, name: &str) -> Result<&mut Self, CombatError> {
+        let index = self
+            .custom_gambits
+            .iter()
+            .position(|gambit| gambit.name == name)
+            .ok_or(CombatError::GambitNotFound)?;
+        self.custom_gambits.remove(index);
+        Ok(self)
+    }
+}
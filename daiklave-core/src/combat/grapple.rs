@@ -0,0 +1,305 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    abilities::AbilityNameVanilla, attributes::AttributeName, weapons::weapon::RangeBand, Character,
+};
+
+use super::{
+    attack::{
+        apply_modifier, best_martial_arts_dots, count_successes, wound_penalty_dice, Attack,
+        AttackOutcome, AttackProfile, AttackStep, AttackType, Defense, DefenseProfile, DiceRoller,
+    },
+    gambit::roll_gambit,
+    Combat, CombatError, CombatMutation, Combatant, StatusEffect, StatusName,
+};
+
+/// The difficulty of the grapple gambit.
+pub const GRAPPLE_DIFFICULTY: u8 = 2;
+
+/// The actions a grappler can take on their turn while they have control
+/// rounds remaining. Each turn, the grappler may take one of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GrappleAction {
+    /// Holds the victim fast, preventing them from acting except to break
+    /// free until the grappler's next turn.
+    Restrain,
+    /// Attacks the victim with a withering or decisive attack. The attack
+    /// itself is resolved separately, with the victim's Defense reduced by
+    /// the clinch.
+    Savage,
+    /// Hurls the victim to Short range, leaving them prone and ending the
+    /// grapple.
+    Throw,
+    /// Slams the victim into the ground, leaving them prone and ending the
+    /// grapple. Requires at least two control rounds.
+    Slam,
+    /// Lets the victim go.
+    Release,
+}
+
+/// A grapple gambit, with difficulty 2. If the gambit succeeds, the
+/// grappler and victim make an opposed control roll, and the grappler gains
+/// a control round for each success they win by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrappleGambit {
+    pub(crate) attack: Attack,
+    pub(crate) control_pool: u8,
+    pub(crate) resist_pool: u8,
+}
+
+impl GrappleGambit {
+    /// Sets up a grapple gambit. The control pool is the grappler's roll to
+    /// establish control; the resist pool is the victim's opposing roll.
+    pub fn new(
+        attacker: AttackProfile,
+        defender: DefenseProfile,
+        defense: Defense,
+        control_pool: u8,
+        resist_pool: u8,
+    ) -> Self {
+        Self {
+            attack: Attack::new(attacker, defender, AttackType::Decisive, defense),
+            control_pool,
+            resist_pool,
+        }
+    }
+
+    /// Adds the defense profile of the combatant defending the victim, if
+    /// any. This is required to resolve the gambit while the victim is
+    /// being protected.
+    pub fn with_protector(mut self, protector: DefenseProfile) -> Self {
+        self.attack = self.attack.with_protector(protector);
+        self
+    }
+
+    /// The grappler's control pool for a character: Strength + Brawl (or
+    /// their best Martial Arts style), including wound penalties.
+    pub fn control_pool(character: &Character<'_>) -> u8 {
+        let strength = character.attributes().get(AttributeName::Strength).dots();
+        Self::grapple_pool(character, strength)
+    }
+
+    /// A victim's resist pool for a character: the higher of Strength or
+    /// Dexterity, plus Brawl (or their best Martial Arts style), including
+    /// wound penalties.
+    pub fn resist_pool(character: &Character<'_>) -> u8 {
+        let attributes = character.attributes();
+        let attribute = attributes
+            .get(AttributeName::Strength)
+            .dots()
+            .max(attributes.get(AttributeName::Dexterity).dots());
+        Self::grapple_pool(character, attribute)
+    }
+
+    fn grapple_pool(character: &Character<'_>, attribute: u8) -> u8 {
+        let ability = character
+            .abilities()
+            .get_vanilla(AbilityNameVanilla::Brawl)
+            .dots()
+            .max(best_martial_arts_dots(character, None));
+        let wound_penalty = wound_penalty_dice(character.health().current_wound_penalty());
+        (attribute + ability).saturating_sub(wound_penalty)
+    }
+
+    /// Rolls and resolves the gambit against the current state of combat.
+    /// The combatants must be at Close range (or have no range set), and
+    /// neither may already be in a grapple. The combat itself is not
+    /// modified; apply the outcome's mutations to do so.
+    pub fn resolve(
+        &self,
+        combat: &Combat,
+        roller: &mut impl DiceRoller,
+    ) -> Result<AttackOutcome, CombatError> {
+        let attacker = &self.attack.attacker;
+        let defender = &self.attack.defender;
+        if combat
+            .range(attacker.name(), defender.name())
+            .is_some_and(|band| band != RangeBand::Close)
+        {
+            return Err(CombatError::OutOfRange);
+        }
+        if [attacker.name(), defender.name()]
+            .into_iter()
+            .any(|name| combat.grapple_victim(name).is_some() || combat.grappler_of(name).is_some())
+        {
+            return Err(CombatError::AlreadyGrappling);
+        }
+
+        let (mut outcome, extra) = roll_gambit(&self.attack, GRAPPLE_DIFFICULTY, combat, roller)?;
+
+        if extra.is_some() {
+            let attacking = combat
+                .combatant(attacker.name())
+                .ok_or(CombatError::NotFound)?;
+            let defending = combat
+                .combatant(defender.name())
+                .ok_or(CombatError::NotFound)?;
+            let control_pool = apply_modifier(self.control_pool, attacking.pool_modifier());
+            let dice = roller.roll_dice(control_pool);
+            let control = count_successes(&dice, true);
+            outcome.steps.push(AttackStep::ControlRoll {
+                pool: control_pool,
+                dice,
+                successes: control,
+            });
+
+            let resist_pool = apply_modifier(self.resist_pool, defending.pool_modifier());
+            let dice = roller.roll_dice(resist_pool);
+            let resist = count_successes(&dice, true);
+            outcome.steps.push(AttackStep::ResistRoll {
+                pool: resist_pool,
+                dice,
+                successes: resist,
+            });
+
+            let control_rounds = control.saturating_sub(resist);
+            outcome
+                .steps
+                .push(AttackStep::ControlRounds(control_rounds));
+            if control_rounds > 0 {
+                outcome.combat_mutations.push(CombatMutation::AddStatus {
+                    name: defender.name.clone(),
+                    status: StatusEffect::Clinched {
+                        controller: attacker.name.clone(),
+                        control_rounds,
+                    },
+                });
+            }
+        }
+
+        Ok(outcome)
+    }
+}
+
+impl Combat {
+    /// The combatant being held by the grappler, if any.
+    pub fn grapple_victim(&self, grappler: &str) -> Option<&Combatant> {
+        self.combatants.iter().find(|combatant| {
+            combatant.statuses().any(|effect| {
+                matches!(effect, StatusEffect::Clinched { controller, .. } if controller == grappler)
+            })
+        })
+    }
+
+    /// The combatant holding the victim in a grapple, if any.
+    pub fn grappler_of(&self, victim: &str) -> Option<&Combatant> {
+        match self.combatant(victim)?.status(StatusName::Clinched)? {
+            StatusEffect::Clinched { controller, .. } => self.combatant(controller.as_str()),
+            _ => None,
+        }
+    }
+
+    /// The grapple actions the combatant may legally take right now. This is
+    /// empty unless it is the combatant's turn, they are controlling a
+    /// grapple, and they have not already taken a grapple action this turn.
+    pub fn grapple_actions(&self, grappler: &str) -> Vec<GrappleAction> {
+        if self.grapple_action_taken
+            || self
+                .current_turn()
+                .is_none_or(|combatant| combatant.name != grappler)
+        {
+            return Vec::new();
+        }
+
+        let Some(StatusEffect::Clinched { control_rounds, .. }) = self
+            .grapple_victim(grappler)
+            .and_then(|victim| victim.status(StatusName::Clinched))
+        else {
+            return Vec::new();
+        };
+
+        let mut actions = vec![
+            GrappleAction::Restrain,
+            GrappleAction::Savage,
+            GrappleAction::Throw,
+        ];
+        if *control_rounds >= 2 {
+            actions.push(GrappleAction::Slam);
+        }
+        actions.push(GrappleAction::Release);
+        actions
+    }
+
+    /// Takes a grapple action on the grappler's turn.
+    pub fn grapple_action(
+        &mut self,
+        grappler: &str,
+        action: GrappleAction,
+    ) -> Result<&mut Self, CombatError> {
+        if !self.is_started() {
+            return Err(CombatError::NotStarted);
+        }
+        let victim = self
+            .grapple_victim(grappler)
+            .ok_or(CombatError::NotGrappling)?
+            .name
+            .clone();
+        if self
+            .current_turn()
+            .is_none_or(|combatant| combatant.name != grappler)
+        {
+            return Err(CombatError::NotTheirTurn);
+        }
+        if !self.grapple_actions(grappler).contains(&action) {
+            return Err(if self.grapple_action_taken {
+                CombatError::AlreadyActed
+            } else {
+                CombatError::InsufficientControl
+            });
+        }
+
+        match action {
+            GrappleAction::Restrain => {
+                self.add_status(victim.as_str(), &StatusEffect::Restrained)?;
+            }
+            GrappleAction::Savage => {}
+            GrappleAction::Throw => {
+                self.release_grapple(victim.as_str())?;
+                self.add_status(victim.as_str(), &StatusEffect::Prone)?;
+                self.positions
+                    .set_range(grappler, victim.as_str(), RangeBand::Short);
+            }
+            GrappleAction::Slam => {
+                self.release_grapple(victim.as_str())?;
+                self.add_status(victim.as_str(), &StatusEffect::Prone)?;
+            }
+            GrappleAction::Release => {
+                self.release_grapple(victim.as_str())?;
+            }
+        }
+        self.grapple_action_taken = true;
+        Ok(self)
+    }
+
+    /// Records a clinched combatant's attempt to break free, removing one
+    /// control round for each success they win the opposed roll by. The
+    /// grapple ends if no control rounds remain.
+    pub fn break_free(&mut self, victim: &str, successes: u8) -> Result<&mut Self, CombatError> {
+        let combatant = self.combatant_mut(victim)?;
+        let Some(StatusEffect::Clinched { control_rounds, .. }) = combatant
+            .statuses
+            .iter_mut()
+            .map(|active| &mut active.effect)
+            .find(|effect| effect.name() == StatusName::Clinched)
+        else {
+            return Err(CombatError::NotGrappling);
+        };
+
+        *control_rounds = control_rounds.saturating_sub(successes);
+        if *control_rounds == 0 {
+            self.release_grapple(victim)?;
+        }
+        Ok(self)
+    }
+
+    /// Ends any grapple holding the victim.
+    pub(crate) fn release_grapple(&mut self, victim: &str) -> Result<&mut Self, CombatError> {
+        self.combatant_mut(victim)?.statuses.retain(|active| {
+            !matches!(
+                active.effect,
+                StatusEffect::Clinched { .. } | StatusEffect::Restrained
+            )
+        });
+        Ok(self)
+    }
+}
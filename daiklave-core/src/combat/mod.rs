@@ -0,0 +1,631 @@
+/// Resolution of withering and decisive attacks.
+pub mod attack;
+mod combatant;
+mod error;
+/// Disarm, distract, unhorse, and custom gambits.
+pub mod gambit;
+/// Grapple gambits, control rounds, and grapple actions.
+pub mod grapple;
+mod mutation;
+mod position;
+mod status;
+
+pub use combatant::{AddCombatant, Combatant, CombatantKind};
+pub use error::CombatError;
+pub use gambit::{CustomGambit, Gambit, GambitKind};
+pub use grapple::{GrappleAction, GrappleGambit};
+pub use mutation::CombatMutation;
+pub use position::MoveDirection;
+pub use status::{CoverLevel, StatusEffect, StatusExpiry, StatusName};
+
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+use crate::weapons::weapon::{AttackRange, RangeBand, Weapon};
+
+use self::{position::Positions, status::ActiveStatus};
+
+/// The Initiative a combatant adds to their Join Battle successes.
+const JOIN_BATTLE_BONUS: i8 = 3;
+/// The Initiative a combatant gains for crashing an opponent.
+const INITIATIVE_BREAK: i8 = 5;
+/// The Initiative a combatant returns to after a decisive attack, or after
+/// too long in Crash.
+pub(crate) const CRASH_RESET: i8 = 3;
+/// The number of turns a combatant can end in Crash before their Initiative
+/// resets.
+const CRASH_RECOVERY_TURNS: u8 = 3;
+
+fn initiative_amount(amount: u8) -> i8 {
+    i8::try_from(amount).unwrap_or(i8::MAX)
+}
+
+/// A combat encounter, tracking each participant's Initiative, the turn
+/// order, the current round, and the range bands between combatants.
+/// Combatants act in order of descending Initiative, with ties broken by
+/// their Join Battle roll.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Combat {
+    pub(crate) combatants: Vec<Combatant>,
+    pub(crate) round: u8,
+    pub(crate) turn_order: Vec<String>,
+    pub(crate) turn_index: usize,
+    pub(crate) positions: Positions,
+    pub(crate) grapple_action_taken: bool,
+    pub(crate) custom_gambits: Vec<CustomGambit>,
+}
+
+impl Combat {
+    /// Applies a mutation to the combat.
+    pub fn apply_mutation(&mut self, mutation: &CombatMutation) -> Result<&mut Self, CombatError> {
+        match mutation {
+            CombatMutation::AddCombatant(add_combatant) => self.add_combatant(add_combatant),
+            CombatMutation::RemoveCombatant(name) => self.remove_combatant(name.as_str()),
+            CombatMutation::JoinBattle { name, successes } => {
+                self.join_battle(name.as_str(), *successes)
+            }
+            CombatMutation::StartCombat => self.start_combat(),
+            CombatMutation::EndTurn => self.end_turn(),
+            CombatMutation::GainInitiative { name, amount } => {
+                self.gain_initiative(name.as_str(), amount.get())
+            }
+            CombatMutation::SpendInitiative { name, amount } => {
+                self.spend_initiative(name.as_str(), amount.get())
+            }
+            CombatMutation::LoseInitiative {
+                name,
+                amount,
+                attacker,
+            } => self.lose_initiative(name.as_str(), amount.get(), attacker.as_deref()),
+            CombatMutation::ResolveInitiativeShift { name, successes } => {
+                self.resolve_initiative_shift(name.as_str(), *successes)
+            }
+            CombatMutation::SetInitiative { name, initiative } => {
+                self.set_initiative(name.as_str(), *initiative)
+            }
+            CombatMutation::SetRange {
+                first,
+                second,
+                band,
+            } => self.set_range(first.as_str(), second.as_str(), *band),
+            CombatMutation::Move {
+                name,
+                target,
+                direction,
+            } => self.move_relative(name.as_str(), target.as_str(), *direction),
+            CombatMutation::Disengage { name, from } => {
+                self.disengage(name.as_str(), from.as_str())
+            }
+            CombatMutation::Rush { name, target } => self.rush(name.as_str(), target.as_str()),
+            CombatMutation::AddStatus { name, status } => self.add_status(name.as_str(), status),
+            CombatMutation::RemoveStatus { name, status } => {
+                self.remove_status(name.as_str(), *status)
+            }
+            CombatMutation::Grapple { name, action } => self.grapple_action(name.as_str(), *action),
+            CombatMutation::BreakFree { name, successes } => {
+                self.break_free(name.as_str(), *successes)
+            }
+            CombatMutation::AddCustomGambit(gambit) => self.add_custom_gambit(gambit),
+            CombatMutation::RemoveCustomGambit(name) => self.remove_custom_gambit(name.as_str()),
+            CombatMutation::EndCombat => self.end_combat(),
+        }
+    }
+
+    /// Gets a combatant by name.
+    pub fn combatant(&self, name: &str) -> Option<&Combatant> {
+        self.combatants
+            .iter()
+            .find(|combatant| combatant.name == name)
+    }
+
+    /// Iterates over all combatants, in the order they were added.
+    pub fn combatants(&self) -> impl Iterator<Item = &Combatant> + '_ {
+        self.combatants.iter()
+    }
+
+    /// True if combat has started.
+    pub fn is_started(&self) -> bool {
+        self.round > 0
+    }
+
+    /// The current round of combat, starting at 1, or 0 if combat has not
+    /// started.
+    pub fn round(&self) -> u8 {
+        self.round
+    }
+
+    /// The combatants acting this round, in turn order.
+    pub fn turn_order(&self) -> impl Iterator<Item = &Combatant> + '_ {
+        self.turn_order
+            .iter()
+            .filter_map(|name| self.combatant(name.as_str()))
+    }
+
+    /// The combatant whose turn it currently is, if combat has started.
+    pub fn current_turn(&self) -> Option<&Combatant> {
+        self.turn_order
+            .get(self.turn_index)
+            .and_then(|name| self.combatant(name.as_str()))
+    }
+
+    /// The range band between two combatants, if it has been set.
+    pub fn range(&self, first: &str, second: &str) -> Option<RangeBand> {
+        self.positions.range(first, second)
+    }
+
+    /// True if the rusher successfully rushed the target this round, and
+    /// will follow them if they move away.
+    pub fn is_rushing(&self, rusher: &str, target: &str) -> bool {
+        self.positions.is_rushing(rusher, target)
+    }
+
+    /// Determines how the attacker would use the weapon against the target
+    /// at their current range, returning the attack range and the weapon's
+    /// accuracy. At Close range, weapons that can be used in melee are;
+    /// otherwise they are thrown or fired. Returns an error if the weapon
+    /// can't reach the target.
+    pub fn attack_range(
+        &self,
+        attacker: &str,
+        target: &str,
+        weapon: &Weapon<'_>,
+    ) -> Result<(AttackRange, i8), CombatError> {
+        if self.combatant(attacker).is_none() || self.combatant(target).is_none() {
+            return Err(CombatError::NotFound);
+        }
+
+        match self.range(attacker, target).ok_or(CombatError::NoRange)? {
+            RangeBand::Close => weapon
+                .accuracy(AttackRange::Melee)
+                .map(|accuracy| (AttackRange::Melee, accuracy))
+                .or_else(|| {
+                    weapon
+                        .accuracy(AttackRange::Ranged(RangeBand::Close))
+                        .map(|accuracy| (AttackRange::Ranged(RangeBand::Close), accuracy))
+                }),
+            band => weapon
+                .accuracy(AttackRange::Ranged(band))
+                .map(|accuracy| (AttackRange::Ranged(band), accuracy)),
+        }
+        .ok_or(CombatError::OutOfRange)
+    }
+
+    /// Checks that an attack made at the given range can reach the target.
+    /// Combatants without a range set between them are assumed to be in
+    /// reach.
+    pub(crate) fn check_attack_range(
+        &self,
+        attacker: &str,
+        target: &str,
+        attack_range: AttackRange,
+    ) -> Result<(), CombatError> {
+        match (self.range(attacker, target), attack_range) {
+            (None, _) | (Some(RangeBand::Close), AttackRange::Melee) => Ok(()),
+            (Some(band), AttackRange::Ranged(attack_band)) if band == attack_band => Ok(()),
+            _ => Err(CombatError::OutOfRange),
+        }
+    }
+
+    fn combatant_mut(&mut self, name: &str) -> Result<&mut Combatant, CombatError> {
+        self.combatants
+            .iter_mut()
+            .find(|combatant| combatant.name == name)
+            .ok_or(CombatError::NotFound)
+    }
+
+    fn initiative_of(&self, name: &str) -> Result<i8, CombatError> {
+        self.combatant(name)
+            .ok_or(CombatError::NotFound)?
+            .initiative
+            .ok_or(CombatError::NotJoined)
+    }
+
+    /// Adds a participant to the combat.
+    pub fn add_combatant(
+        &mut self,
+        add_combatant: &AddCombatant,
+    ) -> Result<&mut Self, CombatError> {
+        if self.combatant(add_combatant.name.as_str()).is_some() {
+            return Err(CombatError::DuplicateCombatant);
+        }
+
+        self.combatants.push(Combatant {
+            name: add_combatant.name.clone(),
+            kind: add_combatant.kind,
+            join_battle_pool: add_combatant.join_battle_pool,
+            join_battle_result: None,
+            initiative: None,
+            crashed_turns: 0,
+            initiative_shift_pending: false,
+            statuses: Vec::new(),
+        });
+        Ok(self)
+    }
+
+    /// Removes a participant from the combat. If it is currently their turn,
+    /// the next combatant in order begins their turn.
+    pub fn remove_combatant(&mut self, name: &str) -> Result<&mut Self, CombatError> {
+        let index = self
+            .combatants
+            .iter()
+            .position(|combatant| combatant.name == name)
+            .ok_or(CombatError::NotFound)?;
+        self.combatants.remove(index);
+        self.positions.remove(name);
+        for combatant in self.combatants.iter_mut() {
+            combatant.statuses.retain(|active| match &active.effect {
+                StatusEffect::Clinched { controller, .. } => controller != name,
+                StatusEffect::DefendingOther(ward) => ward != name,
+                _ => true,
+            });
+            if combatant.status(StatusName::Clinched).is_none() {
+                combatant
+                    .statuses
+                    .retain(|active| active.effect != StatusEffect::Restrained);
+            }
+        }
+
+        if let Some(position) = self.turn_order.iter().position(|n| n == name) {
+            self.turn_order.remove(position);
+            if position < self.turn_index {
+                self.turn_index -= 1;
+            } else if position == self.turn_index {
+                self.begin_turn();
+            }
+        }
+        Ok(self)
+    }
+
+    /// Records a Join Battle roll. The combatant's Initiative becomes the
+    /// successes rolled plus 3.
+    pub fn join_battle(&mut self, name: &str, successes: u8) -> Result<&mut Self, CombatError> {
+        let combatant = self.combatant_mut(name)?;
+        if combatant.initiative.is_some() {
+            return Err(CombatError::AlreadyJoined);
+        }
+
+        combatant.join_battle_result = Some(successes);
+        combatant.set_initiative(initiative_amount(successes).saturating_add(JOIN_BATTLE_BONUS));
+        Ok(self)
+    }
+
+    /// Begins the first round of combat.
+    pub fn start_combat(&mut self) -> Result<&mut Self, CombatError> {
+        if self.is_started() {
+            return Err(CombatError::AlreadyStarted);
+        }
+
+        if self
+            .combatants
+            .iter()
+            .any(|combatant| combatant.initiative.is_none())
+        {
+            return Err(CombatError::NotJoined);
+        }
+
+        self.round = 1;
+        self.begin_round();
+        Ok(self)
+    }
+
+    /// Ends the current combatant's turn. A combatant who ends their turn
+    /// in Crash moves closer to crash recovery. Status effects lasting until
+    /// the end of their turn expire, and anyone they have clinched loses a
+    /// control round, breaking free when none remain.
+    pub fn end_turn(&mut self) -> Result<&mut Self, CombatError> {
+        if !self.is_started() {
+            return Err(CombatError::NotStarted);
+        }
+
+        if let Some(name) = self.turn_order.get(self.turn_index).cloned() {
+            let combatant = self.combatant_mut(name.as_str())?;
+            if combatant.is_crashed() {
+                combatant.crashed_turns = combatant.crashed_turns.saturating_add(1);
+            }
+            combatant.statuses.retain_mut(|active| {
+                if active.effect.expiry() == StatusExpiry::EndOfNextTurn {
+                    active.turn_ends_remaining = active.turn_ends_remaining.saturating_sub(1);
+                    active.turn_ends_remaining > 0
+                } else {
+                    true
+                }
+            });
+
+            for other in self.combatants.iter_mut() {
+                let mut released = false;
+                other
+                    .statuses
+                    .retain_mut(|active| match &mut active.effect {
+                        StatusEffect::Clinched {
+                            controller,
+                            control_rounds,
+                        } if *controller == name => {
+                            *control_rounds = control_rounds.saturating_sub(1);
+                            released = *control_rounds == 0;
+                            !released
+                        }
+                        _ => true,
+                    });
+                if released {
+                    other
+                        .statuses
+                        .retain(|active| active.effect != StatusEffect::Restrained);
+                }
+            }
+        }
+
+        self.turn_index += 1;
+        self.begin_turn();
+        Ok(self)
+    }
+
+    fn begin_round(&mut self) {
+        self.positions.end_rushes();
+        let mut order = self
+            .combatants
+            .iter()
+            .filter(|combatant| combatant.initiative.is_some())
+            .collect::<Vec<&Combatant>>();
+        order.sort_by_key(|combatant| {
+            (
+                Reverse(combatant.initiative),
+                Reverse(combatant.join_battle_result),
+                combatant.name.as_str(),
+            )
+        });
+        self.turn_order = order
+            .into_iter()
+            .map(|combatant| combatant.name.clone())
+            .collect();
+        self.turn_index = 0;
+        self.begin_turn();
+    }
+
+    fn begin_turn(&mut self) {
+        if self.turn_order.is_empty() {
+            return;
+        }
+
+        if self.turn_index >= self.turn_order.len() {
+            self.round = self.round.saturating_add(1);
+            self.begin_round();
+            return;
+        }
+
+        let name = self.turn_order[self.turn_index].clone();
+        self.grapple_action_taken = false;
+        if let Some(victim) = self
+            .grapple_victim(name.as_str())
+            .map(|victim| victim.name.clone())
+        {
+            if let Ok(victim) = self.combatant_mut(victim.as_str()) {
+                victim
+                    .statuses
+                    .retain(|active| active.effect != StatusEffect::Restrained);
+            }
+        }
+        if let Ok(combatant) = self.combatant_mut(name.as_str()) {
+            if combatant.is_crashed() && combatant.crashed_turns >= CRASH_RECOVERY_TURNS {
+                combatant.set_initiative(CRASH_RESET);
+            }
+            combatant
+                .statuses
+                .retain(|active| active.effect.expiry() != StatusExpiry::StartOfNextTurn);
+        }
+    }
+
+    /// Increases a combatant's Initiative.
+    pub fn gain_initiative(&mut self, name: &str, amount: u8) -> Result<&mut Self, CombatError> {
+        let initiative = self.initiative_of(name)?;
+        self.combatant_mut(name)?
+            .set_initiative(initiative.saturating_add(initiative_amount(amount)));
+        Ok(self)
+    }
+
+    /// Spends Initiative, such as for a Charm's Initiative cost. Combatants
+    /// cannot spend more Initiative than they have.
+    pub fn spend_initiative(&mut self, name: &str, amount: u8) -> Result<&mut Self, CombatError> {
+        let initiative = self.initiative_of(name)?;
+        if i16::from(initiative) < i16::from(amount) {
+            return Err(CombatError::InsufficientInitiative);
+        }
+
+        self.combatant_mut(name)?
+            .set_initiative(initiative - initiative_amount(amount));
+        Ok(self)
+    }
+
+    /// Removes Initiative from a combatant. If this crashes them and an
+    /// attacker is specified, the attacker gains 5 Initiative (Initiative
+    /// Break). If the crashed combatant had higher Initiative than the
+    /// attacker, the attacker is also owed an Initiative Shift.
+    pub fn lose_initiative(
+        &mut self,
+        name: &str,
+        amount: u8,
+        attacker: Option<&str>,
+    ) -> Result<&mut Self, CombatError> {
+        let before = self.initiative_of(name)?;
+        let attacker_initiative = attacker
+            .map(|attacker| self.initiative_of(attacker))
+            .transpose()?;
+
+        let after = before.saturating_sub(initiative_amount(amount));
+        self.combatant_mut(name)?.set_initiative(after);
+
+        if let (Some(attacker), Some(attacker_initiative)) = (attacker, attacker_initiative) {
+            if before >= 1 && after < 1 {
+                let attacker = self.combatant_mut(attacker)?;
+                attacker.set_initiative(attacker_initiative.saturating_add(INITIATIVE_BREAK));
+                if before > attacker_initiative {
+                    attacker.initiative_shift_pending = true;
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Resolves a pending Initiative Shift, adding the successes of a new
+    /// Join Battle roll to the combatant's Initiative. If combat is ongoing,
+    /// the combatant takes the next turn.
+    pub fn resolve_initiative_shift(
+        &mut self,
+        name: &str,
+        successes: u8,
+    ) -> Result<&mut Self, CombatError> {
+        let combatant = self.combatant_mut(name)?;
+        if !combatant.initiative_shift_pending {
+            return Err(CombatError::NoInitiativeShift);
+        }
+        combatant.initiative_shift_pending = false;
+        let initiative = combatant.initiative.unwrap_or_default();
+        combatant.set_initiative(initiative.saturating_add(initiative_amount(successes)));
+
+        if self.is_started() {
+            let next = (self.turn_index + 1).min(self.turn_order.len());
+            if let Some(position) = self.turn_order[next..].iter().position(|n| n == name) {
+                self.turn_order.remove(next + position);
+            }
+            self.turn_order.insert(next, name.to_owned());
+        }
+        Ok(self)
+    }
+
+    /// Sets a combatant's Initiative directly.
+    pub fn set_initiative(&mut self, name: &str, initiative: i8) -> Result<&mut Self, CombatError> {
+        self.combatant_mut(name)?.set_initiative(initiative);
+        Ok(self)
+    }
+
+    /// Ends the combat, clearing Initiative, Crash, status effects, and turn
+    /// order for all combatants.
+    pub fn end_combat(&mut self) -> Result<&mut Self, CombatError> {
+        for combatant in self.combatants.iter_mut() {
+            combatant.join_battle_result = None;
+            combatant.initiative = None;
+            combatant.crashed_turns = 0;
+            combatant.initiative_shift_pending = false;
+            combatant.statuses.clear();
+        }
+        self.round = 0;
+        self.turn_order.clear();
+        self.turn_index = 0;
+        self.positions.clear();
+        self.grapple_action_taken = false;
+        Ok(self)
+    }
+
+    /// Applies a status effect to a combatant, replacing any existing effect
+    /// of the same kind. Onslaught penalties stack instead.
+    pub fn add_status(
+        &mut self,
+        name: &str,
+        status: &StatusEffect,
+    ) -> Result<&mut Self, CombatError> {
+        match status {
+            StatusEffect::Clinched { controller, .. } => self.check_pair(name, controller)?,
+            StatusEffect::DefendingOther(ward) => self.check_pair(name, ward)?,
+            _ => {}
+        }
+
+        let is_current_turn = self
+            .current_turn()
+            .is_some_and(|combatant| combatant.name == name);
+        let combatant = self.combatant_mut(name)?;
+
+        if let StatusEffect::Onslaught(penalty) = status {
+            if let Some(StatusEffect::Onslaught(existing)) = combatant
+                .statuses
+                .iter_mut()
+                .map(|active| &mut active.effect)
+                .find(|effect| effect.name() == StatusName::Onslaught)
+            {
+                *existing = existing.saturating_add(*penalty);
+                return Ok(self);
+            }
+        }
+
+        combatant
+            .statuses
+            .retain(|active| active.effect.name() != status.name());
+        combatant.statuses.push(ActiveStatus {
+            effect: status.clone(),
+            turn_ends_remaining: if is_current_turn { 2 } else { 1 },
+        });
+        Ok(self)
+    }
+
+    /// Removes a status effect from a combatant, such as by standing up from
+    /// prone or using an aimed attack.
+    pub fn remove_status(
+        &mut self,
+        name: &str,
+        status: StatusName,
+    ) -> Result<&mut Self, CombatError> {
+        self.combatant_mut(name)?
+            .statuses
+            .retain(|active| active.effect.name() != status);
+        Ok(self)
+    }
+
+    /// The combatant defending the ward, if any.
+    pub fn protector(&self, ward: &str) -> Option<&Combatant> {
+        self.combatants.iter().find(|combatant| {
+            combatant.statuses().any(|effect| {
+                matches!(effect, StatusEffect::DefendingOther(defended) if defended == ward)
+            })
+        })
+    }
+
+    pub(crate) fn check_pair(&self, first: &str, second: &str) -> Result<(), CombatError> {
+        if first == second || self.combatant(first).is_none() || self.combatant(second).is_none() {
+            Err(CombatError::NotFound)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sets the range band between two combatants. Ranges to other
+    /// combatants are unchanged.
+    pub fn set_range(
+        &mut self,
+        first: &str,
+        second: &str,
+        band: RangeBand,
+    ) -> Result<&mut Self, CombatError> {
+        self.check_pair(first, second)?;
+        self.positions.set_range(first, second, band);
+        Ok(self)
+    }
+
+    /// Moves one range band toward or away from another combatant. A
+    /// combatant at Close range must disengage rather than moving away, and
+    /// a combatant who has been rushed is followed by the rusher.
+    pub fn move_relative(
+        &mut self,
+        name: &str,
+        target: &str,
+        direction: MoveDirection,
+    ) -> Result<&mut Self, CombatError> {
+        self.check_pair(name, target)?;
+        self.positions.move_relative(name, target, direction)?;
+        Ok(self)
+    }
+
+    /// Records a successful disengage, moving from Close to Short range.
+    pub fn disengage(&mut self, name: &str, from: &str) -> Result<&mut Self, CombatError> {
+        self.check_pair(name, from)?;
+        self.positions.disengage(name, from)?;
+        Ok(self)
+    }
+
+    /// Records a successful rush against a target at Short or Medium range.
+    /// The rusher closes one range band and, for the rest of the round,
+    /// follows the target if it moves away.
+    pub fn rush(&mut self, name: &str, target: &str) -> Result<&mut Self, CombatError> {
+        self.check_pair(name, target)?;
+        self.positions.rush(name, target)?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,151 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use crate::weapons::weapon::RangeBand;
+
+use super::{AddCombatant, CustomGambit, GrappleAction, MoveDirection, StatusEffect, StatusName};
+
+/// The ways a combat can be atomically updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CombatMutation {
+    /// Adds a participant to the combat. Combatants added after combat has
+    /// started act from the following round, once they have joined battle.
+    AddCombatant(AddCombatant),
+    /// Removes a participant from the combat.
+    RemoveCombatant(String),
+    /// Records a combatant's Join Battle roll, setting their Initiative to
+    /// the successes rolled plus 3.
+    JoinBattle {
+        /// The name of the combatant.
+        name: String,
+        /// The successes rolled on the Join Battle pool.
+        successes: u8,
+    },
+    /// Begins the first round of combat. Every combatant must have joined
+    /// battle.
+    StartCombat,
+    /// Ends the current combatant's turn, advancing to the next combatant
+    /// (and the next round, if all have acted).
+    EndTurn,
+    /// Increases a combatant's Initiative.
+    GainInitiative {
+        /// The name of the combatant.
+        name: String,
+        /// The Initiative gained.
+        amount: NonZeroU8,
+    },
+    /// Spends Initiative voluntarily, such as to pay for a Charm with an
+    /// Initiative cost. Combatants cannot spend more than they have.
+    SpendInitiative {
+        /// The name of the combatant.
+        name: String,
+        /// The Initiative spent.
+        amount: NonZeroU8,
+    },
+    /// Removes Initiative from a combatant, such as from a withering attack.
+    /// If an attacker is specified and this crashes the combatant, the
+    /// attacker gains the Initiative Break bonus and, if the combatant had
+    /// higher Initiative, an Initiative Shift.
+    LoseInitiative {
+        /// The name of the combatant losing Initiative.
+        name: String,
+        /// The Initiative lost.
+        amount: NonZeroU8,
+        /// The combatant responsible, if any.
+        attacker: Option<String>,
+    },
+    /// Resolves a pending Initiative Shift with the successes of a new Join
+    /// Battle roll, which are added to the combatant's Initiative. The
+    /// combatant takes the next turn.
+    ResolveInitiativeShift {
+        /// The name of the combatant.
+        name: String,
+        /// The successes rolled on the Join Battle pool.
+        successes: u8,
+    },
+    /// Sets a combatant's Initiative directly.
+    SetInitiative {
+        /// The name of the combatant.
+        name: String,
+        /// The new Initiative value, which may be negative.
+        initiative: i8,
+    },
+    /// Sets the range band between two combatants.
+    SetRange {
+        /// One of the combatants.
+        first: String,
+        /// The other combatant.
+        second: String,
+        /// The distance between them.
+        band: RangeBand,
+    },
+    /// Moves one range band toward or away from another combatant.
+    Move {
+        /// The combatant moving.
+        name: String,
+        /// The combatant they are moving relative to.
+        target: String,
+        /// Whether they are closing or retreating.
+        direction: MoveDirection,
+    },
+    /// Records a successful disengage from Close range, moving to Short.
+    Disengage {
+        /// The combatant disengaging.
+        name: String,
+        /// The combatant they are escaping.
+        from: String,
+    },
+    /// Records a successful rush, closing one range band on a target at
+    /// Short or Medium range and following them for the rest of the round.
+    Rush {
+        /// The combatant rushing.
+        name: String,
+        /// The target of the rush.
+        target: String,
+    },
+    /// Applies a status effect to a combatant.
+    AddStatus {
+        /// The name of the combatant.
+        name: String,
+        /// The status effect to apply.
+        status: StatusEffect,
+    },
+    /// Removes a status effect from a combatant before it expires.
+    RemoveStatus {
+        /// The name of the combatant.
+        name: String,
+        /// The kind of status effect to remove.
+        status: StatusName,
+    },
+    /// Takes a grapple action on the grappler's turn. Each grappler may take
+    /// one grapple action per turn.
+    Grapple {
+        /// The combatant controlling the grapple.
+        name: String,
+        /// The action taken.
+        action: GrappleAction,
+    },
+    /// Records a clinched combatant's attempt to break free, removing one
+    /// control round per success by which they won the opposed roll.
+    BreakFree {
+        /// The combatant trying to escape.
+        name: String,
+        /// The successes in excess of the grappler's.
+        successes: u8,
+    },
+    /// Registers a custom gambit for use in this combat.
+    AddCustomGambit(CustomGambit),
+    /// Removes a custom gambit by name.
+    RemoveCustomGambit(String),
+    /// Ends the combat, clearing all Initiative, turn order, status effects,
+    /// and range bands. Combatants and custom gambits are retained for future
+    /// combats.
+    EndCombat,
+}
+
+impl From<AddCombatant> for CombatMutation {
+    fn from(add_combatant: AddCombatant) -> Self {
+        Self::AddCombatant(add_combatant)
+    }
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+use crate::weapons::weapon::RangeBand;
+
+use super::CombatError;
+
+/// The direction of a move action, relative to another combatant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MoveDirection {
+    /// Closing one range band toward the other combatant.
+    Toward,
+    /// Retreating one range band away from the other combatant.
+    Away,
+}
+
+fn closer(band: RangeBand) -> Option<RangeBand> {
+    match band {
+        RangeBand::Close => None,
+        RangeBand::Short => Some(RangeBand::Close),
+        RangeBand::Medium => Some(RangeBand::Short),
+        RangeBand::Long => Some(RangeBand::Medium),
+        RangeBand::Extreme => Some(RangeBand::Long),
+    }
+}
+
+fn farther(band: RangeBand) -> Option<RangeBand> {
+    match band {
+        RangeBand::Close => Some(RangeBand::Short),
+        RangeBand::Short => Some(RangeBand::Medium),
+        RangeBand::Medium => Some(RangeBand::Long),
+        RangeBand::Long => Some(RangeBand::Extreme),
+        RangeBand::Extreme => None,
+    }
+}
+
+/// The range band between each pair of combatants, plus any active rushes.
+/// Ranges are only known for pairs that have been set explicitly; a move
+/// changes the range between the mover and their target, and no other.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Positions {
+    ranges: Vec<(String, String, RangeBand)>,
+    rushes: Vec<(String, String)>,
+}
+
+impl Positions {
+    fn pair_mut(&mut self, first: &str, second: &str) -> Option<&mut RangeBand> {
+        self.ranges
+            .iter_mut()
+            .find(|(a, b, _)| (a == first && b == second) || (a == second && b == first))
+            .map(|(_, _, band)| band)
+    }
+
+    pub fn range(&self, first: &str, second: &str) -> Option<RangeBand> {
+        self.ranges
+            .iter()
+            .find(|(a, b, _)| (a == first && b == second) || (a == second && b == first))
+            .map(|(_, _, band)| *band)
+    }
+
+    pub fn is_rushing(&self, rusher: &str, target: &str) -> bool {
+        self.rushes
+            .iter()
+            .any(|(r, t)| r.as_str() == rusher && t.as_str() == target)
+    }
+
+    /// Sets the range band between two combatants, leaving the ranges to
+    /// everyone else unchanged.
+    pub fn set_range(&mut self, first: &str, second: &str, band: RangeBand) {
+        if let Some(existing) = self.pair_mut(first, second) {
+            *existing = band;
+        } else {
+            self.ranges
+                .push((first.to_owned(), second.to_owned(), band));
+        }
+    }
+
+    pub fn move_relative(
+        &mut self,
+        name: &str,
+        target: &str,
+        direction: MoveDirection,
+    ) -> Result<(), CombatError> {
+        let band = self.range(name, target).ok_or(CombatError::NoRange)?;
+        let new_band = match direction {
+            MoveDirection::Toward => closer(band).ok_or(CombatError::InvalidMove)?,
+            MoveDirection::Away => {
+                if band == RangeBand::Close {
+                    return Err(CombatError::Engaged);
+                }
+                farther(band).ok_or(CombatError::InvalidMove)?
+            }
+        };
+
+        // A character moving ends their own rush
+        self.rushes.retain(|(rusher, _)| rusher != name);
+
+        // A successful rusher follows their target as it moves away
+        if direction == MoveDirection::Away && self.is_rushing(target, name) {
+            return Ok(());
+        }
+        self.set_range(name, target, new_band);
+        Ok(())
+    }
+
+    pub fn disengage(&mut self, name: &str, from: &str) -> Result<(), CombatError> {
+        if self.range(name, from).ok_or(CombatError::NoRange)? != RangeBand::Close {
+            return Err(CombatError::InvalidMove);
+        }
+
+        self.rushes.retain(|(rusher, _)| rusher != name);
+        self.set_range(name, from, RangeBand::Short);
+        Ok(())
+    }
+
+    pub fn rush(&mut self, name: &str, target: &str) -> Result<(), CombatError> {
+        let band = self.range(name, target).ok_or(CombatError::NoRange)?;
+        let new_band = match band {
+            RangeBand::Short | RangeBand::Medium => closer(band).unwrap(),
+            RangeBand::Close => return Err(CombatError::InvalidMove),
+            RangeBand::Long | RangeBand::Extreme => return Err(CombatError::TooFarToRush),
+        };
+
+        self.set_range(name, target, new_band);
+        self.rushes.retain(|(rusher, _)| rusher != name);
+        self.rushes.push((name.to_owned(), target.to_owned()));
+        Ok(())
+    }
+
+    pub fn end_rushes(&mut self) {
+        self.rushes.clear();
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.ranges.retain(|(a, b, _)| a != name && b != name);
+        self.rushes
+            .retain(|(rusher, target)| rusher != name && target != name);
+    }
+
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+        self.rushes.clear();
+    }
+}
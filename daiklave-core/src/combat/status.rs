@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+/// How much protection a combatant's cover provides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoverLevel {
+    /// Partial cover, like a low wall. +1 Defense.
+    Light,
+    /// Substantial cover, like an arrow slit. +2 Defense.
+    Heavy,
+    /// Complete cover. The combatant cannot be targeted by attacks until
+    /// they leave it.
+    Full,
+}
+
+/// When a status effect ends on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusExpiry {
+    /// Lasts until removed, such as by standing up or leaving cover.
+    Removed,
+    /// Ends at the start of the combatant's next turn.
+    StartOfNextTurn,
+    /// Ends at the end of the combatant's next turn, or when used.
+    EndOfNextTurn,
+    /// Ends when the grappler's control rounds run out.
+    ControlRounds,
+}
+
+/// The kind of a status effect, without its details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusName {
+    /// See [StatusEffect::Prone].
+    Prone,
+    /// See [StatusEffect::Cover].
+    Cover,
+    /// See [StatusEffect::Aiming].
+    Aiming,
+    /// See [StatusEffect::FullDefense].
+    FullDefense,
+    /// See [StatusEffect::Clinched].
+    Clinched,
+    /// See [StatusEffect::Restrained].
+    Restrained,
+    /// See [StatusEffect::Onslaught].
+    Onslaught,
+    /// See [StatusEffect::DefendingOther].
+    DefendingOther,
+}
+
+/// A transient combat condition affecting a combatant.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusEffect {
+    /// Knocked down or lying flat. -3 to action pools and -1 Defense until
+    /// the combatant rises.
+    Prone,
+    /// Behind cover, until the combatant leaves it.
+    Cover(CoverLevel),
+    /// Spent a turn aiming. +3 dice on the next attack, which must be made
+    /// by the end of the combatant's next turn.
+    Aiming,
+    /// Doing nothing but defending. +2 Defense until the start of the
+    /// combatant's next turn.
+    FullDefense,
+    /// Held in a grapple. -2 Defense until the grappler's control rounds
+    /// run out.
+    Clinched {
+        /// The combatant controlling the grapple.
+        controller: String,
+        /// The remaining control rounds, reduced at the end of each of the
+        /// controller's turns.
+        control_rounds: u8,
+    },
+    /// Held fast by a grappler who spent their turn restraining. The
+    /// combatant cannot act except to try to break free until the
+    /// grappler's next turn begins.
+    Restrained,
+    /// -1 Defense for each attack the combatant has faced since their last
+    /// turn began.
+    Onslaught(u8),
+    /// Protecting another combatant, whose attackers must contend with this
+    /// combatant's Parry if it is higher than the ward's Defense. The
+    /// protector suffers the onslaught penalty for those attacks. Lasts
+    /// until the start of the combatant's next turn.
+    DefendingOther(String),
+}
+
+impl StatusEffect {
+    /// The kind of the status effect.
+    pub fn name(&self) -> StatusName {
+        match self {
+            StatusEffect::Prone => StatusName::Prone,
+            StatusEffect::Cover(_) => StatusName::Cover,
+            StatusEffect::Aiming => StatusName::Aiming,
+            StatusEffect::FullDefense => StatusName::FullDefense,
+            StatusEffect::Clinched { .. } => StatusName::Clinched,
+            StatusEffect::Restrained => StatusName::Restrained,
+            StatusEffect::Onslaught(_) => StatusName::Onslaught,
+            StatusEffect::DefendingOther(_) => StatusName::DefendingOther,
+        }
+    }
+
+    /// When the status effect ends.
+    pub fn expiry(&self) -> StatusExpiry {
+        match self {
+            StatusEffect::Prone | StatusEffect::Cover(_) => StatusExpiry::Removed,
+            StatusEffect::Aiming => StatusExpiry::EndOfNextTurn,
+            StatusEffect::FullDefense
+            | StatusEffect::Onslaught(_)
+            | StatusEffect::DefendingOther(_) => StatusExpiry::StartOfNextTurn,
+            StatusEffect::Clinched { .. } | StatusEffect::Restrained => StatusExpiry::ControlRounds,
+        }
+    }
+
+    /// The bonus or penalty to the combatant's action pools.
+    pub fn pool_modifier(&self) -> i8 {
+        match self {
+            StatusEffect::Prone => -3,
+            StatusEffect::Aiming => 3,
+            _ => 0,
+        }
+    }
+
+    /// The bonus or penalty to the combatant's Parry and Evasion.
+    pub fn defense_modifier(&self) -> i8 {
+        match self {
+            StatusEffect::Prone => -1,
+            StatusEffect::Cover(CoverLevel::Light) => 1,
+            StatusEffect::Cover(CoverLevel::Heavy) => 2,
+            StatusEffect::FullDefense => 2,
+            StatusEffect::Clinched { .. } => -2,
+            StatusEffect::Onslaught(penalty) => -i8::try_from(*penalty).unwrap_or(i8::MAX),
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ActiveStatus {
+    pub effect: StatusEffect,
+    pub turn_ends_remaining: u8,
+}
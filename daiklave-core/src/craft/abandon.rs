@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to remove a crafting project, whether unfinished, failed, or
+/// completed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbandonCraftProject(pub String);
+
+impl From<AbandonCraftProject> for CharacterMutation {
+    fn from(abandon_craft_project: AbandonCraftProject) -> Self {
+        CharacterMutation::AbandonCraftProject(abandon_craft_project)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::extended_roll::ExtendedRoll;
+
+use super::{CraftProject, CraftProjectKind, StartCraftProject};
+
+/// A builder for a crafting project. Goal, terminus, difficulty, and
+/// interval default to the standard values for the project's kind.
+pub struct CraftProjectBuilder {
+    project: CraftProject,
+}
+
+impl CraftProjectBuilder {
+    pub(crate) fn new(name: String, focus: String, kind: CraftProjectKind) -> Self {
+        Self {
+            project: CraftProject {
+                name,
+                focus,
+                kind,
+                extended_roll: ExtendedRoll::new(
+                    kind.default_difficulty(),
+                    kind.default_goal(),
+                    kind.default_terminus(),
+                    kind.default_interval_days(),
+                ),
+            },
+        }
+    }
+
+    /// Sets the difficulty of each roll.
+    pub fn difficulty(mut self, difficulty: u8) -> Self {
+        self.project.extended_roll.difficulty = difficulty;
+        self
+    }
+
+    /// Sets the number of accumulated successes needed to complete the
+    /// project.
+    pub fn goal(mut self, goal: u16) -> Self {
+        self.project.extended_roll.goal = goal;
+        self
+    }
+
+    /// Sets the maximum number of rolls before the project fails.
+    pub fn terminus(mut self, terminus: u8) -> Self {
+        self.project.extended_roll.terminus = terminus;
+        self
+    }
+
+    /// Sets the number of days of work each roll represents.
+    pub fn interval_days(mut self, interval_days: u16) -> Self {
+        self.project.extended_roll.interval_days = interval_days;
+        self
+    }
+
+    /// Completes the project, creating a mutation to start it.
+    pub fn build(self) -> StartCraftProject {
+        StartCraftProject(self.project)
+    }
+}
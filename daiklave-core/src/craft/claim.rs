@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{artifact::AddArtifact, CharacterMutation};
+
+/// A mutation to turn a completed superior or legendary project into an
+/// artifact on the crafter's sheet, removing the project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimCraftedArtifact {
+    /// The name of the completed project.
+    pub project: String,
+    /// The artifact the project created.
+    pub artifact: AddArtifact,
+}
+
+impl From<ClaimCraftedArtifact> for CharacterMutation {
+    fn from(claim_crafted_artifact: ClaimCraftedArtifact) -> Self {
+        CharacterMutation::ClaimCraftedArtifact(claim_crafted_artifact)
+    }
+}
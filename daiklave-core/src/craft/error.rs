@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+/// An error related to crafting projects.
+#[derive(Debug, Error)]
+pub enum CraftError {
+    /// A claimed artifact must be rated the same as its project
+    #[error("Artifact rating does not match the project")]
+    ArtifactRatingMismatch,
+    /// Project names must be unique
+    #[error("A project with that name already exists")]
+    DuplicateProject,
+    /// Superior projects create artifacts rated from 2 to 5
+    #[error("Superior projects must be rated 2 to 5")]
+    InvalidArtifactRating,
+    /// The crafter needs dots in the Craft focus
+    #[error("No dots in that Craft focus")]
+    NoCraftDots,
+    /// Artifacts can only be claimed from superior or legendary projects
+    #[error("Only superior and legendary projects create artifacts")]
+    NotAnArtifactProject,
+    /// Can't roll for a project that has been completed or failed
+    #[error("Project is already finished")]
+    ProjectFinished,
+    /// Can't claim the result of a project that isn't complete
+    #[error("Project is not complete")]
+    ProjectNotComplete,
+    /// Project does not exist
+    #[error("Project not found")]
+    ProjectNotFound,
+}
@@ -0,0 +1,107 @@
+use std::num::NonZeroU16;
+
+use serde::{Deserialize, Serialize};
+
+use crate::experience::ExperiencePoolName;
+
+/// The scale of a crafting project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CraftProjectKind {
+    /// A mundane object of ordinary quality, completed in a single roll.
+    /// Awards Silver Crafting experience.
+    Basic,
+    /// A mundane object of exceptional quality or significance, completed in
+    /// a single roll. Awards Gold Crafting experience.
+    Major,
+    /// An artifact rated from 2 to 5, completed over an extended roll.
+    /// Awards White Crafting experience.
+    Superior(u8),
+    /// An artifact beyond rating, completed over an extended roll. Awards
+    /// White Crafting experience.
+    Legendary,
+}
+
+impl CraftProjectKind {
+    /// The default difficulty of each roll.
+    pub fn default_difficulty(&self) -> u8 {
+        match self {
+            CraftProjectKind::Basic => 1,
+            CraftProjectKind::Major => 3,
+            CraftProjectKind::Superior(_) | CraftProjectKind::Legendary => 5,
+        }
+    }
+
+    /// The default number of accumulated successes needed to complete the
+    /// project. Single-roll projects need only meet their difficulty.
+    pub fn default_goal(&self) -> u16 {
+        match self {
+            CraftProjectKind::Basic | CraftProjectKind::Major => self.default_difficulty().into(),
+            CraftProjectKind::Superior(rating) => match rating {
+                0..=2 => 50,
+                3 => 75,
+                4 => 100,
+                _ => 200,
+            },
+            CraftProjectKind::Legendary => 250,
+        }
+    }
+
+    /// The default maximum number of rolls before the project fails.
+    pub fn default_terminus(&self) -> u8 {
+        match self {
+            CraftProjectKind::Basic | CraftProjectKind::Major => 1,
+            CraftProjectKind::Superior(_) | CraftProjectKind::Legendary => 6,
+        }
+    }
+
+    /// The default number of days of work each roll represents.
+    pub fn default_interval_days(&self) -> u16 {
+        match self {
+            CraftProjectKind::Basic => 1,
+            CraftProjectKind::Major => 7,
+            CraftProjectKind::Superior(rating) => match rating {
+                0..=2 => 7,
+                3 => 14,
+                4 => 30,
+                _ => 60,
+            },
+            CraftProjectKind::Legendary => 90,
+        }
+    }
+
+    /// True if the project creates an artifact with the specified merit
+    /// dots. Superior projects create artifacts of their rating, and
+    /// legendary projects create N/A artifacts (6+ dots).
+    pub fn creates_artifact_rated(&self, merit_dots: u8) -> bool {
+        match self {
+            CraftProjectKind::Basic | CraftProjectKind::Major => false,
+            CraftProjectKind::Superior(rating) => merit_dots == *rating,
+            CraftProjectKind::Legendary => merit_dots >= 6,
+        }
+    }
+
+    /// The experience awarded for completing the project. Single-roll
+    /// projects award 2, plus an extra point if they are exceptional (three
+    /// or more successes over the difficulty). Superior projects award twice
+    /// their rating minus one, and legendary projects award 10.
+    pub fn experience_award(&self, exceptional: bool) -> (ExperiencePoolName, NonZeroU16) {
+        let bonus = u16::from(exceptional);
+        match self {
+            CraftProjectKind::Basic => (
+                ExperiencePoolName::SilverCraft,
+                NonZeroU16::new(2 + bonus).unwrap(),
+            ),
+            CraftProjectKind::Major => (
+                ExperiencePoolName::GoldCraft,
+                NonZeroU16::new(2 + bonus).unwrap(),
+            ),
+            CraftProjectKind::Superior(rating) => (
+                ExperiencePoolName::WhiteCraft,
+                NonZeroU16::new(u16::from(*rating.max(&2)) * 2 - 1).unwrap(),
+            ),
+            CraftProjectKind::Legendary => {
+                (ExperiencePoolName::WhiteCraft, NonZeroU16::new(10).unwrap())
+            }
+        }
+    }
+}
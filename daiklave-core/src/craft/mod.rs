@@ -1,12 +1,31 @@
+mod abandon;
+mod builder;
+mod claim;
 mod craft_memo;
+mod error;
+mod kind;
 mod name;
+mod project;
+mod roll;
+mod start;
+
+pub use abandon::AbandonCraftProject;
+pub use builder::CraftProjectBuilder;
+pub use claim::ClaimCraftedArtifact;
+pub use error::CraftError;
+pub use kind::CraftProjectKind;
 pub use name::CraftName;
+pub use project::CraftProject;
+pub use roll::RollCraftProject;
+pub use start::StartCraftProject;
 
 use std::collections::HashMap;
 
 pub(crate) use craft_memo::CraftMemo;
 
 use crate::{abilities::AbilityRating, CharacterMutationError};
+
+/// A character's Craft ratings, by focus.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Craft<'source>(pub(crate) HashMap<&'source str, AbilityRating<'source>>);
 
@@ -23,6 +42,7 @@ impl<'source> From<&'source CraftMemo> for Craft<'source> {
 }
 
 impl<'source> Craft<'source> {
+    /// Sets the dots in a Craft focus. Zero dots removes the focus.
     pub fn set_dots(
         &mut self,
         focus: &'source str,
@@ -39,10 +59,12 @@ impl<'source> Craft<'source> {
         Ok(self)
     }
 
+    /// The dots in a Craft focus, or 0 if the character has none.
     pub fn dots(&self, focus: &str) -> u8 {
         self.0.get(focus).map_or(0, |ability| ability.dots())
     }
 
+    /// The highest rating among all of the character's Craft foci.
     pub fn max(&self) -> u8 {
         self.0
             .values()
@@ -51,6 +73,7 @@ impl<'source> Craft<'source> {
             .unwrap_or(0)
     }
 
+    /// Iterates over the character's Craft foci, in alphabetical order.
     pub fn iter(&self) -> impl Iterator<Item = &'source str> + '_ {
         let mut vec: Vec<&str> = self.0.keys().copied().collect();
         vec.sort();
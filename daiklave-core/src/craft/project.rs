@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::extended_roll::{ExtendedRoll, ExtendedRollStatus};
+
+use super::{CraftError, CraftProjectBuilder, CraftProjectKind};
+
+/// A crafting project tracked on a character's sheet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CraftProject {
+    pub(crate) name: String,
+    pub(crate) focus: String,
+    pub(crate) kind: CraftProjectKind,
+    pub(crate) extended_roll: ExtendedRoll,
+}
+
+impl CraftProject {
+    /// Starts building a basic project.
+    pub fn basic(name: impl Into<String>, focus: impl Into<String>) -> CraftProjectBuilder {
+        CraftProjectBuilder::new(name.into(), focus.into(), CraftProjectKind::Basic)
+    }
+
+    /// Starts building a major project.
+    pub fn major(name: impl Into<String>, focus: impl Into<String>) -> CraftProjectBuilder {
+        CraftProjectBuilder::new(name.into(), focus.into(), CraftProjectKind::Major)
+    }
+
+    /// Starts building a superior project to create an artifact of the
+    /// specified rating. Errors if the rating is not between 2 and 5.
+    pub fn superior(
+        name: impl Into<String>,
+        focus: impl Into<String>,
+        rating: u8,
+    ) -> Result<CraftProjectBuilder, CraftError> {
+        if !(2..=5).contains(&rating) {
+            return Err(CraftError::InvalidArtifactRating);
+        }
+        Ok(CraftProjectBuilder::new(
+            name.into(),
+            focus.into(),
+            CraftProjectKind::Superior(rating),
+        ))
+    }
+
+    /// Starts building a legendary project.
+    pub fn legendary(name: impl Into<String>, focus: impl Into<String>) -> CraftProjectBuilder {
+        CraftProjectBuilder::new(name.into(), focus.into(), CraftProjectKind::Legendary)
+    }
+
+    /// The name of the project.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The Craft focus used to work on the project.
+    pub fn focus(&self) -> &str {
+        self.focus.as_str()
+    }
+
+    /// The scale of the project.
+    pub fn kind(&self) -> CraftProjectKind {
+        self.kind
+    }
+
+    /// The rolls made toward the project, with its difficulty, goal, and
+    /// terminus.
+    pub fn extended_roll(&self) -> &ExtendedRoll {
+        &self.extended_roll
+    }
+
+    /// True if a single-roll project was completed with three or more
+    /// successes over its difficulty.
+    pub fn is_exceptional(&self) -> bool {
+        let extended_roll = &self.extended_roll;
+        matches!(self.kind, CraftProjectKind::Basic | CraftProjectKind::Major)
+            && extended_roll.status() == ExtendedRollStatus::Completed
+            && extended_roll
+                .rolls()
+                .iter()
+                .any(|roll| roll.successes() >= extended_roll.difficulty().saturating_add(3))
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to record the successes rolled for one interval of work on a
+/// crafting project. If this completes the project, its crafting experience
+/// is awarded automatically.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollCraftProject {
+    /// The name of the project.
+    pub name: String,
+    /// The number of successes rolled.
+    pub successes: u8,
+}
+
+impl From<RollCraftProject> for CharacterMutation {
+    fn from(roll_craft_project: RollCraftProject) -> Self {
+        CharacterMutation::RollCraftProject(roll_craft_project)
+    }
+}
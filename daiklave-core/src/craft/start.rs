@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::CraftProject;
+
+/// A mutation to begin a crafting project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StartCraftProject(pub CraftProject);
+
+impl From<StartCraftProject> for CharacterMutation {
+    fn from(start_craft_project: StartCraftProject) -> Self {
+        CharacterMutation::StartCraftProject(start_craft_project)
+    }
+}
@@ -9,7 +9,7 @@ pub struct AnimaEffect<'source> {
 impl<'source> AnimaEffect<'source> {
     /// The book reference for the anima effect, if any.
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.book_reference
+        self.book_reference.clone()
     }
 
     /// The descripive text of the anima effect.
@@ -9,6 +9,9 @@ pub enum EssenceError {
     /// Can't commit a duplicate mote commitment
     #[error("Mote commitment Ids must be unique")]
     DuplicateCommitment,
+    /// Limit can't be reduced while it is broken
+    #[error("Cannot reduce Limit during a Limit Break")]
+    InLimitBreak,
     /// Can't spend or commit more motes than you have
     #[error("Insufficient motes")]
     InsufficientMotes,
@@ -27,4 +30,7 @@ pub enum EssenceError {
     /// Mote commitment does not exist
     #[error("Mote commitment not found")]
     NotFound,
+    /// Can only resolve a Limit Break if Limit has broken
+    #[error("Limit has not broken")]
+    NotInLimitBreak,
 }
@@ -2,13 +2,15 @@ mod error;
 mod mote_commitment;
 mod mote_pool;
 mod motes;
+mod progression;
+mod set_progression;
 mod set_rating;
 mod state;
 pub(crate) use state::{EssenceState, EssenceStateMemo};
 
 pub(crate) use error::EssenceError;
 pub(crate) use motes::MotesState;
-pub use motes::{CommitMotes, Motes, RecoverMotes, SpendMotes, UncommitMotes};
+pub use motes::{CommitMotes, CommitSceneMotes, Motes, RecoverMotes, SpendMotes, UncommitMotes};
 
 use crate::{
     armor::armor_item::{ArmorType, ArmorWeightClass},
@@ -21,6 +23,8 @@ pub(crate) use mote_commitment::MoteCommitmentNameMutation;
 pub use mote_commitment::{MoteCommitment, MoteCommitmentName};
 pub(crate) use mote_pool::MotePool;
 pub use mote_pool::MotePoolName;
+pub use progression::{essence_for_experience, EssenceMismatch, ESSENCE_EXPERIENCE_THRESHOLDS};
+pub use set_progression::SetEssenceProgression;
 pub use set_rating::SetEssenceRating;
 
 /// An Exalt's Essence rating and mote pools.
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::CommitMotes;
+
+/// A mutation to commit motes to an effect which lasts only until the end of
+/// the scene. The commitment is released automatically by
+/// [crate::lifecycle::EndScene].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitSceneMotes(pub CommitMotes);
+
+impl From<CommitSceneMotes> for CharacterMutation {
+    fn from(commit_scene_motes: CommitSceneMotes) -> Self {
+        CharacterMutation::CommitSceneMotes(commit_scene_motes)
+    }
+}
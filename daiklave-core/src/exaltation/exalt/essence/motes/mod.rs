@@ -1,9 +1,11 @@
 mod commit;
+mod commit_scene;
 mod recover;
 mod spend;
 mod state;
 mod uncommit;
 pub use commit::CommitMotes;
+pub use commit_scene::CommitSceneMotes;
 pub use recover::RecoverMotes;
 pub use spend::SpendMotes;
 pub(crate) use state::{MotesState, MotesStateMemo};
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +14,8 @@ pub(crate) struct MotesStateMemo {
     pub(crate) personal_spent: u8,
     pub(crate) other_commitments:
         HashMap<OtherMoteCommitmentName, (PeripheralCommitted, PersonalCommitted)>,
+    #[serde(default)]
+    pub(crate) scene_commitments: HashSet<OtherMoteCommitmentName>,
 }
 
 impl From<&MotesState<'_>> for MotesStateMemo {
@@ -28,6 +30,11 @@ impl From<&MotesState<'_>> for MotesStateMemo {
                 .iter()
                 .map(|(name, (peripheral, personal))| ((*name).into(), (*peripheral, *personal)))
                 .collect(),
+            scene_commitments: motes
+                .scene_commitments
+                .iter()
+                .map(|name| (*name).into())
+                .collect(),
         }
     }
 }
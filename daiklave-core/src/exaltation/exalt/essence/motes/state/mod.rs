@@ -3,7 +3,7 @@ mod memo;
 
 pub(crate) use alias::{PeripheralCommitted, PersonalCommitted};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::exaltation::exalt::essence::{MoteCommitment, MoteCommitmentName};
 
@@ -16,6 +16,7 @@ pub(crate) struct MotesState<'source> {
     pub personal_available: u8,
     pub personal_spent: u8,
     pub other_commitments: HashMap<&'source str, (PeripheralCommitted, PersonalCommitted)>,
+    pub scene_commitments: HashSet<&'source str>,
 }
 
 impl<'source> MotesState<'source> {
@@ -42,6 +43,11 @@ impl<'source> From<&'source MotesStateMemo> for MotesState<'source> {
                 .iter()
                 .map(|(name, (peripheral, personal))| (name.as_str(), (*peripheral, *personal)))
                 .collect(),
+            scene_commitments: memo
+                .scene_commitments
+                .iter()
+                .map(|name| name.as_str())
+                .collect(),
         }
     }
 }
@@ -0,0 +1,35 @@
+use std::num::NonZeroU8;
+
+/// The total experience (normal plus Exalt) an Exalt must have spent to reach
+/// each Essence rating from 2 to 5.
+pub const ESSENCE_EXPERIENCE_THRESHOLDS: [u16; 4] = [50, 125, 200, 300];
+
+/// The Essence rating an Exalt should have after spending the specified
+/// total experience.
+pub fn essence_for_experience(spent: u16) -> NonZeroU8 {
+    let raised = ESSENCE_EXPERIENCE_THRESHOLDS
+        .iter()
+        .take_while(|threshold| spent >= **threshold)
+        .count() as u8;
+    NonZeroU8::new(1 + raised).unwrap()
+}
+
+/// A disagreement between an Exalt's Essence rating and the rating their
+/// spent experience implies, such as after a manual SetEssenceRating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EssenceMismatch {
+    pub(crate) expected: NonZeroU8,
+    pub(crate) actual: NonZeroU8,
+}
+
+impl EssenceMismatch {
+    /// The Essence rating implied by spent experience.
+    pub fn expected(&self) -> NonZeroU8 {
+        self.expected
+    }
+
+    /// The Exalt's actual Essence rating.
+    pub fn actual(&self) -> NonZeroU8 {
+        self.actual
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to opt in to (or out of) automatic Essence progression, where
+/// an Exalt's Essence rises as they spend experience.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetEssenceProgression(pub bool);
+
+impl From<SetEssenceProgression> for CharacterMutation {
+    fn from(set_essence_progression: SetEssenceProgression) -> Self {
+        Self::SetEssenceProgression(set_essence_progression)
+    }
+}
@@ -14,7 +14,6 @@ use crate::{
         limit::LimitTrigger,
         LimitMemo,
     },
-    experience::ExperiencePool,
 };
 
 /// A builder for a Dawn caste Solar.
@@ -303,9 +302,12 @@ impl DawnBuilder {
             limit: LimitMemo {
                 track: 0,
                 trigger: limit_trigger,
+                virtue_flaw: None,
+                limit_break: false,
+                days_broken: 0,
             },
             solar_charms: Vec::new(),
-            experience: ExperiencePool::default(),
+            legacy_experience: None,
         })))
     }
 }
@@ -13,7 +13,6 @@ use crate::{
         limit::LimitTrigger,
         LimitMemo,
     },
-    experience::ExperiencePool,
 };
 
 /// A builder for an Eclipse caste Solar.
@@ -111,9 +110,12 @@ impl EclipseBuilder {
             limit: LimitMemo {
                 track: 0,
                 trigger: limit_trigger,
+                virtue_flaw: None,
+                limit_break: false,
+                days_broken: 0,
             },
             solar_charms: Vec::new(),
-            experience: ExperiencePool::default(),
+            legacy_experience: None,
         })))
     }
 }
@@ -11,7 +11,6 @@ use crate::{
         limit::LimitTrigger,
         LimitMemo,
     },
-    experience::ExperiencePool,
 };
 
 /// A builder for a Night caste Solar.
@@ -108,9 +107,12 @@ impl NightBuilder {
             limit: LimitMemo {
                 track: 0,
                 trigger: limit_trigger,
+                virtue_flaw: None,
+                limit_break: false,
+                days_broken: 0,
             },
             solar_charms: Vec::new(),
-            experience: ExperiencePool::default(),
+            legacy_experience: None,
         })))
     }
 }
@@ -4,10 +4,7 @@ use crate::{
 };
 
 pub(crate) const ZENITH_ONE: AnimaEffect<'static> = AnimaEffect {
-    book_reference: Some(BookReference {
-        book: Book::CoreRulebook,
-        page_number: 176,
-    }),
+    book_reference: Some(BookReference::published(Book::CoreRulebook, 176)),
     description: "A Zenith Caste may spend 1 mote to cremate a corpse, and \
     learn the Intimacies of the deceased. If these Intimacies are postive, the \
     Zenith may pay 1 mote to pass them onto a target of those Intimacies, or \
@@ -17,10 +14,7 @@ pub(crate) const ZENITH_ONE: AnimaEffect<'static> = AnimaEffect {
 };
 
 pub(crate) const ZENITH_TWO: AnimaEffect<'static> = AnimaEffect {
-    book_reference: Some(BookReference {
-        book: Book::CoreRulebook,
-        page_number: 176,
-    }),
+    book_reference: Some(BookReference::published(Book::CoreRulebook, 176)),
     description: "A Zenith Caste may spend 7 motes and roll (Charisma + \
         Presence) plus (Essence) automatic non-Charm successes to persuade a \
         dematerialized spirit to Materialize. The target counts as having a \
@@ -29,10 +23,7 @@ pub(crate) const ZENITH_TWO: AnimaEffect<'static> = AnimaEffect {
 };
 
 pub(crate) const ZENITH_THREE: AnimaEffect<'static> = AnimaEffect {
-    book_reference: Some(BookReference {
-        book: Book::CoreRulebook,
-        page_number: 176,
-    }),
+    book_reference: Some(BookReference::published(Book::CoreRulebook, 176)),
     description: "A Zenith Caste may spend 10 motes and 1 Willpower after \
     successfully landing a decisive attack against a creature of darkness to \
     prevent returning to base Initiative. At bonfire anima, the costs is 5 \
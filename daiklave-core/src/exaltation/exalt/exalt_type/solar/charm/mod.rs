@@ -50,7 +50,7 @@ impl<'source> SolarCharm<'source> {
 
     /// The book reference of the Charm, if any
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.details.book_reference
+        self.details.book_reference.clone()
     }
 
     /// A short summary of the Charm if provided
@@ -15,7 +15,10 @@ pub(crate) struct SolarMemo {
     pub sorcery: Option<SolarSorcererMemo>,
     pub limit: LimitMemo,
     pub solar_charms: Vec<(SolarCharmName, SolarCharmDetails)>,
-    pub experience: ExperiencePool,
+    /// Solar Experience saved before experience was tracked as a ledger.
+    /// Moved into the character's ledger when loaded; always None after.
+    #[serde(default, rename = "experience")]
+    pub legacy_experience: Option<ExperiencePool>,
 }
 
 #[allow(clippy::from_over_into)]
@@ -24,7 +27,6 @@ impl<'source> Into<Solar<'source>> for &'source SolarMemo {
         Solar {
             caste: (&self.caste).into(),
             favored_abilities: self.favored_abilities,
-            experience: self.experience,
             sorcery: self.sorcery.as_ref().map(|sorcery| (sorcery).into()),
             limit: (&self.limit).into(),
             solar_charms: self
@@ -43,8 +45,12 @@ impl From<&Solar<'_>> for SolarMemo {
             favored_abilities: value.favored_abilities,
             sorcery: value.sorcery.as_ref().map(|view| view.into()),
             limit: (&value.limit).into(),
-            solar_charms: value.solar_charms.iter().map(|(name, details)| ((*name).into(), (*details).to_owned())).collect(),
-            experience: value.experience,
+            solar_charms: value
+                .solar_charms
+                .iter()
+                .map(|(name, details)| ((*name).into(), (*details).to_owned()))
+                .collect(),
+            legacy_experience: None,
         }
     }
-}
\ No newline at end of file
+}
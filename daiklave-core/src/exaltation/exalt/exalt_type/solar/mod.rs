@@ -24,7 +24,6 @@ use crate::{
     abilities::AbilityName,
     charms::{charm::Charm, CharmError},
     exaltation::exalt::{AnimaEffect, Limit},
-    experience::ExperiencePool,
     merits::merit::{MeritError, SorceryArchetypeMeritDetails},
     sorcery::{
         circles::{
@@ -49,7 +48,6 @@ use self::{
 pub struct Solar<'source> {
     pub(crate) caste: SolarCaste<'source>,
     pub(crate) favored_abilities: [AbilityName; 5],
-    pub(crate) experience: ExperiencePool,
     pub(crate) sorcery: Option<SolarSorcererView<'source>>,
     pub(crate) limit: Limit<'source>,
     pub(crate) solar_charms: Vec<(&'source str, &'source SolarCharmDetails)>,
@@ -100,11 +98,6 @@ impl<'source> Solar<'source> {
         self.limit
     }
 
-    /// The Solar's pool of Solar Experience
-    pub fn experience(&self) -> ExperiencePool {
-        self.experience
-    }
-
     pub(crate) fn add_terrestrial_sorcery(
         &mut self,
         add_terrestrial: &'source AddTerrestrialSorcery,
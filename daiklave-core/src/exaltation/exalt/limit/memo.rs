@@ -1,11 +1,17 @@
 use serde::{Deserialize, Serialize};
 
-use super::{Limit, LimitTrigger};
+use super::{Limit, LimitTrigger, VirtueFlaw};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub(crate) struct LimitMemo {
     pub track: u8,
     pub trigger: LimitTrigger,
+    #[serde(default)]
+    pub virtue_flaw: Option<VirtueFlaw>,
+    #[serde(default)]
+    pub limit_break: bool,
+    #[serde(default)]
+    pub days_broken: u8,
 }
 
 #[allow(clippy::from_over_into)]
@@ -14,6 +20,9 @@ impl<'source> Into<Limit<'source>> for &'source LimitMemo {
         Limit {
             track: self.track,
             trigger: &self.trigger,
+            virtue_flaw: self.virtue_flaw.as_ref(),
+            limit_break: self.limit_break,
+            days_broken: self.days_broken,
         }
     }
 }
@@ -23,6 +32,9 @@ impl From<&Limit<'_>> for LimitMemo {
         Self {
             track: value.track,
             trigger: value.trigger.into(),
+            virtue_flaw: value.virtue_flaw.cloned(),
+            limit_break: value.limit_break,
+            days_broken: value.days_broken,
         }
     }
-}
\ No newline at end of file
+}
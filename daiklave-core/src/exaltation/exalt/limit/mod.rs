@@ -1,11 +1,17 @@
 mod gain;
 mod reduce;
+mod resolve_break;
 mod set_trigger;
+mod set_virtue_flaw;
 mod trigger;
+mod virtue_flaw;
 pub use gain::GainLimit;
 pub use reduce::ReduceLimit;
+pub use resolve_break::ResolveLimitBreak;
 pub use set_trigger::SetLimitTrigger;
+pub use set_virtue_flaw::SetVirtueFlaw;
 pub use trigger::LimitTrigger;
+pub use virtue_flaw::{CoreVirtueFlaw, VirtueFlaw, VirtueFlawDuration};
 
 mod memo;
 use std::num::NonZeroU8;
@@ -17,6 +23,9 @@ pub(crate) use memo::LimitMemo;
 pub struct Limit<'source> {
     pub(crate) track: u8,
     pub(crate) trigger: &'source str,
+    pub(crate) virtue_flaw: Option<&'source VirtueFlaw>,
+    pub(crate) limit_break: bool,
+    pub(crate) days_broken: u8,
 }
 
 impl<'source> Limit<'source> {
@@ -30,16 +39,72 @@ impl<'source> Limit<'source> {
         self.trigger
     }
 
+    /// The character's Virtue Flaw, if one has been chosen.
+    pub fn virtue_flaw(&self) -> Option<&'source VirtueFlaw> {
+        self.virtue_flaw
+    }
+
+    /// True if the character's Limit has broken, and they are in the grip
+    /// of their Virtue Flaw.
+    pub fn is_broken(&self) -> bool {
+        self.limit_break
+    }
+
+    /// Adds Limit, to a maximum of 10. Reaching 10 triggers a Limit Break.
     pub(crate) fn gain_limit(&mut self, amount: NonZeroU8) {
-        let max_add = self.track - 10.min(self.track);
+        let max_add = 10 - self.track.min(10);
 
         self.track += amount.get().min(max_add);
+        if self.track >= 10 {
+            self.limit_break = true;
+        }
     }
 
     pub(crate) fn remove_limit(&mut self, amount: NonZeroU8) {
         self.track -= amount.get().min(self.track);
     }
 
+    pub(crate) fn set_virtue_flaw(&mut self, virtue_flaw: &'source VirtueFlaw) {
+        self.virtue_flaw = Some(virtue_flaw);
+    }
+
+    /// Ends a Limit Break, resetting the track to 0. Returns false if the
+    /// character's Limit had not broken.
+    pub(crate) fn resolve_limit_break(&mut self) -> bool {
+        if !self.limit_break {
+            return false;
+        }
+        self.limit_break = false;
+        self.days_broken = 0;
+        self.track = 0;
+        true
+    }
+
+    /// Ends the Limit Break if the Virtue Flaw lasts only a scene. Returns
+    /// true if a Limit Break ended.
+    pub(crate) fn end_scene(&mut self) -> bool {
+        self.virtue_flaw
+            .is_some_and(|virtue_flaw| virtue_flaw.duration == VirtueFlawDuration::Scene)
+            && self.resolve_limit_break()
+    }
+
+    /// Counts a day spent in a Limit Break, ending it if the Virtue Flaw's
+    /// duration has run out. Returns true if a Limit Break ended.
+    pub(crate) fn end_day(&mut self) -> bool {
+        if !self.limit_break {
+            return false;
+        }
+        self.days_broken = self.days_broken.saturating_add(1);
+        if let Some(VirtueFlawDuration::Days(days)) =
+            self.virtue_flaw.map(|virtue_flaw| virtue_flaw.duration)
+        {
+            if self.days_broken >= days.get() {
+                return self.resolve_limit_break();
+            }
+        }
+        false
+    }
+
     pub(crate) fn set_trigger(&mut self, trigger: &'source str) {
         self.trigger = trigger;
     }
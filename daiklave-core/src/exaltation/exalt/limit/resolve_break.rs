@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to resolve a Limit Break once the Virtue Flaw's duration ends
+/// or its resolution condition is met, resetting the Limit track to 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolveLimitBreak;
+
+impl From<ResolveLimitBreak> for CharacterMutation {
+    fn from(_resolve_limit_break: ResolveLimitBreak) -> Self {
+        CharacterMutation::ResolveLimitBreak
+    }
+}
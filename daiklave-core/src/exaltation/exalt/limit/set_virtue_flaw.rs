@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::VirtueFlaw;
+
+/// A mutation to set the Virtue Flaw of a Solar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetVirtueFlaw(pub VirtueFlaw);
+
+impl From<SetVirtueFlaw> for CharacterMutation {
+    fn from(set_virtue_flaw: SetVirtueFlaw) -> Self {
+        Self::SetVirtueFlaw(set_virtue_flaw)
+    }
+}
@@ -0,0 +1,135 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use super::SetVirtueFlaw;
+
+/// How long a Virtue Flaw lasts once Limit breaks. When the duration runs
+/// out, the Limit Break resolves on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VirtueFlawDuration {
+    /// Until the end of the current scene.
+    Scene,
+    /// For a number of days, each ending with a full night's rest.
+    Days(NonZeroU8),
+    /// Until the Virtue Flaw's resolution condition is met.
+    UntilResolved,
+}
+
+/// The Virtue Flaws from the core rulebook, which a Solar may take as the
+/// form their Limit Break takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoreVirtueFlaw {
+    /// The Solar lashes out violently at everyone around them.
+    BerserkAnger,
+    /// The Solar cannot abide those who fail to live up to their ideals.
+    ContemptOfTheVirtuous,
+    /// The Solar inflicts suffering on those who have wronged them.
+    DeliberateCruelty,
+    /// The Solar shuts away all feeling, caring nothing for others.
+    HeartOfFlint,
+    /// The Solar protects the object of their compassion at any cost.
+    RedRageOfCompassion,
+    /// The Solar abandons their mortal concerns to pursue their own whims.
+    WaywardDivinity,
+}
+
+impl CoreVirtueFlaw {
+    /// The name of the Virtue Flaw.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CoreVirtueFlaw::BerserkAnger => "Berserk Anger",
+            CoreVirtueFlaw::ContemptOfTheVirtuous => "Contempt of the Virtuous",
+            CoreVirtueFlaw::DeliberateCruelty => "Deliberate Cruelty",
+            CoreVirtueFlaw::HeartOfFlint => "Heart of Flint",
+            CoreVirtueFlaw::RedRageOfCompassion => "Red Rage of Compassion",
+            CoreVirtueFlaw::WaywardDivinity => "Wayward Divinity",
+        }
+    }
+
+    /// How long the Virtue Flaw lasts once triggered.
+    pub fn duration(&self) -> VirtueFlawDuration {
+        match self {
+            CoreVirtueFlaw::BerserkAnger | CoreVirtueFlaw::RedRageOfCompassion => {
+                VirtueFlawDuration::Scene
+            }
+            CoreVirtueFlaw::ContemptOfTheVirtuous | CoreVirtueFlaw::DeliberateCruelty => {
+                VirtueFlawDuration::Days(NonZeroU8::new(1).unwrap())
+            }
+            CoreVirtueFlaw::HeartOfFlint | CoreVirtueFlaw::WaywardDivinity => {
+                VirtueFlawDuration::Days(NonZeroU8::new(7).unwrap())
+            }
+        }
+    }
+
+    /// What ends the Virtue Flaw early, if anything.
+    pub fn resolution(&self) -> &'static str {
+        match self {
+            CoreVirtueFlaw::BerserkAnger => {
+                "No one remains to attack, or the Solar is incapacitated"
+            }
+            CoreVirtueFlaw::ContemptOfTheVirtuous => {
+                "The Solar humiliates or drives away the object of their contempt"
+            }
+            CoreVirtueFlaw::DeliberateCruelty => {
+                "The Solar inflicts lasting harm on the one who wronged them"
+            }
+            CoreVirtueFlaw::HeartOfFlint => "The Solar's duration expires",
+            CoreVirtueFlaw::RedRageOfCompassion => "The threat to the object of compassion ends",
+            CoreVirtueFlaw::WaywardDivinity => "The Solar fulfills the whim they pursued",
+        }
+    }
+}
+
+/// A Solar's Virtue Flaw: the behavior they fall into when their Limit
+/// breaks, how long it lasts, and what resolves it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VirtueFlaw {
+    pub(crate) name: String,
+    pub(crate) duration: VirtueFlawDuration,
+    pub(crate) resolution: String,
+}
+
+impl VirtueFlaw {
+    /// One of the Virtue Flaws from the core rulebook.
+    pub fn core(flaw: CoreVirtueFlaw) -> Self {
+        Self {
+            name: flaw.name().to_owned(),
+            duration: flaw.duration(),
+            resolution: flaw.resolution().to_owned(),
+        }
+    }
+
+    /// A custom Virtue Flaw.
+    pub fn custom(
+        name: impl Into<String>,
+        duration: VirtueFlawDuration,
+        resolution: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            duration,
+            resolution: resolution.into(),
+        }
+    }
+
+    /// Creates a mutation to set a Solar's Virtue Flaw.
+    pub fn set(self) -> SetVirtueFlaw {
+        SetVirtueFlaw(self)
+    }
+
+    /// The name of the Virtue Flaw.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// How long the Virtue Flaw lasts once triggered.
+    pub fn duration(&self) -> VirtueFlawDuration {
+        self.duration
+    }
+
+    /// What ends the Virtue Flaw, resetting the Limit track.
+    pub fn resolution(&self) -> &str {
+        self.resolution.as_str()
+    }
+}
@@ -235,30 +235,62 @@ impl<'view, 'source> Exalt<'source> {
         Ok(self)
     }
 
-    pub fn recover_motes(
+    pub(crate) fn commit_scene_motes(
         &mut self,
+        name: &'source str,
+        first: MotePoolName,
         amount: NonZeroU8,
     ) -> Result<&mut Self, CharacterMutationError> {
-        let amount = amount.get();
-        let (peripheral, personal) = self.essence().motes().peripheral_and_personal();
-        let (peripheral_spent, personal_spent) = (peripheral.spent, personal.spent);
+        self.commit_motes(name, first, amount)?;
+        self.essence.motes.scene_commitments.insert(name);
+        Ok(self)
+    }
 
-        if amount > peripheral_spent + peripheral_spent {
+    pub fn recover_motes(
+        &mut self,
+        amount: NonZeroU8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let motes = &self.essence.motes;
+        if amount.get() > motes.peripheral_spent.saturating_add(motes.personal_spent) {
             return Err(CharacterMutationError::EssenceError(
                 EssenceError::InsufficientMotes,
             ));
         }
 
-        let peripheral_recovered = peripheral_spent.min(amount);
-        let personal_recovered = personal_spent.min(amount - peripheral_recovered);
-
-        self.essence.motes.peripheral_spent -= peripheral_recovered;
-        self.essence.motes.peripheral_available += peripheral_recovered;
-        self.essence.motes.personal_spent -= peripheral_recovered;
-        self.essence.motes.personal_available += personal_recovered;
+        self.respire_motes(amount.get());
         Ok(self)
     }
 
+    /// Recovers up to the specified amount of spent motes, peripheral first,
+    /// returning the number actually recovered.
+    pub(crate) fn respire_motes(&mut self, amount: u8) -> u8 {
+        let motes = &mut self.essence.motes;
+        let peripheral_recovered = motes.peripheral_spent.min(amount);
+        let personal_recovered = motes.personal_spent.min(amount - peripheral_recovered);
+
+        motes.peripheral_spent -= peripheral_recovered;
+        motes.peripheral_available += peripheral_recovered;
+        motes.personal_spent -= personal_recovered;
+        motes.personal_available += personal_recovered;
+        peripheral_recovered + personal_recovered
+    }
+
+    /// Uncommits every scene-length commitment, returning their names.
+    pub(crate) fn release_scene_commitments(&mut self) -> Vec<&'source str> {
+        let mut released = self
+            .essence
+            .motes
+            .scene_commitments
+            .drain()
+            .collect::<Vec<&'source str>>();
+        released.sort();
+        for name in released.iter() {
+            // Scene commitments are always also other commitments
+            let _ = self.uncommit_motes(MoteCommitmentName::Other(name));
+        }
+        released
+    }
+
     pub fn uncommit_motes(
         &mut self,
         name: MoteCommitmentName<'_>,
@@ -280,6 +312,9 @@ impl<'view, 'source> Exalt<'source> {
                 .remove(other_name)
                 .ok_or(CharacterMutationError::EssenceError(EssenceError::NotFound))?,
         };
+        if let MoteCommitmentName::Other(other_name) = name {
+            self.essence.motes.scene_commitments.remove(other_name);
+        }
         self.essence.motes.peripheral_spent = self
             .essence
             .motes
@@ -325,6 +360,26 @@ impl<'view, 'source> Exalt<'source> {
         Ok(self)
     }
 
+    /// Raises the Essence rating without disturbing commitments, adding the
+    /// new motes to each pool as available motes. Does nothing if the rating
+    /// is already at least this high.
+    pub(crate) fn raise_essence_rating(&mut self, rating: NonZeroU8) {
+        let old_rating = self.essence.rating;
+        if rating <= old_rating || rating > NonZeroU8::new(5).unwrap() {
+            return;
+        }
+
+        let (added_peripheral, added_personal) = match self.exalt_type {
+            ExaltType::Solar(_) => (
+                (rating.get() - old_rating.get()) * 7,
+                (rating.get() - old_rating.get()) * 3,
+            ),
+        };
+        self.essence.motes.peripheral_available += added_peripheral;
+        self.essence.motes.personal_available += added_personal;
+        self.essence.rating = rating;
+    }
+
     pub(crate) fn add_martial_arts_style(
         &mut self,
         name: &'source str,
@@ -468,9 +523,13 @@ impl<'view, 'source> Exalt<'source> {
 
     pub(crate) fn sorcery(&'view self) -> Option<Sorcery<'view, 'source>> {
         match self.exalt_type() {
-            ExaltType::Solar(solar) => solar
-                .sorcery()
-                .map(|sorcerer| Sorcery(ExaltationSorcery::Exalt(ExaltSorcery::Solar(sorcerer)))),
+            ExaltType::Solar(solar) => solar.sorcery().map(|sorcerer| {
+                Sorcery(
+                    ExaltationSorcery::Exalt(ExaltSorcery::Solar(sorcerer)),
+                    None,
+                    &[],
+                )
+            }),
         }
     }
 
@@ -9,7 +9,7 @@ pub(crate) enum ExaltHandsMemo {
     Empty,
     MainHand(EquippedOneHandedWeaponMemo),
     OffHand(EquippedOneHandedWeaponMemo),
-    Both([EquippedOneHandedWeaponMemo; 2]),
+    Both(Box<[EquippedOneHandedWeaponMemo; 2]>),
     TwoHanded(EquippedTwoHandedWeaponMemo),
 }
 
@@ -19,8 +19,10 @@ impl From<&ExaltHands<'_>> for ExaltHandsMemo {
             ExaltHands::Empty => Self::Empty,
             ExaltHands::MainHand(weapon) => Self::MainHand(weapon.into()),
             ExaltHands::OffHand(weapon) => Self::OffHand(weapon.into()),
-            ExaltHands::Both(weapons) => Self::Both([(&weapons[0]).into(), (&weapons[1]).into()]),
+            ExaltHands::Both(weapons) => {
+                Self::Both(Box::new([(&weapons[0]).into(), (&weapons[1]).into()]))
+            }
             ExaltHands::TwoHanded(weapon) => Self::TwoHanded(weapon.into()),
         }
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-use super::{exalt::ExaltMemo, mortal::MortalMemo, Exaltation};
+use crate::experience::ExperiencePool;
+
+use super::{
+    exalt::{exalt_type::ExaltTypeMemo, ExaltMemo},
+    mortal::MortalMemo,
+    Exaltation,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub(crate) enum ExaltationMemo {
@@ -15,4 +21,15 @@ impl From<Exaltation<'_>> for ExaltationMemo {
             Exaltation::Exalt(box_view) => Self::Exalt(Box::new(box_view.as_ref().into())),
         }
     }
-}
\ No newline at end of file
+}
+impl ExaltationMemo {
+    /// Exalt experience saved before experience was tracked as a ledger.
+    pub(crate) fn legacy_experience(&self) -> Option<ExperiencePool> {
+        match self {
+            Self::Mortal(_) => None,
+            Self::Exalt(exalt) => match &exalt.exalt_type {
+                ExaltTypeMemo::Solar(solar) => solar.legacy_experience,
+            },
+        }
+    }
+}
@@ -22,7 +22,10 @@ use crate::{
         ArtifactName, ArtifactNameMutation,
     },
     charms::{
-        charm::{evocation::Evocation, Charm},
+        charm::{
+            evocation::{Evocation, EvokableName},
+            Charm,
+        },
         CharmError,
     },
     hearthstones::UnslottedHearthstone,
@@ -233,6 +236,7 @@ impl<'view, 'source> Exaltation<'source> {
                 Some(MartialArtsStyle {
                     name,
                     maybe_exalt: ExaltationMartialArtist::Mortal(mortal_martial_artist),
+                    active_form: None,
                 })
             }
             Exaltation::Exalt(exalt) => {
@@ -240,6 +244,7 @@ impl<'view, 'source> Exaltation<'source> {
                 Some(MartialArtsStyle {
                     name,
                     maybe_exalt: ExaltationMartialArtist::Exalt(exalt_martial_artist),
+                    active_form: None,
                 })
             }
         }
@@ -381,7 +386,7 @@ impl<'view, 'source> Exaltation<'source> {
             Exaltation::Mortal(mortal) => mortal
                 .sorcery
                 .as_ref()
-                .map(|terrestrial| Sorcery(ExaltationSorcery::Mortal(terrestrial))),
+                .map(|terrestrial| Sorcery(ExaltationSorcery::Mortal(terrestrial), None, &[])),
             Exaltation::Exalt(exalt) => exalt.sorcery(),
         }
     }
@@ -418,6 +423,7 @@ impl<'view, 'source> Exaltation<'source> {
                             personal_available: 13,
                             personal_spent: 0,
                             other_commitments: Default::default(),
+                            scene_commitments: Default::default(),
                         },
                     },
                     evocations: Vec::new(),
@@ -447,11 +453,12 @@ impl<'view, 'source> Exaltation<'source> {
                 let ExaltType::Solar(old_solar) = &mut exalt.exalt_type;
                 solar.solar_charms = std::mem::take(&mut old_solar.solar_charms);
 
-                // If switching solar->solar, preserve Solar experience
-                solar.experience = std::mem::take(&mut old_solar.experience);
-
-                // If switching solar->solar, preserve Limit track
+                // If switching solar->solar, preserve Limit track, Virtue Flaw,
+                // and any ongoing Limit Break
                 solar.limit.track = old_solar.limit.track;
+                solar.limit.virtue_flaw = old_solar.limit.virtue_flaw;
+                solar.limit.limit_break = old_solar.limit.limit_break;
+                solar.limit.days_broken = old_solar.limit.days_broken;
 
                 // Preserve sorcery
                 if let Some(solar_sorcerer) = old_solar.sorcery.take() {
@@ -468,6 +475,7 @@ impl<'view, 'source> Exaltation<'source> {
                             personal_available: 10 + exalt.essence().rating() * 3,
                             personal_spent: 0,
                             other_commitments: Default::default(),
+                            scene_commitments: Default::default(),
                         },
                     },
                     // Preserve Evocations
@@ -519,6 +527,21 @@ impl<'view, 'source> Exaltation<'source> {
         Ok(self)
     }
 
+    pub(crate) fn commit_scene_motes(
+        &mut self,
+        name: &'source str,
+        first: MotePoolName,
+        amount: NonZeroU8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        match self {
+            Exaltation::Mortal(_) => {
+                Err(CharacterMutationError::EssenceError(EssenceError::Mortal))
+            }
+            Exaltation::Exalt(exalt) => exalt.commit_scene_motes(name, first, amount),
+        }?;
+        Ok(self)
+    }
+
     pub fn recover_motes(
         &mut self,
         amount: NonZeroU8,
@@ -532,6 +555,26 @@ impl<'view, 'source> Exaltation<'source> {
         Ok(self)
     }
 
+    pub(crate) fn respire_motes(&mut self, amount: u8) -> u8 {
+        match self {
+            Exaltation::Mortal(_) => 0,
+            Exaltation::Exalt(exalt) => exalt.respire_motes(amount),
+        }
+    }
+
+    pub(crate) fn raise_essence_rating(&mut self, rating: NonZeroU8) {
+        if let Exaltation::Exalt(exalt) = self {
+            exalt.raise_essence_rating(rating);
+        }
+    }
+
+    pub(crate) fn release_scene_commitments(&mut self) -> Vec<&'source str> {
+        match self {
+            Exaltation::Mortal(_) => Vec::new(),
+            Exaltation::Exalt(exalt) => exalt.release_scene_commitments(),
+        }
+    }
+
     pub fn uncommit_motes(
         &mut self,
         to_uncommit: &'source UncommitMotes,
@@ -1072,6 +1115,20 @@ impl<'view, 'source> Exaltation<'source> {
         Ok(self)
     }
 
+    /// The names of all Evocations known from an artifact or hearthstone,
+    /// including any which are currently disabled.
+    pub(crate) fn evocations_of(&self, evokable_name: EvokableName<'_>) -> Vec<&'source str> {
+        match self {
+            Exaltation::Mortal(_) => vec![],
+            Exaltation::Exalt(exalt) => exalt
+                .evocations
+                .iter()
+                .filter(|(_, evocation)| evocation.evokable_name() == evokable_name)
+                .map(|(name, _)| *name)
+                .collect(),
+        }
+    }
+
     pub fn add_spell(
         &mut self,
         name: &'source str,
@@ -1231,7 +1288,6 @@ impl<'view, 'source> Exaltation<'source> {
     }
 }
 
-
 impl<'source> From<&'source ExaltationMemo> for Exaltation<'source> {
     fn from(value: &'source ExaltationMemo) -> Self {
         match value {
@@ -1239,4 +1295,4 @@ impl<'source> From<&'source ExaltationMemo> for Exaltation<'source> {
             ExaltationMemo::Exalt(exalt) => Self::Exalt(Box::new(exalt.as_ref().into())),
         }
     }
-}
\ No newline at end of file
+}
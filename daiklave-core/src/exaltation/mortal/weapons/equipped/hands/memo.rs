@@ -11,7 +11,7 @@ pub(crate) enum MortalHandsMemo {
     Empty,
     MainHand(EquippedOneHandedWeaponNoAttunementMemo),
     OffHand(EquippedOneHandedWeaponNoAttunementMemo),
-    Both([EquippedOneHandedWeaponNoAttunementMemo; 2]),
+    Both(Box<[EquippedOneHandedWeaponNoAttunementMemo; 2]>),
     TwoHanded(EquippedTwoHandedWeaponNoAttunementMemo),
 }
 
@@ -21,7 +21,7 @@ impl From<&MortalHands<'_>> for MortalHandsMemo {
             MortalHands::Empty => Self::Empty,
             MortalHands::MainHand(weapon) => Self::MainHand(weapon.into()),
             MortalHands::OffHand(weapon) => Self::OffHand(weapon.into()),
-            MortalHands::Both(weapons) => Self::Both([(&weapons[0]).into(), (&weapons[1]).into()]),
+            MortalHands::Both(weapons) => Self::Both(Box::new([(&weapons[0]).into(), (&weapons[1]).into()])),
             MortalHands::TwoHanded(weapon) => Self::TwoHanded(weapon.into()),
         }
     }
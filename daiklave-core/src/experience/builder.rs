@@ -0,0 +1,61 @@
+use std::num::NonZeroU16;
+
+use crate::CharacterMutation;
+
+use super::{ExperienceEntry, ExperienceEntryKind, ExperiencePoolName, RecordExperience};
+
+/// A builder for an experience ledger entry.
+pub struct ExperienceEntryBuilder {
+    entry: ExperienceEntry,
+}
+
+impl ExperienceEntryBuilder {
+    pub(crate) fn new(
+        pool: ExperiencePoolName,
+        kind: ExperienceEntryKind,
+        amount: NonZeroU16,
+    ) -> Self {
+        Self {
+            entry: ExperienceEntry {
+                timestamp: None,
+                pool,
+                kind,
+                amount,
+                reason: None,
+                session: None,
+                linked: None,
+            },
+        }
+    }
+
+    /// Records why the experience was gained or spent.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.entry.reason = Some(reason.into());
+        self
+    }
+
+    /// Records when the entry happened, as seconds since the Unix epoch.
+    pub fn at(mut self, timestamp: u64) -> Self {
+        self.entry.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Records the session in which the entry happened.
+    pub fn session(mut self, session: u16) -> Self {
+        self.entry.session = Some(session);
+        self
+    }
+
+    /// Links the entry to the mutation it paid for, like raising an
+    /// Ability. The linked mutation is applied along with the entry, and
+    /// both fail together.
+    pub fn on(mut self, mutation: impl Into<CharacterMutation>) -> Self {
+        self.entry.linked = Some(Box::new(mutation.into()));
+        self
+    }
+
+    /// Completes the entry, creating a mutation to record it.
+    pub fn build(self) -> RecordExperience {
+        RecordExperience(self.entry)
+    }
+}
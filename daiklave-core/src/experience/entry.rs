@@ -0,0 +1,75 @@
+use std::num::NonZeroU16;
+
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::{ExperienceEntryBuilder, ExperiencePoolName};
+
+/// Whether an experience ledger entry added or removed points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExperienceEntryKind {
+    /// Experience was awarded.
+    Gain,
+    /// Experience was spent.
+    Spend,
+}
+
+/// A single line in a character's experience ledger.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExperienceEntry {
+    pub(crate) timestamp: Option<u64>,
+    pub(crate) pool: ExperiencePoolName,
+    pub(crate) kind: ExperienceEntryKind,
+    pub(crate) amount: NonZeroU16,
+    pub(crate) reason: Option<String>,
+    pub(crate) session: Option<u16>,
+    pub(crate) linked: Option<Box<CharacterMutation>>,
+}
+
+impl ExperienceEntry {
+    /// Starts building an entry awarding experience to a pool.
+    pub fn gain(pool: ExperiencePoolName, amount: NonZeroU16) -> ExperienceEntryBuilder {
+        ExperienceEntryBuilder::new(pool, ExperienceEntryKind::Gain, amount)
+    }
+
+    /// Starts building an entry spending experience from a pool.
+    pub fn spend(pool: ExperiencePoolName, amount: NonZeroU16) -> ExperienceEntryBuilder {
+        ExperienceEntryBuilder::new(pool, ExperienceEntryKind::Spend, amount)
+    }
+
+    /// When the entry happened, as seconds since the Unix epoch, if known.
+    pub fn timestamp(&self) -> Option<u64> {
+        self.timestamp
+    }
+
+    /// The pool the experience was added to or taken from.
+    pub fn pool(&self) -> ExperiencePoolName {
+        self.pool
+    }
+
+    /// Whether the experience was gained or spent.
+    pub fn kind(&self) -> ExperienceEntryKind {
+        self.kind
+    }
+
+    /// The number of experience points gained or spent.
+    pub fn amount(&self) -> NonZeroU16 {
+        self.amount
+    }
+
+    /// Why the experience was gained or spent, if recorded.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// The session in which the entry happened, if recorded.
+    pub fn session(&self) -> Option<u16> {
+        self.session
+    }
+
+    /// The mutation the experience paid for, if any.
+    pub fn linked_mutation(&self) -> Option<&CharacterMutation> {
+        self.linked.as_deref()
+    }
+}
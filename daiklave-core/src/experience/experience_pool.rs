@@ -1,13 +1,8 @@
-use std::num::NonZeroU16;
-
 use serde::{Deserialize, Serialize};
 
-use crate::CharacterMutationError;
-
-use super::ExperienceError;
-
-/// One pool of Experience points, whether normal or tied to a specific Exalt
-/// type (like Solar Experience)
+/// The totals for one pool of Experience points, whether normal, tied to a
+/// specific Exalt type (like Solar Experience), or Crafting experience.
+/// Derived from the character's experience ledger.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct ExperiencePool {
     pub(crate) unspent: u16,
@@ -20,27 +15,13 @@ impl ExperiencePool {
         self.unspent
     }
 
-    /// The total number of experience points, both
-    pub fn total(&self) -> u16 {
-        self.unspent.saturating_add(self.spent)
+    /// The experience points already spent.
+    pub fn spent(&self) -> u16 {
+        self.spent
     }
 
-    pub(crate) fn gain(&mut self, amount: NonZeroU16) {
-        self.unspent = self.unspent.saturating_add(amount.get());
-    }
-
-    pub(crate) fn spend(
-        &mut self,
-        amount: NonZeroU16,
-    ) -> Result<&mut Self, CharacterMutationError> {
-        if amount.get() > self.unspent {
-            Err(CharacterMutationError::ExperienceError(
-                ExperienceError::InsufficientExperience,
-            ))
-        } else {
-            self.unspent -= amount.get();
-            self.spent = self.spent.saturating_add(amount.get());
-            Ok(self)
-        }
+    /// The total number of experience points, both spent and unspent.
+    pub fn total(&self) -> u16 {
+        self.unspent.saturating_add(self.spent)
     }
 }
@@ -5,7 +5,8 @@ use serde::{Serialize, Deserialize};
 use crate::CharacterMutation;
 
 /// A mutation to increase the amount of experience for a character. This
-/// increases both current and total.
+/// increases both current and total. Recorded in the experience ledger
+/// without a reason; use ExperienceEntry for more detail.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GainExperience(pub NonZeroU16);
 
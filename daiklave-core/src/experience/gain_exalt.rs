@@ -6,6 +6,8 @@ use crate::CharacterMutation;
 
 /// A mutation to increase the amount of Exalt experience (e.g. Solar
 /// Experience) for a character. This increases both current and total.
+/// Recorded in the experience ledger without a reason; use ExperienceEntry
+/// for more detail.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GainExaltExperience(pub NonZeroU16);
 
@@ -0,0 +1,120 @@
+use std::{fmt, num::NonZeroU16};
+
+use serde::{
+    de::{IgnoredAny, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use super::{
+    ExperienceEntry, ExperienceEntryKind, ExperienceError, ExperiencePool, ExperiencePoolName,
+};
+
+/// Every experience point a character has gained or spent, in order. Pool
+/// totals are derived from this.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub(crate) struct ExperienceLedger {
+    pub entries: Vec<ExperienceEntry>,
+}
+
+impl ExperienceLedger {
+    /// Adds opening entries for a pool carried over from before experience
+    /// was tracked as a ledger, ahead of any other entries.
+    pub fn open(&mut self, pool_name: ExperiencePoolName, pool: ExperiencePool) {
+        let gained = NonZeroU16::new(pool.total())
+            .map(|amount| ExperienceEntry::gain(pool_name, amount).reason("Opening balance"));
+        let spent = NonZeroU16::new(pool.spent)
+            .map(|amount| ExperienceEntry::spend(pool_name, amount).reason("Opening balance"));
+        let opening = gained
+            .into_iter()
+            .chain(spent)
+            .map(|builder| builder.build().0)
+            .collect::<Vec<ExperienceEntry>>();
+        self.entries.splice(0..0, opening);
+    }
+
+    pub fn pool(&self, pool_name: ExperiencePoolName) -> ExperiencePool {
+        self.entries
+            .iter()
+            .filter(|entry| entry.pool == pool_name)
+            .fold(ExperiencePool::default(), |mut pool, entry| {
+                match entry.kind {
+                    ExperienceEntryKind::Gain => {
+                        pool.unspent = pool.unspent.saturating_add(entry.amount.get());
+                    }
+                    ExperienceEntryKind::Spend => {
+                        pool.unspent = pool.unspent.saturating_sub(entry.amount.get());
+                        pool.spent = pool.spent.saturating_add(entry.amount.get());
+                    }
+                }
+                pool
+            })
+    }
+
+    pub fn check(&self, entry: &ExperienceEntry) -> Result<(), ExperienceError> {
+        if entry.kind == ExperienceEntryKind::Spend
+            && entry.amount.get() > self.pool(entry.pool).current()
+        {
+            Err(ExperienceError::InsufficientExperience)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn record(&mut self, entry: ExperienceEntry) -> Result<&mut Self, ExperienceError> {
+        self.check(&entry)?;
+        self.entries.push(entry);
+        Ok(self)
+    }
+}
+
+/// Characters saved before the ledger stored a single pool of
+/// `{ unspent, spent }` instead of entries. Self-describing formats may have
+/// either shape; non-self-describing formats (like postcard) only ever see
+/// the current one.
+impl<'de> Deserialize<'de> for ExperienceLedger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LedgerVisitor;
+
+        impl<'de> Visitor<'de> for LedgerVisitor {
+            type Value = ExperienceLedger;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an experience ledger or a legacy experience pool")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(ExperienceLedger {
+                    entries: seq.next_element()?.unwrap_or_default(),
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ledger = ExperienceLedger::default();
+                let mut legacy = ExperiencePool::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "entries" => ledger.entries = map.next_value()?,
+                        "unspent" => legacy.unspent = map.next_value()?,
+                        "spent" => legacy.spent = map.next_value()?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                ledger.open(ExperiencePoolName::Base, legacy);
+                Ok(ledger)
+            }
+        }
+
+        deserializer.deserialize_struct("ExperienceLedger", &["entries"], LedgerVisitor)
+    }
+}
@@ -1,30 +1,73 @@
+mod builder;
+mod entry;
 mod error;
 mod experience_pool;
 mod gain;
 mod gain_exalt;
+mod ledger;
+mod pool_name;
+mod record;
+mod session;
 mod spend;
 mod spend_exalt;
+pub use builder::ExperienceEntryBuilder;
+pub use entry::{ExperienceEntry, ExperienceEntryKind};
 pub use error::ExperienceError;
 pub use experience_pool::ExperiencePool;
 pub use gain::GainExperience;
 pub use gain_exalt::GainExaltExperience;
+pub(crate) use ledger::ExperienceLedger;
+pub use pool_name::ExperiencePoolName;
+pub use record::RecordExperience;
+pub use session::{AwardSessionExperience, SessionAward, SessionAwardReason};
 pub use spend::SpendExperience;
 pub use spend_exalt::SpendExaltExperience;
 
 /// A character's Experience points
-pub struct Experience {
-    pub(crate) base: ExperiencePool,
-    pub(crate) exalt: Option<ExperiencePool>,
+pub struct Experience<'view> {
+    pub(crate) ledger: &'view ExperienceLedger,
+    pub(crate) exalted: bool,
 }
 
-impl Experience {
+impl<'view> Experience<'view> {
     /// A character's normal experience pool.
     pub fn base(&self) -> ExperiencePool {
-        self.base
+        self.ledger.pool(ExperiencePoolName::Base)
     }
 
     /// If the character is an exalt, their Exalt experience pool.
     pub fn exalt(&self) -> Option<ExperiencePool> {
-        self.exalt
+        if self.exalted {
+            Some(self.ledger.pool(ExperiencePoolName::Exalt))
+        } else {
+            None
+        }
+    }
+
+    /// The character's Crafting experience pools: silver, gold, and white.
+    pub fn crafting(&self) -> [ExperiencePool; 3] {
+        [
+            self.ledger.pool(ExperiencePoolName::SilverCraft),
+            self.ledger.pool(ExperiencePoolName::GoldCraft),
+            self.ledger.pool(ExperiencePoolName::WhiteCraft),
+        ]
+    }
+
+    /// Gets the totals for a single pool.
+    pub fn pool(&self, pool_name: ExperiencePoolName) -> ExperiencePool {
+        self.ledger.pool(pool_name)
+    }
+
+    /// Every experience ledger entry, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &'view ExperienceEntry> + 'view {
+        self.ledger.entries.iter()
+    }
+
+    /// The ledger entries for a specific session.
+    pub fn session(&self, session: u16) -> impl Iterator<Item = &'view ExperienceEntry> + 'view {
+        self.ledger
+            .entries
+            .iter()
+            .filter(move |entry| entry.session == Some(session))
     }
 }
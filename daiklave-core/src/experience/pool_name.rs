@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// The different pools of Experience points a character may have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExperiencePoolName {
+    /// Normal experience, usable by any character.
+    Base,
+    /// Experience tied to a specific Exalt type, like Solar Experience.
+    Exalt,
+    /// Silver Crafting experience.
+    SilverCraft,
+    /// Gold Crafting experience.
+    GoldCraft,
+    /// White Crafting experience.
+    WhiteCraft,
+}
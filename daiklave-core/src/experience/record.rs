@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::ExperienceEntry;
+
+/// A mutation to add an entry to a character's experience ledger, applying
+/// its linked mutation (if any).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordExperience(pub ExperienceEntry);
+
+impl From<RecordExperience> for CharacterMutation {
+    fn from(record_experience: RecordExperience) -> Self {
+        CharacterMutation::RecordExperience(record_experience)
+    }
+}
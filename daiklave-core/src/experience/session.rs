@@ -0,0 +1,106 @@
+use std::num::NonZeroU16;
+
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::ExperiencePoolName;
+
+/// The standard reasons a Storyteller awards experience at the end of a
+/// session.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SessionAwardReason {
+    /// Playing through the session. Standard award: 2.
+    Session,
+    /// Portraying the character's personality and Intimacies. Standard
+    /// award: 1.
+    Roleplaying,
+    /// Accomplishing a significant goal. Standard award: 1.
+    Achievement,
+    /// Having a Flaw complicate the character's life. Standard award: 1.
+    Flaw,
+    /// Any other reason. Standard award: 1.
+    Custom(String),
+}
+
+impl SessionAwardReason {
+    /// The name of the reason, as recorded in the experience ledger.
+    pub fn name(&self) -> &str {
+        match self {
+            SessionAwardReason::Session => "Session",
+            SessionAwardReason::Roleplaying => "Roleplaying",
+            SessionAwardReason::Achievement => "Achievement",
+            SessionAwardReason::Flaw => "Flaw",
+            SessionAwardReason::Custom(reason) => reason.as_str(),
+        }
+    }
+
+    /// The number of experience points normally awarded for this reason.
+    pub fn standard_amount(&self) -> NonZeroU16 {
+        match self {
+            SessionAwardReason::Session => NonZeroU16::new(2).unwrap(),
+            _ => NonZeroU16::new(1).unwrap(),
+        }
+    }
+}
+
+/// A single line of a session's experience award.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionAward {
+    pub(crate) pool: ExperiencePoolName,
+    pub(crate) reason: SessionAwardReason,
+    pub(crate) amount: NonZeroU16,
+}
+
+/// A mutation to award experience at the end of a session, broken down by
+/// reason. Each line of the award is recorded as a separate ledger entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AwardSessionExperience {
+    pub(crate) session: u16,
+    pub(crate) timestamp: Option<u64>,
+    pub(crate) awards: Vec<SessionAward>,
+}
+
+impl AwardSessionExperience {
+    /// Starts an award for the specified session.
+    pub fn new(session: u16) -> Self {
+        Self {
+            session,
+            timestamp: None,
+            awards: Vec::new(),
+        }
+    }
+
+    /// Awards the standard amount of base experience for a reason.
+    pub fn standard(self, reason: SessionAwardReason) -> Self {
+        let amount = reason.standard_amount();
+        self.award(ExperiencePoolName::Base, reason, amount)
+    }
+
+    /// Awards a specific amount of experience to a pool for a reason.
+    pub fn award(
+        mut self,
+        pool: ExperiencePoolName,
+        reason: SessionAwardReason,
+        amount: NonZeroU16,
+    ) -> Self {
+        self.awards.push(SessionAward {
+            pool,
+            reason,
+            amount,
+        });
+        self
+    }
+
+    /// Records when the session ended, as seconds since the Unix epoch.
+    pub fn at(mut self, timestamp: u64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
+impl From<AwardSessionExperience> for CharacterMutation {
+    fn from(award_session_experience: AwardSessionExperience) -> Self {
+        CharacterMutation::AwardSessionExperience(award_session_experience)
+    }
+}
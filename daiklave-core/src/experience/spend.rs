@@ -5,7 +5,8 @@ use serde::{Serialize, Deserialize};
 use crate::CharacterMutation;
 
 /// A mutation to spend experience. This reduces
-/// current experience and leaves total unchanged.
+/// current experience and leaves total unchanged. Recorded in the experience
+/// ledger without a reason; use ExperienceEntry for more detail.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SpendExperience(pub NonZeroU16);
 
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// The roll for a single interval of an extended roll.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntervalRoll {
+    pub(crate) successes: u8,
+    pub(crate) botch: Option<String>,
+}
+
+impl IntervalRoll {
+    /// The number of successes rolled.
+    pub fn successes(&self) -> u8 {
+        self.successes
+    }
+
+    /// If the roll botched, the consequence of the botch.
+    pub fn botch(&self) -> Option<&str> {
+        self.botch.as_deref()
+    }
+}
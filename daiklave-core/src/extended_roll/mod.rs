@@ -0,0 +1,100 @@
+mod interval;
+mod status;
+
+pub use interval::IntervalRoll;
+pub use status::ExtendedRollStatus;
+
+use serde::{Deserialize, Serialize};
+
+/// An action resolved over many rolls, each representing an interval of
+/// work, such as a crafting project or a sorcerous working. Successes
+/// accumulate toward a goal, and the action fails if the goal isn't reached
+/// within the terminus.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtendedRoll {
+    pub(crate) difficulty: u8,
+    pub(crate) goal: u16,
+    pub(crate) terminus: u8,
+    pub(crate) interval_days: u16,
+    pub(crate) rolls: Vec<IntervalRoll>,
+}
+
+impl ExtendedRoll {
+    pub(crate) fn new(difficulty: u8, goal: u16, terminus: u8, interval_days: u16) -> Self {
+        Self {
+            difficulty,
+            goal,
+            terminus,
+            interval_days,
+            rolls: Vec::new(),
+        }
+    }
+
+    /// The difficulty of each roll.
+    pub fn difficulty(&self) -> u8 {
+        self.difficulty
+    }
+
+    /// The number of accumulated successes needed for completion.
+    pub fn goal(&self) -> u16 {
+        self.goal
+    }
+
+    /// The maximum number of rolls before failure.
+    pub fn terminus(&self) -> u8 {
+        self.terminus
+    }
+
+    /// The number of days of work each roll represents.
+    pub fn interval_days(&self) -> u16 {
+        self.interval_days
+    }
+
+    /// The rolls made for each interval so far.
+    pub fn rolls(&self) -> &[IntervalRoll] {
+        &self.rolls
+    }
+
+    /// The consequences of every botched roll so far.
+    pub fn botches(&self) -> impl Iterator<Item = &str> + '_ {
+        self.rolls.iter().filter_map(|roll| roll.botch())
+    }
+
+    /// The accumulated successes toward the goal. Rolls which fail to meet
+    /// the difficulty contribute nothing, and a botch destroys all progress
+    /// made before it.
+    pub fn progress(&self) -> u16 {
+        self.rolls
+            .iter()
+            .rev()
+            .take_while(|roll| roll.botch.is_none())
+            .filter(|roll| roll.successes >= self.difficulty)
+            .map(|roll| u16::from(roll.successes))
+            .sum()
+    }
+
+    /// Whether the extended roll is still in progress, completed, or failed.
+    pub fn status(&self) -> ExtendedRollStatus {
+        if self.progress() >= self.goal {
+            ExtendedRollStatus::Completed
+        } else if self.rolls.len() >= self.terminus.into() {
+            ExtendedRollStatus::Failed
+        } else {
+            ExtendedRollStatus::InProgress
+        }
+    }
+
+    /// Records the roll for one interval and returns the new status, or
+    /// None without recording anything if the extended roll is finished.
+    pub(crate) fn roll(
+        &mut self,
+        successes: u8,
+        botch: Option<String>,
+    ) -> Option<ExtendedRollStatus> {
+        if self.status() != ExtendedRollStatus::InProgress {
+            return None;
+        }
+        self.rolls.push(IntervalRoll { successes, botch });
+        Some(self.status())
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Where an extended roll stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExtendedRollStatus {
+    /// More rolls are needed to reach the goal.
+    InProgress,
+    /// The accumulated successes reached the goal.
+    Completed,
+    /// The terminus was reached without reaching the goal.
+    Failed,
+}
@@ -22,7 +22,7 @@ impl<'source> Flaw<'source> {
 
     /// The book reference for the Flaw, if any.
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.book_reference
+        self.book_reference.clone()
     }
 
     /// A description of the Flaw's mechanical effects.
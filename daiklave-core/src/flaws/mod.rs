@@ -19,9 +19,9 @@ impl<'view, 'source> Flaws<'view, 'source> {
         self.0
             .flaws
             .get_key_value(name)
-            .map(|(&name, &(book_reference, description))| Flaw {
+            .map(|(&name, (book_reference, description))| Flaw {
                 name,
-                book_reference,
+                book_reference: book_reference.clone(),
                 description,
             })
     }
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+/// An error occurring while modifying a character's health levels.
+#[derive(Debug, Error)]
+pub enum HealthError {
+    /// The base health track can be replaced, but not removed
+    #[error("Cannot remove the base health track")]
+    BaseTrack,
+    /// Each named source of health levels may only be added once
+    #[error("Health levels from that source already exist")]
+    DuplicateSource,
+    /// Can't remove health levels from a source the character doesn't have
+    #[error("Health level source not found")]
+    NotFound,
+}
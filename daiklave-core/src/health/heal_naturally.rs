@@ -0,0 +1,17 @@
+use std::num::NonZeroU16;
+
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation for a character to heal naturally over some number of hours of
+/// rest. Boxes heal one at a time, worst wound penalty first, and progress
+/// toward the next box carries over between rests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealNaturally(pub NonZeroU16);
+
+impl From<HealNaturally> for CharacterMutation {
+    fn from(heal_naturally: HealNaturally) -> Self {
+        CharacterMutation::HealNaturally(heal_naturally)
+    }
+}
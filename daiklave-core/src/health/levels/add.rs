@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{health::WoundPenalty, CharacterMutation};
+
+use super::HealthLevelSource;
+
+/// A mutation to add health levels to a character from some source. The
+/// character's existing damage is preserved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddHealthLevels {
+    pub(crate) source: HealthLevelSource,
+    pub(crate) levels: HashMap<WoundPenalty, u8>,
+}
+
+impl AddHealthLevels {
+    /// Adds a purchase of the Ox-Body Technique. This may be purchased
+    /// multiple times.
+    pub fn ox_body_technique() -> Self {
+        Self {
+            source: HealthLevelSource::OxBodyTechnique,
+            levels: HashMap::new(),
+        }
+    }
+
+    /// Adds health levels granted by a merit.
+    pub fn merit(name: impl Into<String>, levels: HashMap<WoundPenalty, u8>) -> Self {
+        Self {
+            source: HealthLevelSource::Merit(name.into()),
+            levels,
+        }
+    }
+
+    /// Adds health levels from any other named source.
+    pub fn custom(name: impl Into<String>, levels: HashMap<WoundPenalty, u8>) -> Self {
+        Self {
+            source: HealthLevelSource::Custom(name.into()),
+            levels,
+        }
+    }
+}
+
+impl From<AddHealthLevels> for CharacterMutation {
+    fn from(add_health_levels: AddHealthLevels) -> Self {
+        CharacterMutation::AddHealthLevels(add_health_levels)
+    }
+}
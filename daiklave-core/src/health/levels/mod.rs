@@ -0,0 +1,168 @@
+mod add;
+mod remove;
+mod source;
+pub use add::AddHealthLevels;
+pub use remove::RemoveHealthLevels;
+pub use source::HealthLevelSource;
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Health, HealthError, WoundPenalty};
+
+const WOUND_PENALTIES: [WoundPenalty; 5] = [
+    WoundPenalty::Zero,
+    WoundPenalty::MinusOne,
+    WoundPenalty::MinusTwo,
+    WoundPenalty::MinusFour,
+    WoundPenalty::Incapacitated,
+];
+
+/// The health levels granted by one purchase of the Ox-Body Technique, for a
+/// character with the given Stamina.
+pub fn ox_body_technique_levels(stamina: u8) -> Vec<WoundPenalty> {
+    match stamina {
+        0..=2 => vec![WoundPenalty::MinusOne, WoundPenalty::MinusTwo],
+        3..=4 => vec![
+            WoundPenalty::MinusOne,
+            WoundPenalty::MinusTwo,
+            WoundPenalty::MinusTwo,
+        ],
+        _ => vec![
+            WoundPenalty::Zero,
+            WoundPenalty::MinusOne,
+            WoundPenalty::MinusTwo,
+            WoundPenalty::MinusTwo,
+        ],
+    }
+}
+
+/// The sources of a character's health boxes, from which their health track
+/// is derived.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct HealthLevels {
+    pub(crate) base: HashMap<WoundPenalty, u8>,
+    pub(crate) ox_body_technique: u8,
+    pub(crate) other: Vec<(HealthLevelSource, HashMap<WoundPenalty, u8>)>,
+}
+
+impl Default for HealthLevels {
+    fn default() -> Self {
+        Self {
+            base: [
+                (WoundPenalty::Zero, 1),
+                (WoundPenalty::MinusOne, 2),
+                (WoundPenalty::MinusTwo, 2),
+                (WoundPenalty::MinusFour, 1),
+                (WoundPenalty::Incapacitated, 1),
+            ]
+            .into_iter()
+            .collect(),
+            ox_body_technique: 0,
+            other: Vec::new(),
+        }
+    }
+}
+
+impl HealthLevels {
+    /// Treats an existing health track as the base track, with no other
+    /// sources.
+    pub(crate) fn from_health(health: &Health) -> Self {
+        Self {
+            base: [
+                (WoundPenalty::Zero, health.zero_boxes),
+                (WoundPenalty::MinusOne, health.minus_one_boxes),
+                (WoundPenalty::MinusTwo, health.minus_two_boxes),
+                (WoundPenalty::MinusFour, health.minus_four_boxes),
+                (WoundPenalty::Incapacitated, health.incapacitated_boxes),
+            ]
+            .into_iter()
+            .collect(),
+            ox_body_technique: 0,
+            other: Vec::new(),
+        }
+    }
+
+    /// Every health box and its source, ordered by wound penalty, with the
+    /// base track first within each penalty.
+    pub(crate) fn boxes(&self, stamina: u8) -> Vec<(HealthLevelSource, WoundPenalty)> {
+        let expand = |source: &HealthLevelSource, levels: &HashMap<WoundPenalty, u8>| {
+            WOUND_PENALTIES
+                .into_iter()
+                .flat_map(|penalty| {
+                    std::iter::repeat_n(
+                        (source.clone(), penalty),
+                        levels.get(&penalty).copied().unwrap_or_default().into(),
+                    )
+                })
+                .collect::<Vec<(HealthLevelSource, WoundPenalty)>>()
+        };
+
+        let mut boxes = expand(&HealthLevelSource::Base, &self.base);
+        for _ in 0..self.ox_body_technique {
+            boxes.extend(
+                ox_body_technique_levels(stamina)
+                    .into_iter()
+                    .map(|penalty| (HealthLevelSource::OxBodyTechnique, penalty)),
+            );
+        }
+        for (source, levels) in self.other.iter() {
+            boxes.extend(expand(source, levels));
+        }
+        // Stable sort keeps sources in order within each wound penalty
+        boxes.sort_by_key(|(_, penalty)| *penalty);
+        boxes
+    }
+
+    /// The total number of boxes at each wound penalty.
+    pub(crate) fn track(&self, stamina: u8) -> HashMap<WoundPenalty, u8> {
+        self.boxes(stamina)
+            .into_iter()
+            .fold(HashMap::new(), |mut track, (_, penalty)| {
+                *track.entry(penalty).or_default() += 1;
+                track
+            })
+    }
+
+    pub(crate) fn add(&mut self, add: &AddHealthLevels) -> Result<&mut Self, HealthError> {
+        match &add.source {
+            HealthLevelSource::Base => {
+                self.base = add.levels.clone();
+            }
+            HealthLevelSource::OxBodyTechnique => {
+                self.ox_body_technique = self.ox_body_technique.saturating_add(1);
+            }
+            source => {
+                if self.other.iter().any(|(existing, _)| existing == source) {
+                    return Err(HealthError::DuplicateSource);
+                }
+                self.other.push((source.clone(), add.levels.clone()));
+            }
+        }
+        Ok(self)
+    }
+
+    pub(crate) fn remove(&mut self, source: &HealthLevelSource) -> Result<&mut Self, HealthError> {
+        match source {
+            HealthLevelSource::Base => {
+                return Err(HealthError::BaseTrack);
+            }
+            HealthLevelSource::OxBodyTechnique => {
+                if self.ox_body_technique == 0 {
+                    return Err(HealthError::NotFound);
+                }
+                self.ox_body_technique -= 1;
+            }
+            source => {
+                let index = self
+                    .other
+                    .iter()
+                    .position(|(existing, _)| existing == source)
+                    .ok_or(HealthError::NotFound)?;
+                self.other.remove(index);
+            }
+        }
+        Ok(self)
+    }
+}
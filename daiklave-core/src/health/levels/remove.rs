@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::HealthLevelSource;
+
+/// A mutation to remove the health levels granted by a source. For the
+/// Ox-Body Technique, removes a single purchase. The character's existing
+/// damage is preserved, rolling over if the track shrinks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoveHealthLevels(pub HealthLevelSource);
+
+impl From<RemoveHealthLevels> for CharacterMutation {
+    fn from(remove_health_levels: RemoveHealthLevels) -> Self {
+        CharacterMutation::RemoveHealthLevels(remove_health_levels)
+    }
+}
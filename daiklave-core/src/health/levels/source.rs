@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Where a health box came from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HealthLevelSource {
+    /// The character's base health track.
+    Base,
+    /// A purchase of the Ox-Body Technique Charm. The health levels granted
+    /// by each purchase depend on the character's Stamina.
+    OxBodyTechnique,
+    /// A merit which grants extra health levels, by name.
+    Merit(String),
+    /// Any other source of health levels, by name.
+    Custom(String),
+}
@@ -1,12 +1,23 @@
 mod damage_level;
+mod error;
 mod heal_damage;
+mod heal_naturally;
 mod health_iter;
+mod levels;
+mod natural_healing;
 mod set;
 mod take_damage;
 mod wound_penalty;
 
 pub use damage_level::DamageLevel;
+pub use error::HealthError;
 pub use heal_damage::HealDamage;
+pub use heal_naturally::HealNaturally;
+pub(crate) use levels::HealthLevels;
+pub use levels::{
+    ox_body_technique_levels, AddHealthLevels, HealthLevelSource, RemoveHealthLevels,
+};
+pub use natural_healing::NaturalHealingReport;
 pub use set::SetHealthTrack;
 pub use take_damage::TakeDamage;
 pub use wound_penalty::WoundPenalty;
@@ -28,6 +39,9 @@ pub struct Health {
     pub(crate) bashing_damage: u8,
     pub(crate) lethal_damage: u8,
     pub(crate) aggravated_damage: u8,
+    /// Hours of rest accumulated toward naturally healing the next box.
+    #[serde(default)]
+    pub(crate) healing_progress: u32,
 }
 
 impl Default for Health {
@@ -41,6 +55,7 @@ impl Default for Health {
             bashing_damage: 0,
             lethal_damage: 0,
             aggravated_damage: 0,
+            healing_progress: 0,
         }
     }
 }
@@ -103,6 +118,11 @@ impl Health {
             + self.minus_four_boxes
             + self.incapacitated_boxes;
 
+        if amount > 0 {
+            // Fresh wounds interrupt any healing in progress
+            self.healing_progress = 0;
+        }
+
         match damage_level {
             DamageLevel::Bashing => {
                 self.bashing_damage += amount;
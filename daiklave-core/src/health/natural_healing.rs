@@ -0,0 +1,139 @@
+use super::{DamageLevel, Health, WoundPenalty};
+
+const DAY: u32 = 24;
+const WEEK: u32 = 7 * DAY;
+const MONTH: u32 = 30 * DAY;
+
+impl DamageLevel {
+    /// The hours of rest needed to naturally heal a single box of this
+    /// damage at the given wound penalty. Exalts, and mortals with the
+    /// Exalted Healing merit, heal much faster than ordinary mortals.
+    /// Aggravated damage never heals naturally.
+    pub fn natural_healing_hours(
+        &self,
+        wound_penalty: WoundPenalty,
+        exalted_healing: bool,
+    ) -> Option<u32> {
+        let hours = match (self, exalted_healing, wound_penalty) {
+            (DamageLevel::Aggravated, _, _) => {
+                return None;
+            }
+            (DamageLevel::Bashing, true, WoundPenalty::Zero) => 1,
+            (DamageLevel::Bashing, true, WoundPenalty::MinusOne) => 12,
+            (DamageLevel::Bashing, true, WoundPenalty::MinusTwo) => DAY,
+            (DamageLevel::Bashing, true, WoundPenalty::MinusFour) => 2 * DAY,
+            (DamageLevel::Bashing, true, WoundPenalty::Incapacitated) => 4 * DAY,
+            (DamageLevel::Lethal, true, WoundPenalty::Zero) => DAY,
+            (DamageLevel::Lethal, true, WoundPenalty::MinusOne) => 2 * DAY,
+            (DamageLevel::Lethal, true, WoundPenalty::MinusTwo) => 3 * DAY,
+            (DamageLevel::Lethal, true, WoundPenalty::MinusFour) => 5 * DAY,
+            (DamageLevel::Lethal, true, WoundPenalty::Incapacitated) => WEEK,
+            (DamageLevel::Bashing, false, WoundPenalty::Zero) => 12,
+            (DamageLevel::Bashing, false, WoundPenalty::MinusOne) => DAY,
+            (DamageLevel::Bashing, false, WoundPenalty::MinusTwo) => 2 * DAY,
+            (DamageLevel::Bashing, false, WoundPenalty::MinusFour) => 4 * DAY,
+            (DamageLevel::Bashing, false, WoundPenalty::Incapacitated) => WEEK,
+            (DamageLevel::Lethal, false, WoundPenalty::Zero) => 2 * DAY,
+            (DamageLevel::Lethal, false, WoundPenalty::MinusOne) => 4 * DAY,
+            (DamageLevel::Lethal, false, WoundPenalty::MinusTwo) => WEEK,
+            (DamageLevel::Lethal, false, WoundPenalty::MinusFour) => 2 * WEEK,
+            (DamageLevel::Lethal, false, WoundPenalty::Incapacitated) => MONTH,
+        };
+        Some(hours)
+    }
+}
+
+/// The result of a period of natural healing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NaturalHealingReport {
+    pub(crate) healed: Vec<(WoundPenalty, DamageLevel)>,
+    pub(crate) hours_until_next_box: Option<u32>,
+    pub(crate) hours_until_healed: Option<u32>,
+}
+
+impl NaturalHealingReport {
+    /// The boxes that were healed, in the order they healed.
+    pub fn healed(&self) -> impl Iterator<Item = (WoundPenalty, DamageLevel)> + '_ {
+        self.healed.iter().copied()
+    }
+
+    /// The hours of further rest before the next box heals, or None if no
+    /// damage remains that can heal naturally.
+    pub fn hours_until_next_box(&self) -> Option<u32> {
+        self.hours_until_next_box
+    }
+
+    /// The hours of further rest before all bashing and lethal damage has
+    /// healed, or None if no damage remains that can heal naturally.
+    pub fn hours_until_healed(&self) -> Option<u32> {
+        self.hours_until_healed
+    }
+}
+
+impl Health {
+    /// The next box to heal naturally: the damaged box with the worst wound
+    /// penalty, unless it is aggravated.
+    fn next_to_heal(&self) -> Option<(WoundPenalty, DamageLevel)> {
+        self.iter()
+            .filter_map(|(wound_penalty, maybe_damage)| {
+                maybe_damage.map(|damage| (wound_penalty, damage))
+            })
+            .last()
+            .filter(|(_, damage)| damage != &DamageLevel::Aggravated)
+    }
+
+    /// The hours of rest before the next box of damage heals naturally, or
+    /// None if no damage remains that can heal naturally.
+    pub fn hours_until_next_box(&self, exalted_healing: bool) -> Option<u32> {
+        let (wound_penalty, damage) = self.next_to_heal()?;
+        damage
+            .natural_healing_hours(wound_penalty, exalted_healing)
+            .map(|hours| hours.saturating_sub(self.healing_progress))
+    }
+
+    /// The hours of rest before all bashing and lethal damage heals
+    /// naturally, or None if no damage remains that can heal naturally.
+    pub fn hours_until_healed(&self, exalted_healing: bool) -> Option<u32> {
+        self.next_to_heal()?;
+        let total = self
+            .iter()
+            .filter_map(|(wound_penalty, maybe_damage)| {
+                maybe_damage?.natural_healing_hours(wound_penalty, exalted_healing)
+            })
+            .sum::<u32>();
+        Some(total.saturating_sub(self.healing_progress))
+    }
+
+    pub(crate) fn heal_naturally(
+        &mut self,
+        hours: u32,
+        exalted_healing: bool,
+    ) -> NaturalHealingReport {
+        let mut healed = Vec::new();
+        let mut remaining = self.healing_progress.saturating_add(hours);
+        self.healing_progress = 0;
+
+        while let Some((wound_penalty, damage)) = self.next_to_heal() {
+            let Some(needed) = damage.natural_healing_hours(wound_penalty, exalted_healing) else {
+                break;
+            };
+            if remaining < needed {
+                self.healing_progress = remaining;
+                break;
+            }
+            remaining -= needed;
+            match damage {
+                DamageLevel::Bashing => self.bashing_damage -= 1,
+                DamageLevel::Lethal => self.lethal_damage -= 1,
+                DamageLevel::Aggravated => {}
+            }
+            healed.push((wound_penalty, damage));
+        }
+
+        NaturalHealingReport {
+            healed,
+            hours_until_next_box: self.hours_until_next_box(exalted_healing),
+            hours_until_healed: self.hours_until_healed(exalted_healing),
+        }
+    }
+}
@@ -19,7 +19,7 @@ pub(crate) struct HearthstoneDetailsMemo {
 impl From<&HearthstoneDetails<'_>> for HearthstoneDetailsMemo {
     fn from(view: &HearthstoneDetails<'_>) -> Self {
         Self {
-            book_reference: view.book_reference,
+            book_reference: view.book_reference.cloned(),
             category: view.category,
             geomancy_level: view.geomancy_level,
             powers: view.powers.to_owned(),
@@ -9,7 +9,7 @@ use super::{
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct HearthstoneDetails<'source> {
-    book_reference: Option<BookReference>,
+    book_reference: Option<&'source BookReference>,
     category: HearthstoneCategory,
     geomancy_level: GeomancyLevel,
     powers: &'source str,
@@ -19,7 +19,7 @@ pub(crate) struct HearthstoneDetails<'source> {
 impl<'source> From<&'source HearthstoneDetailsMemo> for HearthstoneDetails<'source> {
     fn from(memo: &'source HearthstoneDetailsMemo) -> Self {
         Self {
-            book_reference: memo.book_reference,
+            book_reference: memo.book_reference.as_ref(),
             category: memo.category,
             geomancy_level: memo.geomancy_level,
             powers: memo.powers.as_str(),
@@ -30,7 +30,7 @@ impl<'source> From<&'source HearthstoneDetailsMemo> for HearthstoneDetails<'sour
 
 impl<'source> HearthstoneDetails<'source> {
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.book_reference
+        self.book_reference.cloned()
     }
 
     pub fn category(&self) -> HearthstoneCategory {
@@ -1,4 +1,7 @@
-use crate::{artifact::ArtifactName, book_reference::BookReference, merits::merit::Merit};
+use crate::{
+    artifact::ArtifactName, book_reference::BookReference, charms::charm::evocation::EvokableName,
+    merits::merit::Merit, Character,
+};
 
 mod add;
 /// A builder path for creating new Hearthstones.
@@ -51,6 +54,14 @@ impl<'source> Hearthstone<'source> {
         self.0.name()
     }
 
+    /// The Evocations the character can currently use from the hearthstone.
+    /// Evocations which build on a disabled Evocation are omitted.
+    pub fn evocations(&self, character: &Character<'source>) -> impl Iterator<Item = &'source str> {
+        character
+            .available_evocations(EvokableName::Hearthstone(self.name()))
+            .into_iter()
+    }
+
     /// If the hearthstone is currently slotted into an artifact, the Id of
     /// that artifact.
     pub fn slotted_into(&self) -> Option<ArtifactName<'source>> {
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use super::{IntimacyLevel, IntimacyType, IntimacyTypeMemo};
+
+/// How an Intimacy changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntimacyChangeKind {
+    /// The Intimacy was strengthened.
+    Strengthened {
+        /// The level before the change.
+        from: IntimacyLevel,
+        /// The level after the change.
+        to: IntimacyLevel,
+    },
+    /// The Intimacy was weakened, or removed if it was Minor.
+    Weakened {
+        /// The level before the change.
+        from: IntimacyLevel,
+        /// The level after the change, or None if the Intimacy was lost.
+        to: Option<IntimacyLevel>,
+    },
+    /// The emotional context of a Tie changed.
+    EmotionChanged {
+        /// The emotion before the change.
+        from: String,
+    },
+}
+
+/// A record of a change to one of the character's Intimacies, and what
+/// caused it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntimacyChange {
+    pub(crate) intimacy_type: IntimacyTypeMemo,
+    pub(crate) kind: IntimacyChangeKind,
+    pub(crate) cause: String,
+}
+
+impl IntimacyChange {
+    /// The Intimacy that changed, as it was after the change.
+    pub fn intimacy_type(&self) -> IntimacyType<'_> {
+        (&self.intimacy_type).into()
+    }
+
+    /// How the Intimacy changed.
+    pub fn kind(&self) -> &IntimacyChangeKind {
+        &self.kind
+    }
+
+    /// What caused the change, such as a social influence or session
+    /// reflection.
+    pub fn cause(&self) -> &str {
+        self.cause.as_str()
+    }
+}
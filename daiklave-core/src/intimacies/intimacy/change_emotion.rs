@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::{IntimacyType, IntimacyTypeMemo};
+
+/// A mutation to change the emotional context of a Tie, keeping its level.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeTieEmotion {
+    pub(crate) from: IntimacyTypeMemo,
+    pub(crate) to: IntimacyTypeMemo,
+    pub(crate) cause: String,
+}
+
+impl ChangeTieEmotion {
+    /// Constructs a mutation to change the emotion of a Tie, recording the
+    /// cause of the change. The Intimacy must be a Tie when applied.
+    pub fn new(
+        intimacy_type: IntimacyType<'_>,
+        emotion: impl Into<String>,
+        cause: impl Into<String>,
+    ) -> Self {
+        let from: IntimacyTypeMemo = intimacy_type.into();
+        let to = match &from {
+            IntimacyTypeMemo::Tie(target, _) => {
+                IntimacyTypeMemo::Tie(target.clone(), emotion.into())
+            }
+            IntimacyTypeMemo::Principle(_) => from.clone(),
+        };
+        Self {
+            from,
+            to,
+            cause: cause.into(),
+        }
+    }
+}
+
+impl From<ChangeTieEmotion> for CharacterMutation {
+    fn from(change_tie_emotion: ChangeTieEmotion) -> Self {
+        Self::ChangeTieEmotion(change_tie_emotion)
+    }
+}
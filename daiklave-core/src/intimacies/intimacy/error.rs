@@ -3,10 +3,16 @@ use thiserror::Error;
 /// An error related to Intimacies.
 #[derive(Debug, Error)]
 pub enum IntimacyError {
+    /// Defining is the strongest an Intimacy can be.
+    #[error("Intimacy is already Defining")]
+    AlreadyDefining,
     /// Only one instance of an IntimacyId per character.
     #[error("Cannot have duplicate Intimacies")]
     DuplicateIntimacy,
     /// Cannot remove or alter a missing Intimacy.
     #[error("Intimacy not found")]
     NotFound,
+    /// Only Ties have an emotional context to change.
+    #[error("Intimacy is not a Tie")]
+    NotATie,
 }
@@ -22,3 +22,23 @@ impl From<IntimacyLevel> for u8 {
         }
     }
 }
+
+impl IntimacyLevel {
+    /// The next stronger level, or None for a Defining Intimacy.
+    pub fn stronger(&self) -> Option<Self> {
+        match self {
+            IntimacyLevel::Minor => Some(IntimacyLevel::Major),
+            IntimacyLevel::Major => Some(IntimacyLevel::Defining),
+            IntimacyLevel::Defining => None,
+        }
+    }
+
+    /// The next weaker level, or None for a Minor Intimacy.
+    pub fn weaker(&self) -> Option<Self> {
+        match self {
+            IntimacyLevel::Minor => None,
+            IntimacyLevel::Major => Some(IntimacyLevel::Minor),
+            IntimacyLevel::Defining => Some(IntimacyLevel::Major),
+        }
+    }
+}
@@ -2,17 +2,25 @@ mod add;
 
 /// A builder to construct a new Intimacy.
 pub mod builder;
+mod change;
+mod change_emotion;
 mod error;
 mod intimacy_type;
 mod level;
 mod remove;
+mod strengthen;
+mod weaken;
 
 pub use add::AddIntimacy;
+pub use change::{IntimacyChange, IntimacyChangeKind};
+pub use change_emotion::ChangeTieEmotion;
 pub use error::IntimacyError;
 pub use intimacy_type::IntimacyType;
 pub(crate) use intimacy_type::IntimacyTypeMemo;
 pub use level::IntimacyLevel;
 pub use remove::RemoveIntimacy;
+pub use strengthen::StrengthenIntimacy;
+pub use weaken::WeakenIntimacy;
 
 /// An Intimacy held by a character.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::{IntimacyType, IntimacyTypeMemo};
+
+/// A mutation to strengthen an Intimacy by one level. Defining Intimacies
+/// cannot be strengthened further.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrengthenIntimacy {
+    pub(crate) intimacy_type: IntimacyTypeMemo,
+    pub(crate) cause: String,
+}
+
+impl StrengthenIntimacy {
+    /// Constructs a mutation to strengthen the specified Intimacy, recording
+    /// the cause of the change.
+    pub fn new(intimacy_type: IntimacyType<'_>, cause: impl Into<String>) -> Self {
+        Self {
+            intimacy_type: intimacy_type.into(),
+            cause: cause.into(),
+        }
+    }
+}
+
+impl From<StrengthenIntimacy> for CharacterMutation {
+    fn from(strengthen_intimacy: StrengthenIntimacy) -> Self {
+        Self::StrengthenIntimacy(strengthen_intimacy)
+    }
+}
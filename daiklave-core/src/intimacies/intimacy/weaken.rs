@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::{IntimacyType, IntimacyTypeMemo};
+
+/// A mutation to weaken an Intimacy by one level. Weakening a Minor Intimacy
+/// removes it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeakenIntimacy {
+    pub(crate) intimacy_type: IntimacyTypeMemo,
+    pub(crate) cause: String,
+}
+
+impl WeakenIntimacy {
+    /// Constructs a mutation to weaken the specified Intimacy, recording
+    /// the cause of the change.
+    pub fn new(intimacy_type: IntimacyType<'_>, cause: impl Into<String>) -> Self {
+        Self {
+            intimacy_type: intimacy_type.into(),
+            cause: cause.into(),
+        }
+    }
+}
+
+impl From<WeakenIntimacy> for CharacterMutation {
+    fn from(weaken_intimacy: WeakenIntimacy) -> Self {
+        Self::WeakenIntimacy(weaken_intimacy)
+    }
+}
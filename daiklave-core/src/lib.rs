@@ -15,6 +15,9 @@ pub mod armor;
 /// General properties of artifacts.
 pub mod artifact;
 
+/// Battle groups, for mass combat.
+pub mod battle_group;
+
 /// Official page references.
 pub mod book_reference;
 
@@ -22,12 +25,23 @@ pub mod book_reference;
 /// type definitions are recorded separately.
 pub mod charms;
 
+/// Combat encounters, Initiative, and turn order.
+pub mod combat;
+
+/// The Craft ability and crafting projects, from basic objects to legendary
+/// artifacts.
+pub mod craft;
+
 /// Traits which depend on being Mortal or Exalted.
 pub mod exaltation;
 
 /// A character's Experience points.
 pub mod experience;
 
+/// Actions resolved over many rolls, like crafting projects and sorcerous
+/// workings.
+pub mod extended_roll;
+
 /// Flaws, which can earn extra experience or add an interesting dimension to
 /// a character.
 pub mod flaws;
@@ -48,6 +62,9 @@ pub mod intimacies;
 /// Languages of the Realm and Threshold
 pub mod languages;
 
+/// Scene, session, and story boundaries, and the resources they refresh.
+pub mod lifecycle;
+
 /// Martial Arts style logic
 pub mod martial_arts;
 
@@ -57,16 +74,27 @@ pub mod merits;
 /// All of the ways a character can be atomically updated.
 pub mod mutations;
 
+/// Quick Characters, the simplified statblocks used for NPCs and antagonists
+pub mod quick_character;
+
+/// Social influence against Resolve, Guile, and Intimacies.
+pub mod social;
+
 /// Sorcery logic
 pub mod sorcery;
 
+/// Spirits, such as gods, demons, and elementals
+pub mod spirit;
+
+/// Stunts and their rewards.
+pub mod stunt;
+
 /// Logic for building and equipping weapons
 pub mod weapons;
 
 mod character;
 pub(crate) mod concept;
 pub use concept::ConceptError;
-pub(crate) mod craft;
 pub(crate) mod name;
 mod willpower;
 
@@ -74,3 +102,5 @@ pub use character::{
     Character, CharacterEvent, CharacterEventSource, CharacterMemo, CharacterMutation,
     CharacterMutationError,
 };
+pub use quick_character::{QuickCharacter, QuickCharacterMemo, QuickCharacterMutation};
+pub use spirit::{Spirit, SpiritMemo, SpiritMutation};
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to end the current scene, releasing all scene-length mote
+/// commitments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndScene;
+
+impl From<EndScene> for CharacterMutation {
+    fn from(_end_scene: EndScene) -> Self {
+        CharacterMutation::EndScene
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to end the current session. This ends the scene, and reduces
+/// the character's Limit (if any) by one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndSession;
+
+impl From<EndSession> for CharacterMutation {
+    fn from(_end_session: EndSession) -> Self {
+        CharacterMutation::EndSession
+    }
+}
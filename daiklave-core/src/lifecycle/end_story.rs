@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to end the current story. This ends the session, recovers all
+/// spent motes, and restores the character's Willpower to its permanent
+/// rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndStory;
+
+impl From<EndStory> for CharacterMutation {
+    fn from(_end_story: EndStory) -> Self {
+        CharacterMutation::EndStory
+    }
+}
@@ -0,0 +1,17 @@
+mod end_scene;
+mod end_session;
+mod end_story;
+mod report;
+mod rest;
+pub use end_scene::EndScene;
+pub use end_session::EndSession;
+pub use end_story::EndStory;
+pub use report::LifecycleReport;
+pub use rest::Rest;
+
+/// The motes an Exalt recovers for each hour of rest.
+pub const MOTES_PER_HOUR_RESTING: u8 = 10;
+
+/// The hours of rest which count as a full night's sleep, restoring a point
+/// of Willpower.
+pub const FULL_NIGHT_HOURS: u8 = 8;
@@ -0,0 +1,63 @@
+/// Everything that changed on a character as the result of a scene, session,
+/// or story ending, or a period of rest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LifecycleReport {
+    pub(crate) released_commitments: Vec<String>,
+    pub(crate) motes_recovered: u8,
+    pub(crate) willpower_recovered: u8,
+    pub(crate) limit_reduced: u8,
+    pub(crate) sorcerous_motes_lost: u8,
+    pub(crate) limit_break_ended: bool,
+}
+
+impl LifecycleReport {
+    /// The names of the scene-length mote commitments that were released.
+    /// Their motes become spent, rather than available.
+    pub fn released_commitments(&self) -> impl Iterator<Item = &str> + '_ {
+        self.released_commitments.iter().map(|name| name.as_str())
+    }
+
+    /// The number of spent motes that were recovered.
+    pub fn motes_recovered(&self) -> u8 {
+        self.motes_recovered
+    }
+
+    /// The amount of Willpower that was recovered.
+    pub fn willpower_recovered(&self) -> u8 {
+        self.willpower_recovered
+    }
+
+    /// The amount of Limit that was removed from the Limit track.
+    pub fn limit_reduced(&self) -> u8 {
+        self.limit_reduced
+    }
+
+    /// The number of banked sorcerous motes that vanished because the scene
+    /// ended while the character was shaping a spell.
+    pub fn sorcerous_motes_lost(&self) -> u8 {
+        self.sorcerous_motes_lost
+    }
+
+    /// True if the character's Limit Break ran its course and ended.
+    pub fn limit_break_ended(&self) -> bool {
+        self.limit_break_ended
+    }
+
+    /// True if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub(crate) fn merge(&mut self, other: LifecycleReport) {
+        self.released_commitments.extend(other.released_commitments);
+        self.motes_recovered = self.motes_recovered.saturating_add(other.motes_recovered);
+        self.willpower_recovered = self
+            .willpower_recovered
+            .saturating_add(other.willpower_recovered);
+        self.limit_reduced = self.limit_reduced.saturating_add(other.limit_reduced);
+        self.sorcerous_motes_lost = self
+            .sorcerous_motes_lost
+            .saturating_add(other.sorcerous_motes_lost);
+        self.limit_break_ended |= other.limit_break_ended;
+    }
+}
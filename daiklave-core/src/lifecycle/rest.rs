@@ -0,0 +1,24 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation for the character to rest for some number of hours,
+/// recovering motes. Resting for a full night also restores a point of
+/// Willpower, up to the character's permanent rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rest(pub NonZeroU8);
+
+impl Rest {
+    /// The number of hours rested.
+    pub fn hours(&self) -> u8 {
+        self.0.get()
+    }
+}
+
+impl From<Rest> for CharacterMutation {
+    fn from(rest: Rest) -> Self {
+        CharacterMutation::Rest(rest)
+    }
+}
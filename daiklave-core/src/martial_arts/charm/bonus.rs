@@ -0,0 +1,10 @@
+/// The extra text of a Martial Arts Charm which applies to a specific
+/// character, based on their Exalt type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MartialArtsCharmBonus<'source> {
+    /// The Charm's Mastery effect, which enhances it for Solars.
+    Mastery(&'source str),
+    /// The Charm's Terrestrial limitation, which restricts it for Exalts
+    /// who lack the Solars' mastery of the martial arts.
+    Terrestrial(&'source str),
+}
@@ -1,4 +1,5 @@
 mod add;
+mod bonus;
 /// A builder path for constructing a Martial Arts charm.
 pub mod builder;
 mod keyword;
@@ -10,6 +11,7 @@ use std::{
 };
 
 pub use add::AddMartialArtsCharm;
+pub use bonus::MartialArtsCharmBonus;
 pub use keyword::MartialArtsCharmKeyword;
 pub use name::MartialArtsCharmName;
 
@@ -40,7 +42,7 @@ impl<'source> MartialArtsCharm<'source> {
 
     /// The book reference for the Charm, if any.
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.details.book_reference
+        self.details.book_reference.clone()
     }
 
     /// A brief summary of the Charm.
@@ -3,13 +3,28 @@ use thiserror::Error;
 /// An error related to Martial Arts.
 #[derive(Debug, Error)]
 pub enum MartialArtsError {
+    /// The style does not allow the character's worn armor.
+    #[error("Armor is too heavy for this style")]
+    ArmorNotAllowed,
+    /// The character does not know that Martial Arts Charm.
+    #[error("Martial Arts Charm not found")]
+    CharmNotFound,
     /// Style already exists.
     #[error("Already have style with this id")]
     DuplicateStyle,
+    /// No Martial Arts Form is active.
+    #[error("No Form is active")]
+    NoActiveForm,
+    /// Only Charms with the Form keyword can be activated as a Form.
+    #[error("Not a Form Charm")]
+    NotAForm,
     /// Prerequisite conditions were not met.
     #[error("Prerequisites not met")]
     PrerequsitesNotMet,
     /// Can't remove a missing style
     #[error("Style not found")]
     StyleNotFound,
+    /// The style does not allow one of the character's equipped weapons.
+    #[error("Weapon not usable with this style")]
+    WeaponNotAllowed,
 }
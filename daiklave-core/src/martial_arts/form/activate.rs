@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{martial_arts::charm::MartialArtsCharmName, CharacterMutation};
+
+/// A mutation to activate a Martial Arts Form Charm. Only one Form may be
+/// active at a time, so this ends any other active Form. The character's
+/// equipped weapons and armor must be allowed by the Form's style.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivateMartialArtsForm(pub MartialArtsCharmName);
+
+impl From<ActivateMartialArtsForm> for CharacterMutation {
+    fn from(activate_martial_arts_form: ActivateMartialArtsForm) -> Self {
+        CharacterMutation::ActivateMartialArtsForm(activate_martial_arts_form)
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to end the character's active Martial Arts Form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndMartialArtsForm;
+
+impl From<EndMartialArtsForm> for CharacterMutation {
+    fn from(_end_martial_arts_form: EndMartialArtsForm) -> Self {
+        CharacterMutation::EndMartialArtsForm
+    }
+}
@@ -0,0 +1,5 @@
+mod activate;
+mod end;
+
+pub use activate::ActivateMartialArtsForm;
+pub use end::EndMartialArtsForm;
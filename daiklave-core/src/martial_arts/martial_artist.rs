@@ -3,6 +3,7 @@ use crate::{
     armor::armor_item::ArmorWeightClass,
     book_reference::BookReference,
     exaltation::ExaltationMartialArtist,
+    weapons::weapon::{Weapon, WeaponName},
 };
 
 use super::{charm::MartialArtsCharm, style::MartialArtsStyleWeapon};
@@ -11,6 +12,7 @@ use super::{charm::MartialArtsCharm, style::MartialArtsStyleWeapon};
 pub struct MartialArtsStyle<'view, 'source> {
     pub(crate) name: &'source str,
     pub(crate) maybe_exalt: ExaltationMartialArtist<'view, 'source>,
+    pub(crate) active_form: Option<&'source str>,
 }
 
 impl<'view, 'source> MartialArtsStyle<'view, 'source> {
@@ -42,6 +44,40 @@ impl<'view, 'source> MartialArtsStyle<'view, 'source> {
         self.maybe_exalt.max_armor_weight()
     }
 
+    /// True if the weapon can be used with the style. Unarmed attacks use
+    /// the Unarmed entry; other weapons match by their base weapon name.
+    pub fn allows_weapon(&self, weapon: &Weapon<'_>) -> bool {
+        let base_name = match weapon.name() {
+            WeaponName::Unarmed => None,
+            WeaponName::Mundane(name) => Some(name),
+            WeaponName::Artifact(_) => weapon.base_artifact_weapon().map(|(name, _)| name),
+        };
+        self.usable_weapons()
+            .any(|usable| match (usable, base_name) {
+                (MartialArtsStyleWeapon::Unarmed, None) => true,
+                (MartialArtsStyleWeapon::BaseWeapon(usable_name), Some(name)) => {
+                    usable_name.eq_ignore_ascii_case(name)
+                }
+                _ => false,
+            })
+    }
+
+    /// True if armor of the specified weight (or no armor, if None) can be
+    /// worn with the style.
+    pub fn allows_armor(&self, weight_class: Option<ArmorWeightClass>) -> bool {
+        match (weight_class, self.max_armor_weight()) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(worn), Some(max)) => worn <= max,
+        }
+    }
+
+    /// The style's Form Charm, if it is the character's active Form.
+    pub fn active_form(&self) -> Option<MartialArtsCharm<'source>> {
+        let active_form = self.active_form?;
+        self.charms().find(|charm| charm.name() == active_form)
+    }
+
     /// The details of the Martial Arts ability the character has for this
     /// style, including rating and specialties.
     pub fn ability(&self) -> Ability<'view, 'source> {
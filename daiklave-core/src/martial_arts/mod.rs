@@ -1,6 +1,7 @@
 /// Details of an individual Martial Arts Charm.
 pub mod charm;
 mod error;
+mod form;
 mod martial_artist;
 
 /// Details of an individual Martial Arts style.
@@ -8,21 +9,59 @@ pub mod style;
 
 pub(crate) use error::MartialArtsError;
 
+pub use form::{ActivateMartialArtsForm, EndMartialArtsForm};
 pub use martial_artist::MartialArtsStyle;
 
-use crate::exaltation::Exaltation;
+use crate::{
+    charms::charm::Charm,
+    exaltation::{exalt::exalt_type::ExaltType, Exaltation},
+};
+
+use self::charm::{MartialArtsCharm, MartialArtsCharmBonus};
 
 /// All of the character's Martial Arts styles.
-pub struct MartialArts<'view, 'source>(pub(crate) &'view Exaltation<'source>);
+pub struct MartialArts<'view, 'source>(
+    pub(crate) &'view Exaltation<'source>,
+    pub(crate) Option<&'view str>,
+);
 
 impl<'view, 'source> MartialArts<'view, 'source> {
     /// The details of a particular Martial Arts style.
     pub fn style(&self, name: &str) -> Option<MartialArtsStyle<'view, 'source>> {
-        self.0.martial_artist(name)
+        let mut style = self.0.martial_artist(name)?;
+        style.active_form = self
+            .active_form()
+            .filter(|form| form.style() == style.name)
+            .map(|form| form.name());
+        Some(style)
     }
 
     /// Iterates over the Ids for all Martial Arts the character knows.
     pub fn iter(&self) -> impl Iterator<Item = &'source str> {
         self.0.martial_arts_id_iter()
     }
+
+    /// The character's active Form Charm, if any.
+    pub fn active_form(&self) -> Option<MartialArtsCharm<'source>> {
+        match self.0.get_martial_arts_charm(self.1?)? {
+            Charm::MartialArts(charm) => Some(charm),
+            _ => None,
+        }
+    }
+
+    /// The Mastery effect or Terrestrial limitation of a Martial Arts Charm
+    /// which applies to this character. Solars benefit from Mastery effects;
+    /// other characters are bound by Terrestrial limitations.
+    pub fn charm_bonus(&self, charm_name: &str) -> Option<MartialArtsCharmBonus<'source>> {
+        let charm = match self.0.get_martial_arts_charm(charm_name)? {
+            Charm::MartialArts(charm) => charm,
+            _ => return None,
+        };
+        match self.0 {
+            Exaltation::Exalt(exalt) => match exalt.exalt_type() {
+                ExaltType::Solar(_) => charm.mastery().map(MartialArtsCharmBonus::Mastery),
+            },
+            Exaltation::Mortal(_) => charm.terrestrial().map(MartialArtsCharmBonus::Terrestrial),
+        }
+    }
 }
@@ -25,7 +25,7 @@ pub(crate) struct MartialArtsStyleDetails {
 impl<'source> MartialArtsStyleDetails {
     /// The page reference for the style (if any).
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.book_reference
+        self.book_reference.clone()
     }
 
     /// The style's description.
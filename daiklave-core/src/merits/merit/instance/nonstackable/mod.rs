@@ -22,7 +22,7 @@ impl<'source> NonStackableMerit<'source> {
     }
 
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.instance.0.book_reference
+        self.instance.0.book_reference.clone()
     }
 
     pub fn dots(&self) -> u8 {
@@ -26,7 +26,7 @@ impl<'source> SorceryArchetypeMerit<'source> {
     }
 
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.details.book_reference
+        self.details.book_reference.clone()
     }
 
     pub fn dots(&self) -> u8 {
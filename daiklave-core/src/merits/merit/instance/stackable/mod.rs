@@ -28,7 +28,7 @@ impl<'source> StackableMerit<'source> {
     }
 
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.instance.0.book_reference
+        self.instance.0.book_reference.clone()
     }
 
     pub fn dots(&self) -> u8 {
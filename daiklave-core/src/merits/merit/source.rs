@@ -127,53 +127,36 @@ impl<'source> MeritSource<'source> {
 
     pub fn book_reference(&self) -> Option<BookReference> {
         match self {
-            MeritSource::Artifact { name: _, dots: _ } => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 159,
-            }),
+            MeritSource::Artifact { name: _, dots: _ } => {
+                Some(BookReference::published(Book::CoreRulebook, 159))
+            }
             MeritSource::Demense {
                 name: _,
                 has_manse: _,
                 geomancy_level: _,
-            } => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 160,
-            }),
+            } => Some(BookReference::published(Book::CoreRulebook, 160)),
             MeritSource::Hearthstone {
                 name: _,
                 has_manse: _,
                 geomancy_level: _,
-            } => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 161,
-            }),
-            MeritSource::ExaltedHealing { is_exalt: _ } => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 165,
-            }),
-            MeritSource::LocalTongues { count: _ } => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 162,
-            }),
-            MeritSource::MajorLanguage(_) => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 162,
-            }),
+            } => Some(BookReference::published(Book::CoreRulebook, 161)),
+            MeritSource::ExaltedHealing { is_exalt: _ } => {
+                Some(BookReference::published(Book::CoreRulebook, 165))
+            }
+            MeritSource::LocalTongues { count: _ } => {
+                Some(BookReference::published(Book::CoreRulebook, 162))
+            }
+            MeritSource::MajorLanguage(_) => {
+                Some(BookReference::published(Book::CoreRulebook, 162))
+            }
             MeritSource::Manse {
                 name: _,
                 geomancy_level: _,
-            } => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 163,
-            }),
-            MeritSource::MartialArtist { style_name: _ } => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 163,
-            }),
-            MeritSource::MortalSorcerer => Some(BookReference {
-                book: Book::CoreRulebook,
-                page_number: 470,
-            }),
+            } => Some(BookReference::published(Book::CoreRulebook, 163)),
+            MeritSource::MartialArtist { style_name: _ } => {
+                Some(BookReference::published(Book::CoreRulebook, 163))
+            }
+            MeritSource::MortalSorcerer => Some(BookReference::published(Book::CoreRulebook, 470)),
             MeritSource::NonStackable(nonstackable) => nonstackable.book_reference(),
             MeritSource::SorceryArchetype(sorcery_archetype_merit) => {
                 sorcery_archetype_merit.book_reference()
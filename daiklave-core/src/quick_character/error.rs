@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+/// An error related to Quick Characters.
+#[derive(Debug, Error)]
+pub enum QuickCharacterError {
+    /// The named action pool does not exist.
+    #[error("Action pool not found")]
+    ActionPoolNotFound,
+    /// The named attack does not exist.
+    #[error("Attack not found")]
+    AttackNotFound,
+    /// The named Charm does not exist.
+    #[error("Charm not found")]
+    CharmNotFound,
+    /// Charm names must be unique.
+    #[error("Quick Characters cannot have two Charms with the same name")]
+    DuplicateCharm,
+}
@@ -0,0 +1,96 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use crate::exaltation::exalt::essence::{EssenceError, MotePoolName};
+
+/// The Essence rating and mote pools of an Essence-using Quick Character.
+/// Unlike player characters, mote pool sizes are taken directly from the
+/// statblock rather than calculated from Essence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuickEssence {
+    pub(crate) rating: NonZeroU8,
+    pub(crate) personal_available: u8,
+    pub(crate) personal_max: u8,
+    pub(crate) peripheral_available: u8,
+    pub(crate) peripheral_max: u8,
+}
+
+impl QuickEssence {
+    /// The Quick Character's Essence rating.
+    pub fn rating(&self) -> NonZeroU8 {
+        self.rating
+    }
+
+    /// The currently available motes in the specified pool.
+    pub fn available(&self, pool: MotePoolName) -> u8 {
+        match pool {
+            MotePoolName::Peripheral => self.peripheral_available,
+            MotePoolName::Personal => self.personal_available,
+        }
+    }
+
+    /// The maximum size of the specified pool.
+    pub fn max(&self, pool: MotePoolName) -> u8 {
+        match pool {
+            MotePoolName::Peripheral => self.peripheral_max,
+            MotePoolName::Personal => self.personal_max,
+        }
+    }
+
+    pub(crate) fn spend(&mut self, first: MotePoolName, amount: u8) -> Result<(), EssenceError> {
+        if self
+            .peripheral_available
+            .saturating_add(self.personal_available)
+            < amount
+        {
+            return Err(EssenceError::InsufficientMotes);
+        }
+
+        let (first_pool, second_pool) = match first {
+            MotePoolName::Peripheral => {
+                (&mut self.peripheral_available, &mut self.personal_available)
+            }
+            MotePoolName::Personal => {
+                (&mut self.personal_available, &mut self.peripheral_available)
+            }
+        };
+
+        let from_first = (*first_pool).min(amount);
+        *first_pool -= from_first;
+        *second_pool -= amount - from_first;
+        Ok(())
+    }
+
+    pub(crate) fn recover(&mut self, amount: u8) {
+        let to_peripheral = self
+            .peripheral_max
+            .saturating_sub(self.peripheral_available)
+            .min(amount);
+        self.peripheral_available += to_peripheral;
+        self.personal_available = self
+            .personal_available
+            .saturating_add(amount - to_peripheral)
+            .min(self.personal_max);
+    }
+}
+
+/// A mutation to set a Quick Character's Essence rating and mote pools. Both
+/// pools start full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetQuickEssence(pub(crate) QuickEssence);
+
+impl SetQuickEssence {
+    /// Creates a new mutation with the given Essence rating and pool sizes.
+    /// Unlike player characters, Quick Characters are not limited to Essence
+    /// 5; ancient gods and behemoths may be rated higher.
+    pub fn new(rating: NonZeroU8, personal: u8, peripheral: u8) -> Self {
+        Self(QuickEssence {
+            rating,
+            personal_available: personal,
+            personal_max: personal,
+            peripheral_available: peripheral,
+            peripheral_max: peripheral,
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{health::Health, willpower::Willpower, CharacterMutationError};
+
+use super::{essence::QuickEssence, QuickCharacter, QuickCharacterMutation, QuickStats};
+
+/// An owned instance of a Quick Character. This is the format used in
+/// serialization and deserialization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuickCharacterMemo {
+    /// The name of the Quick Character.
+    pub name: String,
+    pub(crate) action_pools: HashMap<String, u8>,
+    pub(crate) attacks: HashMap<String, (u8, u8)>,
+    pub(crate) stats: QuickStats,
+    pub(crate) health: Health,
+    pub(crate) willpower: Willpower,
+    pub(crate) essence: Option<QuickEssence>,
+    pub(crate) charms: HashMap<String, String>,
+}
+
+impl Default for QuickCharacterMemo {
+    fn default() -> Self {
+        Self {
+            name: "New Quick Character".to_owned(),
+            action_pools: Default::default(),
+            attacks: Default::default(),
+            stats: Default::default(),
+            health: Default::default(),
+            willpower: Default::default(),
+            essence: None,
+            charms: Default::default(),
+        }
+    }
+}
+
+impl From<QuickCharacter<'_>> for QuickCharacterMemo {
+    fn from(quick_character: QuickCharacter<'_>) -> Self {
+        Self {
+            name: quick_character.name.to_owned(),
+            action_pools: quick_character
+                .action_pools
+                .into_iter()
+                .map(|(name, dice)| (name.to_owned(), dice))
+                .collect(),
+            attacks: quick_character
+                .attacks
+                .into_iter()
+                .map(|(name, attack)| (name.to_owned(), attack))
+                .collect(),
+            stats: quick_character.stats,
+            health: quick_character.health,
+            willpower: quick_character.willpower,
+            essence: quick_character.essence,
+            charms: quick_character
+                .charms
+                .into_iter()
+                .map(|(name, summary)| (name.to_owned(), summary.to_owned()))
+                .collect(),
+        }
+    }
+}
+
+impl QuickCharacterMemo {
+    /// Applies a specific QuickCharacterMutation or returns an error. Note
+    /// that this operation is applied immutably, returning a cloned and
+    /// updated version of the Quick Character.
+    pub fn apply_mutation(
+        &self,
+        mutation: &QuickCharacterMutation,
+    ) -> Result<QuickCharacterMemo, CharacterMutationError> {
+        let mut view: QuickCharacter = self.into();
+        view.apply_mutation(mutation)?;
+        Ok(view.into())
+    }
+}
@@ -0,0 +1,329 @@
+mod error;
+mod essence;
+mod memo;
+mod mutation;
+mod stat;
+
+pub use error::QuickCharacterError;
+pub use essence::{QuickEssence, SetQuickEssence};
+pub use memo::QuickCharacterMemo;
+pub use mutation::QuickCharacterMutation;
+pub use stat::{QuickStatName, QuickStats};
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    num::NonZeroU8,
+};
+
+use crate::{
+    battle_group::BattleGroupStatblock,
+    exaltation::exalt::essence::{EssenceError, MotePoolName, RecoverMotes, SpendMotes},
+    health::{DamageLevel, HealDamage, Health, SetHealthTrack, TakeDamage, WoundPenalty},
+    willpower::{GainWillpower, SetWillpowerRating, SpendWillpower, Willpower},
+    CharacterMutationError,
+};
+
+/// A borrowed instance of a Quick Character (QC), the simplified statblock
+/// format used for NPCs and antagonists. Instead of Attributes and Abilities,
+/// a QC has named action pools and flat defenses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickCharacter<'source> {
+    pub(crate) name: &'source str,
+    pub(crate) action_pools: HashMap<&'source str, u8>,
+    pub(crate) attacks: HashMap<&'source str, (u8, u8)>,
+    pub(crate) stats: QuickStats,
+    pub(crate) health: Health,
+    pub(crate) willpower: Willpower,
+    pub(crate) essence: Option<QuickEssence>,
+    pub(crate) charms: HashMap<&'source str, &'source str>,
+}
+
+impl<'source> From<&'source QuickCharacterMemo> for QuickCharacter<'source> {
+    fn from(memo: &'source QuickCharacterMemo) -> Self {
+        Self {
+            name: memo.name.as_str(),
+            action_pools: memo
+                .action_pools
+                .iter()
+                .map(|(name, dice)| (name.as_str(), *dice))
+                .collect(),
+            attacks: memo
+                .attacks
+                .iter()
+                .map(|(name, attack)| (name.as_str(), *attack))
+                .collect(),
+            stats: memo.stats,
+            health: memo.health,
+            willpower: memo.willpower,
+            essence: memo.essence,
+            charms: memo
+                .charms
+                .iter()
+                .map(|(name, summary)| (name.as_str(), summary.as_str()))
+                .collect(),
+        }
+    }
+}
+
+impl<'source> QuickCharacter<'source> {
+    /// Applies a mutation to the Quick Character.
+    pub fn apply_mutation(
+        &mut self,
+        mutation: &'source QuickCharacterMutation,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        match mutation {
+            QuickCharacterMutation::SetName(name) => {
+                self.name = name.as_str();
+                Ok(self)
+            }
+            QuickCharacterMutation::SetActionPool(name, dice) => {
+                self.action_pools.insert(name.as_str(), *dice);
+                Ok(self)
+            }
+            QuickCharacterMutation::RemoveActionPool(name) => {
+                self.action_pools
+                    .remove(name.as_str())
+                    .ok_or(QuickCharacterError::ActionPoolNotFound)?;
+                Ok(self)
+            }
+            QuickCharacterMutation::SetAttack {
+                name,
+                accuracy,
+                damage,
+            } => {
+                self.attacks.insert(name.as_str(), (*accuracy, *damage));
+                Ok(self)
+            }
+            QuickCharacterMutation::RemoveAttack(name) => {
+                self.attacks
+                    .remove(name.as_str())
+                    .ok_or(QuickCharacterError::AttackNotFound)?;
+                Ok(self)
+            }
+            QuickCharacterMutation::SetStat(stat, value) => {
+                self.stats.set(*stat, *value);
+                Ok(self)
+            }
+            QuickCharacterMutation::SetHealthTrack(SetHealthTrack(wound_penalties)) => {
+                self.set_health_track(wound_penalties)
+            }
+            QuickCharacterMutation::TakeDamage(TakeDamage { level, amount }) => {
+                self.take_damage(*level, amount.get())
+            }
+            QuickCharacterMutation::HealDamage(HealDamage(amount)) => {
+                self.health.heal_damage(amount.get())?;
+                Ok(self)
+            }
+            QuickCharacterMutation::GainWillpower(GainWillpower(amount)) => {
+                self.willpower.current = self.willpower.current.saturating_add(amount.get());
+                Ok(self)
+            }
+            QuickCharacterMutation::SpendWillpower(SpendWillpower(amount)) => {
+                self.willpower.current -= self.willpower.current.min(amount.get());
+                Ok(self)
+            }
+            QuickCharacterMutation::SetWillpowerRating(SetWillpowerRating(dots)) => {
+                self.willpower.rating = *dots;
+                self.willpower.current = dots.get();
+                Ok(self)
+            }
+            QuickCharacterMutation::SetEssence(SetQuickEssence(essence)) => {
+                self.essence = Some(*essence);
+                Ok(self)
+            }
+            QuickCharacterMutation::RemoveEssence => {
+                self.essence = None;
+                Ok(self)
+            }
+            QuickCharacterMutation::SpendMotes(SpendMotes { first, amount }) => {
+                self.spend_motes(*first, amount.get())
+            }
+            QuickCharacterMutation::RecoverMotes(RecoverMotes(amount)) => {
+                self.recover_motes(*amount)
+            }
+            QuickCharacterMutation::AddCharm { name, summary } => {
+                if let Entry::Vacant(e) = self.charms.entry(name.as_str()) {
+                    e.insert(summary.as_str());
+                    Ok(self)
+                } else {
+                    Err(QuickCharacterError::DuplicateCharm.into())
+                }
+            }
+            QuickCharacterMutation::RemoveCharm(name) => {
+                self.charms
+                    .remove(name.as_str())
+                    .ok_or(QuickCharacterError::CharmNotFound)?;
+                Ok(self)
+            }
+        }
+    }
+
+    /// The Quick Character's name.
+    pub fn name(&self) -> &'source str {
+        self.name
+    }
+
+    /// Gets a named action pool, like "Read Intentions".
+    pub fn action_pool(&self, name: &str) -> Option<u8> {
+        self.action_pools.get(name).copied()
+    }
+
+    /// Iterates over all named action pools.
+    pub fn action_pools(&self) -> impl Iterator<Item = (&'source str, u8)> + '_ {
+        self.action_pools.iter().map(|(name, dice)| (*name, *dice))
+    }
+
+    /// Gets a named attack's accuracy and damage.
+    pub fn attack(&self, name: &str) -> Option<(u8, u8)> {
+        self.attacks.get(name).copied()
+    }
+
+    /// Iterates over all named attacks with their accuracy and damage.
+    pub fn attacks(&self) -> impl Iterator<Item = (&'source str, u8, u8)> + '_ {
+        self.attacks
+            .iter()
+            .map(|(name, (accuracy, damage))| (*name, *accuracy, *damage))
+    }
+
+    /// The Quick Character's flat Parry, Evasion, Soak, Hardness, Resolve,
+    /// and Guile.
+    pub fn stats(&self) -> &QuickStats {
+        &self.stats
+    }
+
+    /// The Quick Character's health track (read-only).
+    pub fn health(&self) -> &Health {
+        &self.health
+    }
+
+    /// The Quick Character's current willpower and permanent rating.
+    pub fn willpower(&self) -> &Willpower {
+        &self.willpower
+    }
+
+    /// The Quick Character's Essence rating and motes, or None if they are
+    /// mortal.
+    pub fn essence(&self) -> Option<&QuickEssence> {
+        self.essence.as_ref()
+    }
+
+    /// Gets the summary of a Charm by name.
+    pub fn charm(&self, name: &str) -> Option<&'source str> {
+        self.charms.get(name).copied()
+    }
+
+    /// Iterates over all Charms by name and summary.
+    pub fn charms(&self) -> impl Iterator<Item = (&'source str, &'source str)> + '_ {
+        self.charms.iter().map(|(name, summary)| (*name, *summary))
+    }
+
+    /// Sets the Quick Character to have the specified number of each type of
+    /// health box, preserving existing damage if possible.
+    pub fn set_health_track(
+        &mut self,
+        new_wound_penalties: &HashMap<WoundPenalty, u8>,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let (bashing, lethal, aggravated) = (
+            self.health.bashing_damage,
+            self.health.lethal_damage,
+            self.health.aggravated_damage,
+        );
+
+        self.health = Health {
+            zero_boxes: new_wound_penalties
+                .get(&WoundPenalty::Zero)
+                .cloned()
+                .unwrap_or_default(),
+            minus_one_boxes: new_wound_penalties
+                .get(&WoundPenalty::MinusOne)
+                .cloned()
+                .unwrap_or_default(),
+            minus_two_boxes: new_wound_penalties
+                .get(&WoundPenalty::MinusTwo)
+                .cloned()
+                .unwrap_or_default(),
+            minus_four_boxes: new_wound_penalties
+                .get(&WoundPenalty::MinusFour)
+                .cloned()
+                .unwrap_or_default(),
+            incapacitated_boxes: new_wound_penalties
+                .get(&WoundPenalty::Incapacitated)
+                .cloned()
+                .unwrap_or_default(),
+            bashing_damage: 0,
+            lethal_damage: 0,
+            aggravated_damage: 0,
+            healing_progress: 0,
+        };
+
+        self.take_damage(DamageLevel::Bashing, bashing)?
+            .take_damage(DamageLevel::Lethal, lethal)?
+            .take_damage(DamageLevel::Aggravated, aggravated)
+    }
+
+    /// Adds damage to the Quick Character (including overflow rollovers).
+    pub fn take_damage(
+        &mut self,
+        damage_level: DamageLevel,
+        amount: u8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.health.take_damage(damage_level, amount)?;
+        Ok(self)
+    }
+
+    /// Spends motes, starting with the specified pool and overflowing to the
+    /// other.
+    pub fn spend_motes(
+        &mut self,
+        first: MotePoolName,
+        amount: u8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.essence
+            .as_mut()
+            .ok_or(EssenceError::Mortal)?
+            .spend(first, amount)?;
+        Ok(self)
+    }
+
+    /// Recovers spent motes, peripheral first.
+    pub fn recover_motes(
+        &mut self,
+        amount: NonZeroU8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.essence
+            .as_mut()
+            .ok_or(EssenceError::Mortal)?
+            .recover(amount.get());
+        Ok(self)
+    }
+}
+
+impl From<&QuickCharacter<'_>> for BattleGroupStatblock {
+    fn from(quick_character: &QuickCharacter<'_>) -> Self {
+        let statblock = quick_character.action_pools.iter().fold(
+            BattleGroupStatblock::name(quick_character.name),
+            |statblock, (name, dice)| statblock.action_pool(*name, *dice),
+        );
+        let statblock = quick_character
+            .attacks
+            .iter()
+            .fold(statblock, |statblock, (name, (accuracy, damage))| {
+                statblock.attack(*name, *accuracy, *damage)
+            });
+
+        statblock
+            .parry(quick_character.stats.parry)
+            .evasion(quick_character.stats.evasion)
+            .soak(quick_character.stats.soak)
+            .hardness(quick_character.stats.hardness)
+            .resolve(quick_character.stats.resolve)
+            .guile(quick_character.stats.guile)
+            .willpower(quick_character.willpower.rating.get())
+            .health(Health {
+                bashing_damage: 0,
+                lethal_damage: 0,
+                aggravated_damage: 0,
+                ..quick_character.health
+            })
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    exaltation::exalt::essence::{RecoverMotes, SpendMotes},
+    health::{HealDamage, SetHealthTrack, TakeDamage},
+    willpower::{GainWillpower, SetWillpowerRating, SpendWillpower},
+};
+
+use super::{essence::SetQuickEssence, QuickStatName};
+
+/// The ways a Quick Character can be atomically updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuickCharacterMutation {
+    /// Sets the Quick Character's name.
+    SetName(String),
+    /// Adds or replaces a named action pool, like "Read Intentions 6".
+    SetActionPool(String, u8),
+    /// Removes a named action pool.
+    RemoveActionPool(String),
+    /// Adds or replaces a named attack with its accuracy and damage.
+    SetAttack {
+        /// The name of the attack, like "Sword" or "Unarmed".
+        name: String,
+        /// The number of dice rolled to hit.
+        accuracy: u8,
+        /// The raw damage of a withering attack.
+        damage: u8,
+    },
+    /// Removes a named attack.
+    RemoveAttack(String),
+    /// Sets a flat statistic, like Parry or Resolve.
+    SetStat(QuickStatName, u8),
+    /// Sets the Quick Character's health track.
+    SetHealthTrack(SetHealthTrack),
+    /// Deals damage to the Quick Character.
+    TakeDamage(TakeDamage),
+    /// Heals damage from the Quick Character.
+    HealDamage(HealDamage),
+    /// Increases current Willpower.
+    GainWillpower(GainWillpower),
+    /// Spends Willpower.
+    SpendWillpower(SpendWillpower),
+    /// Sets the permanent Willpower rating, and resets current Willpower.
+    SetWillpowerRating(SetWillpowerRating),
+    /// Sets the Quick Character's Essence and mote pools.
+    SetEssence(SetQuickEssence),
+    /// Removes the Quick Character's Essence, making them a mortal.
+    RemoveEssence,
+    /// Spends motes.
+    SpendMotes(SpendMotes),
+    /// Recovers spent motes.
+    RecoverMotes(RecoverMotes),
+    /// Adds a Charm by name, with a short summary of its effect.
+    AddCharm {
+        /// The name of the Charm.
+        name: String,
+        /// A summary of what the Charm does.
+        summary: String,
+    },
+    /// Removes a Charm by name.
+    RemoveCharm(String),
+}
+
+impl From<SetHealthTrack> for QuickCharacterMutation {
+    fn from(set_health_track: SetHealthTrack) -> Self {
+        Self::SetHealthTrack(set_health_track)
+    }
+}
+
+impl From<TakeDamage> for QuickCharacterMutation {
+    fn from(take_damage: TakeDamage) -> Self {
+        Self::TakeDamage(take_damage)
+    }
+}
+
+impl From<HealDamage> for QuickCharacterMutation {
+    fn from(heal_damage: HealDamage) -> Self {
+        Self::HealDamage(heal_damage)
+    }
+}
+
+impl From<GainWillpower> for QuickCharacterMutation {
+    fn from(gain_willpower: GainWillpower) -> Self {
+        Self::GainWillpower(gain_willpower)
+    }
+}
+
+impl From<SpendWillpower> for QuickCharacterMutation {
+    fn from(spend_willpower: SpendWillpower) -> Self {
+        Self::SpendWillpower(spend_willpower)
+    }
+}
+
+impl From<SetWillpowerRating> for QuickCharacterMutation {
+    fn from(set_willpower_rating: SetWillpowerRating) -> Self {
+        Self::SetWillpowerRating(set_willpower_rating)
+    }
+}
+
+impl From<SetQuickEssence> for QuickCharacterMutation {
+    fn from(set_quick_essence: SetQuickEssence) -> Self {
+        Self::SetEssence(set_quick_essence)
+    }
+}
+
+impl From<SpendMotes> for QuickCharacterMutation {
+    fn from(spend_motes: SpendMotes) -> Self {
+        Self::SpendMotes(spend_motes)
+    }
+}
+
+impl From<RecoverMotes> for QuickCharacterMutation {
+    fn from(recover_motes: RecoverMotes) -> Self {
+        Self::RecoverMotes(recover_motes)
+    }
+}
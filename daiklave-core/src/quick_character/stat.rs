@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// The flat statistics of a Quick Character which are not derived from any
+/// Attribute or Ability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum QuickStatName {
+    /// Parry defense.
+    Parry,
+    /// Evasion defense.
+    Evasion,
+    /// Total soak, including armor.
+    Soak,
+    /// Hardness, including armor.
+    Hardness,
+    /// Resolve, the social defense against persuasion.
+    Resolve,
+    /// Guile, the social defense against reading intentions.
+    Guile,
+}
+
+/// The flat defensive statistics of a Quick Character.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuickStats {
+    pub(crate) parry: u8,
+    pub(crate) evasion: u8,
+    pub(crate) soak: u8,
+    pub(crate) hardness: u8,
+    pub(crate) resolve: u8,
+    pub(crate) guile: u8,
+}
+
+impl QuickStats {
+    /// Gets the value of a specific statistic.
+    pub fn get(&self, stat: QuickStatName) -> u8 {
+        match stat {
+            QuickStatName::Parry => self.parry,
+            QuickStatName::Evasion => self.evasion,
+            QuickStatName::Soak => self.soak,
+            QuickStatName::Hardness => self.hardness,
+            QuickStatName::Resolve => self.resolve,
+            QuickStatName::Guile => self.guile,
+        }
+    }
+
+    pub(crate) fn set(&mut self, stat: QuickStatName, value: u8) {
+        match stat {
+            QuickStatName::Parry => self.parry = value,
+            QuickStatName::Evasion => self.evasion = value,
+            QuickStatName::Soak => self.soak = value,
+            QuickStatName::Hardness => self.hardness = value,
+            QuickStatName::Resolve => self.resolve = value,
+            QuickStatName::Guile => self.guile = value,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// The kinds of social actions one character can take to influence, or read,
+/// another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SocialAction {
+    /// Instills a new Intimacy, or strengthens or weakens an existing one.
+    Instill,
+    /// Convinces the target to take some action.
+    Persuade,
+    /// Convinces the target to take some action in exchange for a bribe or
+    /// favor.
+    Bargain,
+    /// Stirs up an emotion in the target, which they may act on.
+    Inspire,
+    /// Convinces the target to take some action under threat of harm.
+    Threaten,
+    /// Discerns the target's feelings or motives. Rolled against Guile
+    /// instead of Resolve; Intimacies do not apply.
+    ReadIntentions,
+}
+
+impl SocialAction {
+    /// True if the action is opposed by the target's Guile rather than
+    /// their Resolve.
+    pub fn against_guile(&self) -> bool {
+        matches!(self, SocialAction::ReadIntentions)
+    }
+}
@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+/// An error occurring while resolving social influence.
+#[derive(Debug, Error)]
+pub enum SocialError {
+    /// The target doesn't hold a cited Intimacy
+    #[error("Target does not have that Intimacy")]
+    IntimacyNotFound,
+    /// A Decision Point requires successful influence opposed by a Major or
+    /// Defining Intimacy
+    #[error("Cannot resist influence with that Intimacy")]
+    NoDecisionPoint,
+    /// A Decision Point requires spending Willpower
+    #[error("Not enough Willpower to resist")]
+    InsufficientWillpower,
+}
@@ -0,0 +1,107 @@
+use crate::{
+    intimacies::intimacy::{IntimacyLevel, IntimacyType, IntimacyTypeMemo},
+    Character,
+};
+
+use super::{InfluenceOutcome, SocialAction, SocialError};
+
+/// A social action by one character against another, with the Intimacies of
+/// the target which the actor's player argues support or oppose it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SocialInfluence {
+    pub(crate) action: SocialAction,
+    pub(crate) successes: u8,
+    pub(crate) supporting: Vec<IntimacyTypeMemo>,
+    pub(crate) opposing: Vec<IntimacyTypeMemo>,
+}
+
+impl SocialInfluence {
+    /// Sets up a social action with the successes the actor rolled.
+    pub fn new(action: SocialAction, successes: u8) -> Self {
+        Self {
+            action,
+            successes,
+            supporting: Vec::new(),
+            opposing: Vec::new(),
+        }
+    }
+
+    /// Cites one of the target's Intimacies as supporting the influence,
+    /// lowering their Resolve.
+    pub fn supported_by(mut self, intimacy: IntimacyType<'_>) -> Self {
+        self.supporting.push(intimacy.into());
+        self
+    }
+
+    /// Cites one of the target's Intimacies as opposing the influence,
+    /// raising their Resolve.
+    pub fn opposed_by(mut self, intimacy: IntimacyType<'_>) -> Self {
+        self.opposing.push(intimacy.into());
+        self
+    }
+
+    /// Resolves the influence against the target. Only the strongest
+    /// supporting and strongest opposing Intimacy apply: a supporting
+    /// Intimacy lowers Resolve by 1, 2, or 3 for Minor, Major or Defining,
+    /// and an opposing Intimacy raises it by 2, 3, or 4. The influence
+    /// succeeds if the actor's successes meet or exceed the modified Resolve
+    /// (or the target's Guile, for reading intentions).
+    pub fn resolve(&self, target: &Character<'_>) -> Result<InfluenceOutcome, SocialError> {
+        let level_of = |cited: &IntimacyTypeMemo| {
+            target
+                .intimacies()
+                .iter()
+                .find(|intimacy| intimacy.intimacy_type == cited)
+                .map(|intimacy| intimacy.level())
+                .ok_or(SocialError::IntimacyNotFound)
+        };
+        let strongest = |cited: &[IntimacyTypeMemo]| {
+            cited
+                .iter()
+                .map(level_of)
+                .collect::<Result<Vec<IntimacyLevel>, SocialError>>()
+                .map(|levels| levels.into_iter().max())
+        };
+
+        if self.action.against_guile() {
+            let guile = target.guile();
+            return Ok(InfluenceOutcome {
+                action: self.action,
+                successes: self.successes,
+                base: guile,
+                modified: guile,
+                succeeded: self.successes >= guile,
+                decision_points: Vec::new(),
+            });
+        }
+
+        let supporting = strongest(&self.supporting)?;
+        let opposing = strongest(&self.opposing)?;
+        let base = target.resolve();
+        let modified = base
+            .saturating_sub(supporting.map_or(0, |level| u8::from(level) - 1))
+            .saturating_add(opposing.map_or(0, u8::from));
+        let succeeded = self.successes >= modified;
+
+        // The target may resist successful influence by citing any opposing
+        // Major or Defining Intimacy
+        let decision_points = if succeeded {
+            self.opposing
+                .iter()
+                .filter(|cited| level_of(cited).is_ok_and(|level| level >= IntimacyLevel::Major))
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(InfluenceOutcome {
+            action: self.action,
+            successes: self.successes,
+            base,
+            modified,
+            succeeded,
+            decision_points,
+        })
+    }
+}
@@ -0,0 +1,8 @@
+mod action;
+mod error;
+mod influence;
+mod outcome;
+pub use action::SocialAction;
+pub use error::SocialError;
+pub use influence::SocialInfluence;
+pub use outcome::InfluenceOutcome;
@@ -0,0 +1,91 @@
+use std::num::NonZeroU8;
+
+use crate::{
+    intimacies::intimacy::{IntimacyLevel, IntimacyType, IntimacyTypeMemo},
+    mutations::{GainLimit, SpendWillpower},
+    Character, CharacterMutation,
+};
+
+use super::{SocialAction, SocialError};
+
+/// The result of resolving a social action against a target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfluenceOutcome {
+    pub(crate) action: SocialAction,
+    pub(crate) successes: u8,
+    pub(crate) base: u8,
+    pub(crate) modified: u8,
+    pub(crate) succeeded: bool,
+    pub(crate) decision_points: Vec<IntimacyTypeMemo>,
+}
+
+impl InfluenceOutcome {
+    /// The social action taken.
+    pub fn action(&self) -> SocialAction {
+        self.action
+    }
+
+    /// The successes the actor rolled.
+    pub fn successes(&self) -> u8 {
+        self.successes
+    }
+
+    /// The target's unmodified Resolve (or Guile, for reading intentions).
+    pub fn base_difficulty(&self) -> u8 {
+        self.base
+    }
+
+    /// The target's Resolve after applying Intimacies.
+    pub fn difficulty(&self) -> u8 {
+        self.modified
+    }
+
+    /// True if the influence succeeds (before any Decision Point).
+    pub fn succeeded(&self) -> bool {
+        self.succeeded
+    }
+
+    /// The opposing Intimacies the target may cite at a Decision Point to
+    /// resist the influence.
+    pub fn decision_points(&self) -> impl Iterator<Item = IntimacyType<'_>> + '_ {
+        self.decision_points.iter().map(|memo| memo.into())
+    }
+
+    /// The target resists the influence at a Decision Point, citing an
+    /// opposing Major or Defining Intimacy. Returns the Willpower the target
+    /// must spend to do so, to be applied to them.
+    pub fn resist(
+        &self,
+        target: &Character<'_>,
+        cited: IntimacyType<'_>,
+    ) -> Result<SpendWillpower, SocialError> {
+        let cited: IntimacyTypeMemo = cited.into();
+        if !self.decision_points.contains(&cited) {
+            return Err(SocialError::NoDecisionPoint);
+        }
+        if target.willpower().current() == 0 {
+            return Err(SocialError::InsufficientWillpower);
+        }
+        Ok(SpendWillpower(NonZeroU8::new(1).unwrap()))
+    }
+
+    /// As [InfluenceOutcome::resist], but returns every mutation to be
+    /// applied to the target: the Willpower spent and, if they have Limit and
+    /// the influence would have violated a Defining Intimacy, a point of
+    /// Limit.
+    pub fn resist_mutations(
+        &self,
+        target: &Character<'_>,
+        cited: IntimacyType<'_>,
+    ) -> Result<Vec<CharacterMutation>, SocialError> {
+        let mut mutations = vec![self.resist(target, cited)?.into()];
+        let cited: IntimacyTypeMemo = cited.into();
+        let defining = target.intimacies().iter().any(|intimacy| {
+            intimacy.intimacy_type == &cited && intimacy.level() == IntimacyLevel::Defining
+        });
+        if defining && target.limit().is_some() {
+            mutations.push(GainLimit(NonZeroU8::new(1).unwrap()).into());
+        }
+        Ok(mutations)
+    }
+}
@@ -32,7 +32,7 @@ impl<'view, 'source> SorceryArchetype<'view, 'source> {
 
     /// The book reference for the archetype, if any.
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.archetype.book_reference
+        self.archetype.book_reference.clone()
     }
 
     /// A description of the archetype.
@@ -24,6 +24,7 @@ impl SorceryArchetypeName {
             ritual: ShapingRitualDetails {
                 book_reference,
                 description: description.into(),
+                bonus_motes: 0,
             },
         }
     }
@@ -8,3 +8,12 @@ pub struct AddShapingRitual {
     pub(crate) summary: String,
     pub(crate) ritual: ShapingRitualDetails,
 }
+
+impl AddShapingRitual {
+    /// Sets the number of bonus sorcerous motes the ritual grants when its
+    /// conditions are met while shaping a spell.
+    pub fn bonus_motes(mut self, bonus_motes: u8) -> Self {
+        self.ritual.bonus_motes = bonus_motes;
+        self
+    }
+}
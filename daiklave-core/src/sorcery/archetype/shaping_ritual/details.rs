@@ -6,4 +6,6 @@ use crate::book_reference::BookReference;
 pub(crate) struct ShapingRitualDetails {
     pub book_reference: Option<BookReference>,
     pub description: String,
+    #[serde(default)]
+    pub bonus_motes: u8,
 }
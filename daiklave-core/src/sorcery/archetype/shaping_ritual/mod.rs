@@ -26,11 +26,17 @@ impl<'source> ShapingRitual<'source> {
 
     /// A page reference for the ritual, if any.
     pub fn book_reference(&self) -> Option<BookReference> {
-        self.details.book_reference
+        self.details.book_reference.clone()
     }
 
     /// A description of the ritual.
     pub fn description(&self) -> &'source str {
         &self.details.description
     }
+
+    /// The number of bonus sorcerous motes the ritual grants when its
+    /// conditions are met while shaping a spell.
+    pub fn bonus_motes(&self) -> u8 {
+        self.details.bonus_motes
+    }
 }
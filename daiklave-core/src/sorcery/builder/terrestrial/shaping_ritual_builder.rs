@@ -12,6 +12,7 @@ pub struct TerrestrialShapingRitualBuilder {
     pub(crate) archetype: SorceryArchetypeDetails,
     pub(crate) summary: String,
     pub(crate) book_reference: Option<BookReference>,
+    pub(crate) bonus_motes: u8,
 }
 
 impl TerrestrialShapingRitualBuilder {
@@ -21,6 +22,13 @@ impl TerrestrialShapingRitualBuilder {
         self
     }
 
+    /// Sets the number of bonus sorcerous motes the ritual grants when its
+    /// conditions are met while shaping a spell.
+    pub fn bonus_motes(mut self, bonus_motes: u8) -> Self {
+        self.bonus_motes = bonus_motes;
+        self
+    }
+
     /// Sets a description for the ritual and completes the builder.
     pub fn description(
         self,
@@ -33,6 +41,7 @@ impl TerrestrialShapingRitualBuilder {
             shaping_ritual: ShapingRitualDetails {
                 book_reference: self.book_reference,
                 description: description.into(),
+                bonus_motes: self.bonus_motes,
             },
         }
     }
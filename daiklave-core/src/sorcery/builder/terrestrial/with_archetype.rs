@@ -23,6 +23,7 @@ impl TerrestrialSorceryBuilderWithArchetype {
             archetype: self.archetype,
             summary: summary.into(),
             book_reference: None,
+            bonus_motes: 0,
         }
     }
 
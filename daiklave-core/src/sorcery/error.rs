@@ -11,9 +11,33 @@ pub enum SorceryError {
     /// each.
     #[error("Each Circle needs its own Shaping Ritual")]
     DuplicateShapingRitual,
+    /// Each sorcerous working needs a unique name.
+    #[error("A working with that name already exists")]
+    DuplicateWorking,
+    /// A spell cannot be cast without shaping enough sorcerous motes for it.
+    #[error("Not enough sorcerous motes to cast the spell")]
+    InsufficientSorcerousMotes,
+    /// Casting a spell requires enough Willpower to pay its cost.
+    #[error("Not enough Willpower to cast the spell")]
+    InsufficientWillpower,
+    /// A working's finesse must be between 1 and 5.
+    #[error("Finesse must be between 1 and 5")]
+    InvalidFinesse,
     /// Shaping rituals require specific sorcerous archetypes to use.
     #[error("Missing an archetype for a shaping ritual")]
     MissingArchetype,
+    /// Sorcerous workings require the sorcerer to know the working's circle.
+    #[error("Sorcerer has not been initiated into that circle")]
+    MissingCircle,
+    /// The sorcerer has no shaping ritual for that circle.
+    #[error("No shaping ritual for that circle")]
+    MissingShapingRitual,
+    /// The character is not shaping a spell, or is shaping a different one.
+    #[error("Not shaping that spell")]
+    NotShaping,
+    /// Ritual spells are not cast by shaping sorcerous motes.
+    #[error("Ritual spells cannot be shaped")]
+    RitualSpell,
     /// Only Solars can learn Solar sorcery, and only Celestial Exalts can
     /// learn Celestial sorcery.
     #[error("Wrong Exalt type for this sorcery level")]
@@ -26,4 +50,13 @@ pub enum SorceryError {
     /// Cannot remove a Control Spell.
     #[error("Cannot remove a Control Spell.")]
     RemoveControlSpell,
+    /// The sorcerer does not know the spell.
+    #[error("Spell not found")]
+    SpellNotFound,
+    /// The working has already been completed or has failed.
+    #[error("Working is already finished")]
+    WorkingFinished,
+    /// The sorcerer has no working with that name.
+    #[error("Working not found")]
+    WorkingNotFound,
 }
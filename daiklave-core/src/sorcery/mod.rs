@@ -7,7 +7,9 @@ mod archetype;
 pub mod builder;
 pub(crate) mod circles;
 mod error;
+mod shaping;
 mod spells;
+mod working;
 pub use spells::Spells;
 
 /// Properties of an individual Spell.
@@ -22,13 +24,22 @@ pub use circles::{
     SorceryCircle, TerrestrialSpell,
 };
 pub(crate) use error::SorceryError;
+pub use shaping::{CastSpell, DistractShaping, InterruptShaping, ShapeSorcery, SpellShaping};
+pub use working::{
+    AbandonSorcerousWorking, RollSorcerousWorking, SorcerousWorking, SorcerousWorkingBuilder,
+    StartSorcerousWorking, WorkingAmbition,
+};
 
-use crate::exaltation::ExaltationSorcery;
+use crate::{exaltation::ExaltationSorcery, extended_roll::ExtendedRollStatus};
 
 use self::{builder::SorceryBuilder, spell::Spell};
 
 /// A character's Sorcery abilities.
-pub struct Sorcery<'view, 'source>(pub(crate) ExaltationSorcery<'view, 'source>);
+pub struct Sorcery<'view, 'source>(
+    pub(crate) ExaltationSorcery<'view, 'source>,
+    pub(crate) Option<&'view SpellShaping>,
+    pub(crate) &'view [SorcerousWorking],
+);
 
 impl<'view, 'source> Sorcery<'view, 'source> {
     /// Starts building a new circle of Sorcery to add to a character.
@@ -60,4 +71,27 @@ impl<'view, 'source> Sorcery<'view, 'source> {
     pub fn spells(&self) -> Spells<'view, 'source> {
         Spells(self.0)
     }
+
+    /// The spell currently being shaped and its banked sorcerous motes, if
+    /// the sorcerer is shaping.
+    pub fn shaping(&self) -> Option<&'view SpellShaping> {
+        self.1
+    }
+
+    /// The sorcerer's workings, in the order they were started.
+    pub fn workings(&self) -> impl Iterator<Item = &'view SorcerousWorking> {
+        self.2.iter()
+    }
+
+    /// Gets a sorcerous working by name.
+    pub fn working(&self, name: &str) -> Option<&'view SorcerousWorking> {
+        self.2.iter().find(|working| working.name.as_str() == name)
+    }
+
+    /// The workings the sorcerer has completed.
+    pub fn completed_workings(&self) -> impl Iterator<Item = &'view SorcerousWorking> {
+        self.2
+            .iter()
+            .filter(|working| working.extended_roll.status() == ExtendedRollStatus::Completed)
+    }
 }
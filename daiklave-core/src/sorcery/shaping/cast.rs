@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{sorcery::spell::SpellName, CharacterMutation};
+
+/// A mutation to cast the spell being shaped. Requires enough sorcerous
+/// motes for the spell, and spends its Willpower cost. Any excess sorcerous
+/// motes are lost.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CastSpell(pub SpellName);
+
+impl From<CastSpell> for CharacterMutation {
+    fn from(cast_spell: CastSpell) -> Self {
+        CharacterMutation::CastSpell(cast_spell)
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to resolve a distraction while shaping a spell, such as taking
+/// damage. If the successes rolled to maintain concentration are less than
+/// the difficulty, all banked sorcerous motes are lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DistractShaping {
+    /// The difficulty of the roll to maintain concentration.
+    pub difficulty: u8,
+    /// The number of successes rolled.
+    pub successes: u8,
+}
+
+impl From<DistractShaping> for CharacterMutation {
+    fn from(distract_shaping: DistractShaping) -> Self {
+        CharacterMutation::DistractShaping(distract_shaping)
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to interrupt a sorcerer's shaping, for example by taking an
+/// action other than shaping sorcery. All banked sorcerous motes are lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterruptShaping;
+
+impl From<InterruptShaping> for CharacterMutation {
+    fn from(_interrupt_shaping: InterruptShaping) -> Self {
+        CharacterMutation::InterruptShaping
+    }
+}
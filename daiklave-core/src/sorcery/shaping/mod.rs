@@ -0,0 +1,11 @@
+mod cast;
+mod distract;
+mod interrupt;
+mod shape;
+mod state;
+
+pub use cast::CastSpell;
+pub use distract::DistractShaping;
+pub use interrupt::InterruptShaping;
+pub use shape::ShapeSorcery;
+pub use state::SpellShaping;
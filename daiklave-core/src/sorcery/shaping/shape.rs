@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    sorcery::{spell::SpellName, SorceryCircle},
+    CharacterMutation,
+};
+
+/// A mutation to take a shape sorcery action, banking the successes of an
+/// (Intelligence + Occult) roll as sorcerous motes toward a spell. Shaping a
+/// different spell than the one currently being shaped discards any motes
+/// banked so far.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShapeSorcery {
+    /// The name of the spell being shaped.
+    pub spell: SpellName,
+    /// The number of successes rolled.
+    pub successes: u8,
+    /// The circle of the shaping ritual whose conditions were met during
+    /// this action, if any. Its bonus motes are added to the successes.
+    pub shaping_ritual: Option<SorceryCircle>,
+}
+
+impl From<ShapeSorcery> for CharacterMutation {
+    fn from(shape_sorcery: ShapeSorcery) -> Self {
+        CharacterMutation::ShapeSorcery(shape_sorcery)
+    }
+}
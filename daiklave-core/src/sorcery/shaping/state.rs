@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::sorcery::spell::SpellName;
+
+/// A spell in the process of being shaped, and the sorcerous motes banked
+/// toward it so far. Sorcerous motes vanish if shaping is interrupted, or at
+/// the end of the scene.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpellShaping {
+    pub(crate) spell: SpellName,
+    pub(crate) motes: u8,
+}
+
+impl SpellShaping {
+    /// The name of the spell being shaped.
+    pub fn spell(&self) -> &str {
+        self.spell.as_str()
+    }
+
+    /// The number of sorcerous motes banked toward the spell.
+    pub fn motes(&self) -> u8 {
+        self.motes
+    }
+}
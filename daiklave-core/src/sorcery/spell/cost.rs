@@ -2,14 +2,21 @@ use std::num::NonZeroU8;
 
 use serde::{Deserialize, Serialize};
 
+/// The mote cost of casting a spell.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SpellMotesCost {
+    /// The spell is cast through a lengthy ritual rather than by shaping
+    /// sorcerous motes.
     Ritual,
+    /// The spell requires this many sorcerous motes to be shaped.
     SorcerousMotes(NonZeroU8),
 }
 
+/// The full cost of casting a spell.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SpellCost {
+    /// The sorcerous motes (or ritual) required.
     pub motes_cost: SpellMotesCost,
+    /// The Willpower spent when the spell is cast.
     pub willpower_cost: NonZeroU8,
 }
@@ -11,6 +11,7 @@ mod name;
 use std::{collections::HashSet, num::NonZeroU8};
 
 pub use add::AddSpell;
+pub use cost::{SpellCost, SpellMotesCost};
 pub(crate) use inner::SpellInner;
 pub use keyword::SpellKeyword;
 pub use mutation::SpellMutation;
@@ -18,7 +19,7 @@ pub use name::SpellName;
 
 use crate::book_reference::BookReference;
 
-use self::{builder::SpellBuilder, cost::SpellCost};
+use self::builder::SpellBuilder;
 
 use super::{CelestialSpell, SolarSpell, SorceryCircle, TerrestrialSpell};
 
@@ -66,9 +67,9 @@ impl<'source> Spell<'source> {
     /// The book reference for the spell, if any
     pub fn book_reference(&self) -> Option<BookReference> {
         match self {
-            Spell::Terrestrial(_, terrestrial) => terrestrial.book_reference,
-            Spell::Celestial(_, celestial) => celestial.book_reference,
-            Spell::Solar(_, solar) => solar.book_reference,
+            Spell::Terrestrial(_, terrestrial) => terrestrial.book_reference.clone(),
+            Spell::Celestial(_, celestial) => celestial.book_reference.clone(),
+            Spell::Solar(_, solar) => solar.book_reference.clone(),
         }
     }
 
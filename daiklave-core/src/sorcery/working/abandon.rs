@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to remove a sorcerous working, whether unfinished, failed, or
+/// completed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbandonSorcerousWorking(pub String);
+
+impl From<AbandonSorcerousWorking> for CharacterMutation {
+    fn from(abandon_sorcerous_working: AbandonSorcerousWorking) -> Self {
+        CharacterMutation::AbandonSorcerousWorking(abandon_sorcerous_working)
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::sorcery::SorceryCircle;
+
+/// How ambitious a sorcerous working is for its circle, which determines its
+/// default goal number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WorkingAmbition {
+    /// A modest effect, at the low end of what the circle can achieve.
+    Lesser,
+    /// A significant effect for the circle.
+    Greater,
+    /// An effect at the limit of what the circle can achieve.
+    Superior,
+}
+
+impl WorkingAmbition {
+    /// The default goal number for a working of this ambition at the
+    /// specified circle.
+    pub fn default_goal_number(&self, circle: SorceryCircle) -> u16 {
+        match (circle, self) {
+            (SorceryCircle::Terrestrial, WorkingAmbition::Lesser) => 5,
+            (SorceryCircle::Terrestrial, WorkingAmbition::Greater) => 15,
+            (SorceryCircle::Terrestrial, WorkingAmbition::Superior) => 25,
+            (SorceryCircle::Celestial, WorkingAmbition::Lesser) => 30,
+            (SorceryCircle::Celestial, WorkingAmbition::Greater) => 40,
+            (SorceryCircle::Celestial, WorkingAmbition::Superior) => 50,
+            (SorceryCircle::Solar, WorkingAmbition::Lesser) => 50,
+            (SorceryCircle::Solar, WorkingAmbition::Greater) => 75,
+            (SorceryCircle::Solar, WorkingAmbition::Superior) => 100,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    extended_roll::ExtendedRoll,
+    sorcery::{SorceryCircle, SorceryError},
+};
+
+use super::{SorcerousWorking, StartSorcerousWorking, WorkingAmbition};
+
+/// A builder for a sorcerous working. Finesse defaults to 1, and goal
+/// number, terminus, and interval default to the standard values for the
+/// working's circle and ambition.
+pub struct SorcerousWorkingBuilder {
+    working: SorcerousWorking,
+}
+
+impl SorcerousWorkingBuilder {
+    pub(crate) fn new(name: String, circle: SorceryCircle, ambition: WorkingAmbition) -> Self {
+        Self {
+            working: SorcerousWorking {
+                name,
+                circle,
+                ambition,
+                means: Vec::new(),
+                extended_roll: ExtendedRoll::new(
+                    1,
+                    ambition.default_goal_number(circle),
+                    6,
+                    match circle {
+                        SorceryCircle::Terrestrial => 7,
+                        SorceryCircle::Celestial => 30,
+                        SorceryCircle::Solar => 90,
+                    },
+                ),
+            },
+        }
+    }
+
+    /// Sets the finesse of the working, which is also the difficulty of
+    /// each roll. Errors if the finesse is not between 1 and 5.
+    pub fn finesse(mut self, finesse: u8) -> Result<Self, SorceryError> {
+        if !(1..=5).contains(&finesse) {
+            return Err(SorceryError::InvalidFinesse);
+        }
+        self.working.extended_roll.difficulty = finesse;
+        Ok(self)
+    }
+
+    /// Adds a means the sorcerer has gathered to aid the working, such as
+    /// rare ingredients, a favorable location, or a bound spirit.
+    pub fn means(mut self, means: impl Into<String>) -> Self {
+        self.working.means.push(means.into());
+        self
+    }
+
+    /// Sets the number of accumulated successes needed to complete the
+    /// working.
+    pub fn goal_number(mut self, goal_number: u16) -> Self {
+        self.working.extended_roll.goal = goal_number;
+        self
+    }
+
+    /// Sets the maximum number of rolls before the working fails.
+    pub fn terminus(mut self, terminus: u8) -> Self {
+        self.working.extended_roll.terminus = terminus;
+        self
+    }
+
+    /// Sets the number of days of work each roll represents.
+    pub fn interval_days(mut self, interval_days: u16) -> Self {
+        self.working.extended_roll.interval_days = interval_days;
+        self
+    }
+
+    /// Completes the working, creating a mutation to start it.
+    pub fn build(self) -> StartSorcerousWorking {
+        StartSorcerousWorking(self.working)
+    }
+}
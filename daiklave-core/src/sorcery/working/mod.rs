@@ -0,0 +1,13 @@
+mod abandon;
+mod ambition;
+mod builder;
+mod roll;
+mod sorcerous_working;
+mod start;
+
+pub use abandon::AbandonSorcerousWorking;
+pub use ambition::WorkingAmbition;
+pub use builder::SorcerousWorkingBuilder;
+pub use roll::RollSorcerousWorking;
+pub use sorcerous_working::SorcerousWorking;
+pub use start::StartSorcerousWorking;
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+/// A mutation to record the roll for one interval of work on a sorcerous
+/// working. A botch destroys all progress made so far.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollSorcerousWorking {
+    /// The name of the working.
+    pub name: String,
+    /// The number of successes rolled.
+    pub successes: u8,
+    /// If the roll botched, the consequence of the botch.
+    pub botch: Option<String>,
+}
+
+impl From<RollSorcerousWorking> for CharacterMutation {
+    fn from(roll_sorcerous_working: RollSorcerousWorking) -> Self {
+        CharacterMutation::RollSorcerousWorking(roll_sorcerous_working)
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{extended_roll::ExtendedRoll, sorcery::SorceryCircle};
+
+use super::{SorcerousWorkingBuilder, WorkingAmbition};
+
+/// A sorcerous working tracked on a character's sheet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SorcerousWorking {
+    pub(crate) name: String,
+    pub(crate) circle: SorceryCircle,
+    pub(crate) ambition: WorkingAmbition,
+    pub(crate) means: Vec<String>,
+    pub(crate) extended_roll: ExtendedRoll,
+}
+
+impl SorcerousWorking {
+    /// Starts building a lesser working of the specified circle.
+    pub fn lesser(name: impl Into<String>, circle: SorceryCircle) -> SorcerousWorkingBuilder {
+        SorcerousWorkingBuilder::new(name.into(), circle, WorkingAmbition::Lesser)
+    }
+
+    /// Starts building a greater working of the specified circle.
+    pub fn greater(name: impl Into<String>, circle: SorceryCircle) -> SorcerousWorkingBuilder {
+        SorcerousWorkingBuilder::new(name.into(), circle, WorkingAmbition::Greater)
+    }
+
+    /// Starts building a superior working of the specified circle.
+    pub fn superior(name: impl Into<String>, circle: SorceryCircle) -> SorcerousWorkingBuilder {
+        SorcerousWorkingBuilder::new(name.into(), circle, WorkingAmbition::Superior)
+    }
+
+    /// The name of the working.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The circle of the working.
+    pub fn circle(&self) -> SorceryCircle {
+        self.circle
+    }
+
+    /// The ambition of the working.
+    pub fn ambition(&self) -> WorkingAmbition {
+        self.ambition
+    }
+
+    /// The finesse of the working, from 1 to 5. This is also the difficulty
+    /// of each roll.
+    pub fn finesse(&self) -> u8 {
+        self.extended_roll.difficulty()
+    }
+
+    /// The means the sorcerer has gathered to aid the working.
+    pub fn means(&self) -> impl Iterator<Item = &str> + '_ {
+        self.means.iter().map(|means| means.as_str())
+    }
+
+    /// The rolls made toward the working, with its goal number and
+    /// terminus. Botched rolls destroy all progress made before them.
+    pub fn extended_roll(&self) -> &ExtendedRoll {
+        &self.extended_roll
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::SorcerousWorking;
+
+/// A mutation to begin a sorcerous working.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StartSorcerousWorking(pub SorcerousWorking);
+
+impl From<StartSorcerousWorking> for CharacterMutation {
+    fn from(start_sorcerous_working: StartSorcerousWorking) -> Self {
+        CharacterMutation::StartSorcerousWorking(start_sorcerous_working)
+    }
+}
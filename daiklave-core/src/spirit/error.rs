@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+/// An error related to spirit characters.
+#[derive(Debug, Error)]
+pub enum SpiritError {
+    /// Spirits cannot be materialized twice.
+    #[error("Spirit is already materialized")]
+    AlreadyMaterialized,
+    /// Spirit Essence ratings can only be between 1 and 10.
+    #[error("Spirit Essence must be between 1 and 10")]
+    InvalidEssence,
+    /// The spirit has no Sanctum to enter.
+    #[error("Spirit does not have a Sanctum")]
+    NoSanctum,
+    /// Can't dematerialize a spirit that is not materialized.
+    #[error("Spirit is not materialized")]
+    NotMaterialized,
+    /// Spirits track Essence, motes, and Charms with spirit-specific
+    /// mutations rather than the Quick Character equivalents.
+    #[error("Use the spirit-specific mutation instead")]
+    StatblockMutation,
+}
@@ -0,0 +1,75 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use crate::exaltation::exalt::essence::EssenceError;
+
+use super::SpiritError;
+
+/// The Essence rating and mote pool of a spirit. Unlike the Exalted, spirits
+/// do not divide their motes into personal and peripheral pools; they have a
+/// single pool of (Essence x 10) + 50 motes. Gods and elementals may have
+/// Essence ratings up to 10.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpiritEssence {
+    pub(crate) rating: NonZeroU8,
+    pub(crate) motes_available: u8,
+}
+
+impl Default for SpiritEssence {
+    fn default() -> Self {
+        let rating = NonZeroU8::new(1).unwrap();
+        Self {
+            rating,
+            motes_available: Self::pool_size(rating),
+        }
+    }
+}
+
+impl SpiritEssence {
+    fn pool_size(rating: NonZeroU8) -> u8 {
+        rating.get() * 10 + 50
+    }
+
+    pub(crate) fn new(rating: NonZeroU8) -> Result<Self, SpiritError> {
+        if rating > NonZeroU8::new(10).unwrap() {
+            Err(SpiritError::InvalidEssence)
+        } else {
+            Ok(Self {
+                rating,
+                motes_available: Self::pool_size(rating),
+            })
+        }
+    }
+
+    /// The spirit's Essence rating.
+    pub fn rating(&self) -> NonZeroU8 {
+        self.rating
+    }
+
+    /// The motes currently available to the spirit.
+    pub fn motes_available(&self) -> u8 {
+        self.motes_available
+    }
+
+    /// The maximum size of the spirit's mote pool.
+    pub fn motes_max(&self) -> u8 {
+        Self::pool_size(self.rating)
+    }
+
+    pub(crate) fn spend(&mut self, amount: u8) -> Result<(), EssenceError> {
+        if self.motes_available < amount {
+            return Err(EssenceError::InsufficientMotes);
+        }
+
+        self.motes_available -= amount;
+        Ok(())
+    }
+
+    pub(crate) fn recover(&mut self, amount: u8) {
+        self.motes_available = self
+            .motes_available
+            .saturating_add(amount)
+            .min(self.motes_max());
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    charms::charm::{spirit::SpiritCharmName, EclipseCharm, SpiritOnlyCharm},
+    quick_character::QuickCharacterMemo,
+    CharacterMutationError,
+};
+
+use super::{essence::SpiritEssence, Spirit, SpiritMutation};
+
+/// An owned instance of a spirit. This is the format used in serialization
+/// and deserialization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpiritMemo {
+    pub(crate) statblock: QuickCharacterMemo,
+    pub(crate) essence: SpiritEssence,
+    pub(crate) eclipse_charms: HashMap<SpiritCharmName, EclipseCharm>,
+    pub(crate) spirit_only_charms: HashMap<SpiritCharmName, SpiritOnlyCharm>,
+    pub(crate) sanctum: Option<(String, String)>,
+    pub(crate) in_sanctum: bool,
+    pub(crate) materialized: bool,
+}
+
+impl Default for SpiritMemo {
+    fn default() -> Self {
+        Self {
+            statblock: QuickCharacterMemo {
+                name: "New Spirit".to_owned(),
+                ..Default::default()
+            },
+            essence: Default::default(),
+            eclipse_charms: Default::default(),
+            spirit_only_charms: Default::default(),
+            sanctum: None,
+            in_sanctum: false,
+            materialized: false,
+        }
+    }
+}
+
+impl From<Spirit<'_>> for SpiritMemo {
+    fn from(spirit: Spirit<'_>) -> Self {
+        Self {
+            statblock: spirit.statblock.into(),
+            essence: spirit.essence,
+            eclipse_charms: spirit
+                .eclipse_charms
+                .into_iter()
+                .map(|(name, charm)| (name.into(), charm.to_owned()))
+                .collect(),
+            spirit_only_charms: spirit
+                .spirit_only_charms
+                .into_iter()
+                .map(|(name, charm)| (name.into(), charm.to_owned()))
+                .collect(),
+            sanctum: spirit
+                .sanctum
+                .map(|sanctum| (sanctum.name.to_owned(), sanctum.description.to_owned())),
+            in_sanctum: spirit.in_sanctum,
+            materialized: spirit.materialized,
+        }
+    }
+}
+
+impl SpiritMemo {
+    /// Applies a specific SpiritMutation or returns an error. Note that this
+    /// operation is applied immutably, returning a cloned and updated version
+    /// of the spirit.
+    pub fn apply_mutation(
+        &self,
+        mutation: &SpiritMutation,
+    ) -> Result<SpiritMemo, CharacterMutationError> {
+        let mut view: Spirit = self.into();
+        view.apply_mutation(mutation)?;
+        Ok(view.into())
+    }
+}
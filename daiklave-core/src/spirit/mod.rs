@@ -0,0 +1,258 @@
+mod error;
+mod essence;
+mod memo;
+mod mutation;
+mod sanctum;
+
+pub use error::SpiritError;
+pub use essence::SpiritEssence;
+pub use memo::SpiritMemo;
+pub use mutation::SpiritMutation;
+pub use sanctum::Sanctum;
+
+use std::{collections::HashMap, num::NonZeroU8};
+
+use crate::{
+    charms::{
+        charm::{
+            spirit::{AddEclipseCharm, AddSpiritCharm, AddSpiritOnlyCharm},
+            Charm, EclipseCharm, SpiritOnlyCharm,
+        },
+        CharmError,
+    },
+    exaltation::exalt::essence::RecoverMotes,
+    quick_character::{QuickCharacter, QuickCharacterMutation},
+    CharacterMutationError,
+};
+
+/// A borrowed instance of a spirit, such as a god, demon, or elemental.
+/// Spirits use the Quick Character format for their traits, but have their
+/// own Essence and mote pool, Spirit Charms (including those not available to
+/// Eclipse Caste Solars), a Sanctum, and may be materialized or immaterial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spirit<'source> {
+    pub(crate) statblock: QuickCharacter<'source>,
+    pub(crate) essence: SpiritEssence,
+    pub(crate) eclipse_charms: HashMap<&'source str, &'source EclipseCharm>,
+    pub(crate) spirit_only_charms: HashMap<&'source str, &'source SpiritOnlyCharm>,
+    pub(crate) sanctum: Option<Sanctum<'source>>,
+    pub(crate) in_sanctum: bool,
+    pub(crate) materialized: bool,
+}
+
+impl<'source> From<&'source SpiritMemo> for Spirit<'source> {
+    fn from(memo: &'source SpiritMemo) -> Self {
+        Self {
+            statblock: (&memo.statblock).into(),
+            essence: memo.essence,
+            eclipse_charms: memo
+                .eclipse_charms
+                .iter()
+                .map(|(name, charm)| (name.as_str(), charm))
+                .collect(),
+            spirit_only_charms: memo
+                .spirit_only_charms
+                .iter()
+                .map(|(name, charm)| (name.as_str(), charm))
+                .collect(),
+            sanctum: memo.sanctum.as_ref().map(|(name, description)| Sanctum {
+                name: name.as_str(),
+                description: description.as_str(),
+            }),
+            in_sanctum: memo.in_sanctum,
+            materialized: memo.materialized,
+        }
+    }
+}
+
+impl<'source> Spirit<'source> {
+    /// Applies a mutation to the spirit.
+    pub fn apply_mutation(
+        &mut self,
+        mutation: &'source SpiritMutation,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        match mutation {
+            SpiritMutation::Statblock(statblock_mutation) => {
+                self.apply_statblock_mutation(statblock_mutation)
+            }
+            SpiritMutation::SetEssence(rating) => self.set_essence_rating(*rating),
+            SpiritMutation::SpendMotes(amount) => {
+                self.essence.spend(amount.get())?;
+                Ok(self)
+            }
+            SpiritMutation::RecoverMotes(RecoverMotes(amount)) => {
+                self.essence.recover(amount.get());
+                Ok(self)
+            }
+            SpiritMutation::AddCharm(add_spirit_charm) => self.add_charm(add_spirit_charm),
+            SpiritMutation::RemoveCharm(name) => self.remove_charm(name.as_str()),
+            SpiritMutation::SetSanctum { name, description } => {
+                self.sanctum = Some(Sanctum {
+                    name: name.as_str(),
+                    description: description.as_str(),
+                });
+                Ok(self)
+            }
+            SpiritMutation::RemoveSanctum => {
+                self.sanctum = None;
+                self.in_sanctum = false;
+                Ok(self)
+            }
+            SpiritMutation::EnterSanctum => {
+                if self.sanctum.is_none() {
+                    return Err(SpiritError::NoSanctum.into());
+                }
+                self.in_sanctum = true;
+                self.materialized = false;
+                Ok(self)
+            }
+            SpiritMutation::LeaveSanctum => {
+                self.in_sanctum = false;
+                Ok(self)
+            }
+            SpiritMutation::Materialize(cost) => self.materialize(*cost),
+            SpiritMutation::Dematerialize => {
+                if !self.materialized {
+                    return Err(SpiritError::NotMaterialized.into());
+                }
+                self.materialized = false;
+                Ok(self)
+            }
+        }
+    }
+
+    fn apply_statblock_mutation(
+        &mut self,
+        statblock_mutation: &'source QuickCharacterMutation,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        match statblock_mutation {
+            QuickCharacterMutation::SetEssence(_)
+            | QuickCharacterMutation::RemoveEssence
+            | QuickCharacterMutation::SpendMotes(_)
+            | QuickCharacterMutation::RecoverMotes(_)
+            | QuickCharacterMutation::AddCharm { .. }
+            | QuickCharacterMutation::RemoveCharm(_) => Err(SpiritError::StatblockMutation.into()),
+            other => {
+                self.statblock.apply_mutation(other)?;
+                Ok(self)
+            }
+        }
+    }
+
+    /// The spirit's name.
+    pub fn name(&self) -> &'source str {
+        self.statblock.name()
+    }
+
+    /// The spirit's traits (action pools, attacks, defenses, health, and
+    /// Willpower) in Quick Character format.
+    pub fn statblock(&self) -> &QuickCharacter<'source> {
+        &self.statblock
+    }
+
+    /// The spirit's Essence rating and mote pool.
+    pub fn essence(&self) -> &SpiritEssence {
+        &self.essence
+    }
+
+    /// Gets a Spirit Charm by name.
+    pub fn charm(&self, name: &str) -> Option<Charm<'source>> {
+        self.eclipse_charms
+            .get(name)
+            .map(|eclipse_charm| Charm::Eclipse(eclipse_charm))
+            .or_else(|| {
+                self.spirit_only_charms
+                    .get(name)
+                    .map(|spirit_only_charm| Charm::SpiritOnly(spirit_only_charm))
+            })
+    }
+
+    /// Iterates over the names of all of the spirit's Charms.
+    pub fn charm_names(&self) -> impl Iterator<Item = &'source str> + '_ {
+        self.eclipse_charms
+            .keys()
+            .chain(self.spirit_only_charms.keys())
+            .copied()
+    }
+
+    /// The spirit's Sanctum, if it has one.
+    pub fn sanctum(&self) -> Option<Sanctum<'source>> {
+        self.sanctum
+    }
+
+    /// True if the spirit is currently within its Sanctum.
+    pub fn is_in_sanctum(&self) -> bool {
+        self.in_sanctum
+    }
+
+    /// True if the spirit has taken on a physical form.
+    pub fn is_materialized(&self) -> bool {
+        self.materialized
+    }
+
+    /// Sets the spirit's Essence rating, refilling its mote pool.
+    pub fn set_essence_rating(
+        &mut self,
+        rating: NonZeroU8,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        self.essence = SpiritEssence::new(rating)?;
+        Ok(self)
+    }
+
+    /// Adds a Spirit Charm. The spirit must have at least the Charm's
+    /// required Essence.
+    pub fn add_charm(
+        &mut self,
+        add_spirit_charm: &'source AddSpiritCharm,
+    ) -> Result<&mut Self, CharacterMutationError> {
+        let (name, essence_required) = match add_spirit_charm {
+            AddSpiritCharm::Eclipse(AddEclipseCharm { name, charm }) => {
+                (name.as_str(), charm.essence_required())
+            }
+            AddSpiritCharm::SpiritOnly(AddSpiritOnlyCharm { name, charm }) => {
+                (name.as_str(), charm.essence_required())
+            }
+        };
+
+        if self.charm(name).is_some() {
+            return Err(CharmError::DuplicateCharm.into());
+        }
+
+        if essence_required > self.essence.rating.get() {
+            return Err(CharmError::PrerequisitesNotMet.into());
+        }
+
+        match add_spirit_charm {
+            AddSpiritCharm::Eclipse(AddEclipseCharm { charm, .. }) => {
+                self.eclipse_charms.insert(name, charm);
+            }
+            AddSpiritCharm::SpiritOnly(AddSpiritOnlyCharm { charm, .. }) => {
+                self.spirit_only_charms.insert(name, charm);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Removes a Spirit Charm by name.
+    pub fn remove_charm(&mut self, name: &str) -> Result<&mut Self, CharacterMutationError> {
+        if self.eclipse_charms.remove(name).is_none()
+            && self.spirit_only_charms.remove(name).is_none()
+        {
+            return Err(CharmError::NotFound.into());
+        }
+        Ok(self)
+    }
+
+    /// Materializes the spirit, spending the specified motes. A spirit that
+    /// materializes leaves its Sanctum.
+    pub fn materialize(&mut self, cost: NonZeroU8) -> Result<&mut Self, CharacterMutationError> {
+        if self.materialized {
+            return Err(SpiritError::AlreadyMaterialized.into());
+        }
+
+        self.essence.spend(cost.get())?;
+        self.materialized = true;
+        self.in_sanctum = false;
+        Ok(self)
+    }
+}
@@ -0,0 +1,66 @@
+use std::num::NonZeroU8;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    charms::charm::spirit::{AddSpiritCharm, SpiritCharmName},
+    exaltation::exalt::essence::RecoverMotes,
+    quick_character::QuickCharacterMutation,
+};
+
+/// The ways a spirit can be atomically updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpiritMutation {
+    /// Updates the spirit's statblock (name, action pools, attacks, flat
+    /// stats, health, and Willpower). Essence, mote, and Charm mutations are
+    /// not allowed here; use the spirit-specific mutations instead.
+    Statblock(QuickCharacterMutation),
+    /// Sets the spirit's Essence rating (from 1 to 10), refilling its mote
+    /// pool.
+    SetEssence(NonZeroU8),
+    /// Spends motes from the spirit's mote pool.
+    SpendMotes(NonZeroU8),
+    /// Recovers spent motes.
+    RecoverMotes(RecoverMotes),
+    /// Adds a Spirit Charm, which may be either an Eclipse Charm or a
+    /// spirit-only Charm.
+    AddCharm(AddSpiritCharm),
+    /// Removes a Spirit Charm by name.
+    RemoveCharm(SpiritCharmName),
+    /// Sets the spirit's Sanctum.
+    SetSanctum {
+        /// The name of the Sanctum.
+        name: String,
+        /// A description of the Sanctum.
+        description: String,
+    },
+    /// Removes the spirit's Sanctum, ejecting it if it is inside.
+    RemoveSanctum,
+    /// Retreats into the spirit's Sanctum, dematerializing if necessary.
+    EnterSanctum,
+    /// Leaves the spirit's Sanctum.
+    LeaveSanctum,
+    /// Materializes the spirit, spending the specified motes (as listed by
+    /// its Materialize Charm). Leaves its Sanctum if necessary.
+    Materialize(NonZeroU8),
+    /// Returns the spirit to its immaterial state.
+    Dematerialize,
+}
+
+impl From<QuickCharacterMutation> for SpiritMutation {
+    fn from(quick_character_mutation: QuickCharacterMutation) -> Self {
+        Self::Statblock(quick_character_mutation)
+    }
+}
+
+impl From<AddSpiritCharm> for SpiritMutation {
+    fn from(add_spirit_charm: AddSpiritCharm) -> Self {
+        Self::AddCharm(add_spirit_charm)
+    }
+}
+
+impl From<RecoverMotes> for SpiritMutation {
+    fn from(recover_motes: RecoverMotes) -> Self {
+        Self::RecoverMotes(recover_motes)
+    }
+}
@@ -0,0 +1,20 @@
+/// The personal dwelling place of a spirit, such as a god's shrine-palace or
+/// an elemental's hidden grotto. Spirits within their Sanctum are
+/// dematerialized and beyond the reach of most mortals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sanctum<'source> {
+    pub(crate) name: &'source str,
+    pub(crate) description: &'source str,
+}
+
+impl<'source> Sanctum<'source> {
+    /// The name of the Sanctum.
+    pub fn name(&self) -> &'source str {
+        self.name
+    }
+
+    /// A description of the Sanctum.
+    pub fn description(&self) -> &'source str {
+        self.description
+    }
+}
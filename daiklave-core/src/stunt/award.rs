@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CharacterMutation;
+
+use super::StuntLevel;
+
+/// A mutation to award a stunt to the character, applying its Willpower
+/// reward. The bonus dice (or static bonus) apply only to the stunted
+/// action, and are not recorded on the character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AwardStunt {
+    /// The level of the stunt.
+    pub level: StuntLevel,
+}
+
+impl AwardStunt {
+    /// The bonus dice the stunt adds to the stunted action.
+    pub fn bonus_dice(&self) -> u8 {
+        self.level.bonus_dice()
+    }
+
+    /// The static bonus the stunt grants, if used to enhance a static value
+    /// instead of a dice pool.
+    pub fn static_bonus(&self) -> u8 {
+        self.level.static_bonus()
+    }
+}
+
+impl From<AwardStunt> for CharacterMutation {
+    fn from(award_stunt: AwardStunt) -> Self {
+        CharacterMutation::AwardStunt(award_stunt)
+    }
+}
@@ -0,0 +1,57 @@
+mod award;
+pub use award::AwardStunt;
+
+use serde::{Deserialize, Serialize};
+
+/// How impressive a stunt was, as judged by the storyteller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum StuntLevel {
+    /// A one-point stunt: a vivid description of the action.
+    One,
+    /// A two-point stunt: a description that also uses the scene's
+    /// environment or circumstances to the character's advantage.
+    Two,
+    /// A three-point stunt: an exceptional description with a lasting
+    /// impact on the scene.
+    Three,
+}
+
+impl StuntLevel {
+    /// Gets the stunt level for a rating from 1 to 3, or None if out of
+    /// range.
+    pub fn new(level: u8) -> Option<Self> {
+        match level {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            3 => Some(Self::Three),
+            _ => None,
+        }
+    }
+
+    /// The stunt level as a number from 1 to 3.
+    pub fn get(&self) -> u8 {
+        match self {
+            StuntLevel::One => 1,
+            StuntLevel::Two => 2,
+            StuntLevel::Three => 3,
+        }
+    }
+
+    /// The bonus dice added to the stunted action's pool. Stunts of every
+    /// level grant two dice.
+    pub fn bonus_dice(&self) -> u8 {
+        2
+    }
+
+    /// The static bonus the stunt grants instead, if it enhances a static
+    /// value such as Defense rather than a dice pool.
+    pub fn static_bonus(&self) -> u8 {
+        1
+    }
+
+    /// The Willpower the character regains: none for a one-point stunt, one
+    /// for a two-point stunt, and two for a three-point stunt.
+    pub fn willpower(&self) -> u8 {
+        self.get() - 1
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    book_reference::{Book, BookReference},
+    weapons::weapon::{OptionalWeaponTag, RangeBand, WeaponWeightClass},
+};
+
+use super::AddBaseArtifactWeapon;
+
+/// The base artifact weapons listed in the Core Rulebook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CoreRulebookBaseArtifactWeapon {
+    /// Short Daiklave
+    ShortDaiklave,
+    /// Razor Claws
+    RazorClaws,
+    /// Smashfist
+    Smashfist,
+    /// Daiklave
+    Daiklave,
+    /// Reaver Daiklave
+    ReaverDaiklave,
+    /// Direlance
+    Direlance,
+    /// Goremaul
+    Goremaul,
+    /// Grand Daiklave
+    GrandDaiklave,
+    /// Grand Goremaul
+    GrandGoremaul,
+    /// Devil Caster
+    DevilCaster,
+    /// Powerbow
+    Powerbow,
+    /// Skydisc
+    Skydisc,
+    /// Shield (Artifact)
+    Shield,
+}
+
+impl CoreRulebookBaseArtifactWeapon {
+    /// The name of the item, as printed in the book.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ShortDaiklave => "Short Daiklave",
+            Self::RazorClaws => "Razor Claws",
+            Self::Smashfist => "Smashfist",
+            Self::Daiklave => "Daiklave",
+            Self::ReaverDaiklave => "Reaver Daiklave",
+            Self::Direlance => "Direlance",
+            Self::Goremaul => "Goremaul",
+            Self::GrandDaiklave => "Grand Daiklave",
+            Self::GrandGoremaul => "Grand Goremaul",
+            Self::DevilCaster => "Devil Caster",
+            Self::Powerbow => "Powerbow",
+            Self::Skydisc => "Skydisc",
+            Self::Shield => "Shield (Artifact)",
+        }
+    }
+
+    /// The page of the Core Rulebook the item is listed on.
+    pub fn book_reference(&self) -> BookReference {
+        let page_number = match self {
+            Self::ShortDaiklave | Self::RazorClaws | Self::Smashfist | Self::Shield => 597,
+            Self::Daiklave | Self::ReaverDaiklave | Self::Direlance | Self::Goremaul => 598,
+            Self::GrandDaiklave | Self::GrandGoremaul => 599,
+            Self::DevilCaster | Self::Powerbow | Self::Skydisc => 600,
+        };
+
+        BookReference::published(Book::CoreRulebook, page_number)
+    }
+
+    /// Iterates over every entry in the catalog, in book order.
+    pub fn iter() -> impl Iterator<Item = CoreRulebookBaseArtifactWeapon> {
+        [
+            Self::ShortDaiklave,
+            Self::RazorClaws,
+            Self::Smashfist,
+            Self::Daiklave,
+            Self::ReaverDaiklave,
+            Self::Direlance,
+            Self::Goremaul,
+            Self::GrandDaiklave,
+            Self::GrandGoremaul,
+            Self::DevilCaster,
+            Self::Powerbow,
+            Self::Skydisc,
+            Self::Shield,
+        ]
+        .into_iter()
+    }
+}
+
+impl AddBaseArtifactWeapon {
+    /// Iterates over every item in the Core Rulebook catalog, ready to be
+    /// added to a character.
+    pub fn core_rulebook() -> impl Iterator<Item = AddBaseArtifactWeapon> {
+        CoreRulebookBaseArtifactWeapon::iter().map(Self::from_core_rulebook)
+    }
+
+    /// Constructs the given item from the Core Rulebook catalog.
+    pub fn from_core_rulebook(item: CoreRulebookBaseArtifactWeapon) -> AddBaseArtifactWeapon {
+        let name = item.name();
+        let book_reference = item.book_reference();
+
+        let weapon = match item {
+            CoreRulebookBaseArtifactWeapon::ShortDaiklave => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Balanced),
+            CoreRulebookBaseArtifactWeapon::RazorClaws => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .worn()
+                .lethal()
+                .brawl(),
+            CoreRulebookBaseArtifactWeapon::Smashfist => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .worn()
+                .bashing()
+                .brawl()
+                .tag(OptionalWeaponTag::Smashing),
+            CoreRulebookBaseArtifactWeapon::Daiklave => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Balanced),
+            CoreRulebookBaseArtifactWeapon::ReaverDaiklave => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Chopping),
+            CoreRulebookBaseArtifactWeapon::Direlance => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .melee()
+                .thrown_range(RangeBand::Short)
+                .tag(OptionalWeaponTag::Piercing)
+                .tag(OptionalWeaponTag::Reaching),
+            CoreRulebookBaseArtifactWeapon::Goremaul => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .bashing()
+                .melee()
+                .tag(OptionalWeaponTag::Smashing),
+            CoreRulebookBaseArtifactWeapon::GrandDaiklave => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Heavy)
+                .two_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Balanced)
+                .tag(OptionalWeaponTag::Reaching),
+            CoreRulebookBaseArtifactWeapon::GrandGoremaul => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Heavy)
+                .two_handed()
+                .bashing()
+                .melee()
+                .tag(OptionalWeaponTag::Reaching)
+                .tag(OptionalWeaponTag::Smashing),
+            CoreRulebookBaseArtifactWeapon::DevilCaster => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .archery()
+                .archery_range(RangeBand::Short)
+                .tag(OptionalWeaponTag::Flame)
+                .tag(OptionalWeaponTag::Mounted)
+                .tag(OptionalWeaponTag::Slow),
+            CoreRulebookBaseArtifactWeapon::Powerbow => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .two_handed()
+                .lethal()
+                .archery()
+                .archery_range(RangeBand::Extreme)
+                .tag(OptionalWeaponTag::Powerful),
+            CoreRulebookBaseArtifactWeapon::Skydisc => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .lethal()
+                .thrown()
+                .thrown_range(RangeBand::Long)
+                .tag(OptionalWeaponTag::Cutting),
+            CoreRulebookBaseArtifactWeapon::Shield => Self::base_name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .bashing()
+                .melee()
+                .tag(OptionalWeaponTag::Shield),
+        };
+
+        weapon.build()
+    }
+}
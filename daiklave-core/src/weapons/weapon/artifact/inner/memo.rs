@@ -22,7 +22,7 @@ pub struct ArtifactWeaponInnerMemo {
 impl From<&ArtifactWeaponInner<'_>> for ArtifactWeaponInnerMemo {
     fn from(value: &ArtifactWeaponInner<'_>) -> Self {
         Self {
-            book_reference: value.book_reference,
+            book_reference: value.book_reference.clone(),
             merit_dots: value.merit_dots,
             magic_material: value.magic_material,
             base_weapon_name: value.base_weapon_name.into(),
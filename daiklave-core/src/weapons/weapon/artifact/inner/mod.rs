@@ -24,7 +24,7 @@ pub struct ArtifactWeaponInner<'source> {
 impl<'source> From<&'source ArtifactWeaponInnerMemo> for ArtifactWeaponInner<'source> {
     fn from(memo: &'source ArtifactWeaponInnerMemo) -> Self {
         Self {
-            book_reference: memo.book_reference,
+            book_reference: memo.book_reference.clone(),
             merit_dots: memo.merit_dots,
             magic_material: memo.magic_material,
             base_weapon_name: &memo.base_weapon_name,
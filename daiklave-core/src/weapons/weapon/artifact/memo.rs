@@ -32,6 +32,15 @@ impl AddArtifactWeapon {
     ) -> ArtifactWeaponBuilder {
         ArtifactWeaponBuilder::base_weapon(add_base_weapon)
     }
+
+    pub(crate) fn merit_dots(&self) -> u8 {
+        match &self.handedness {
+            ArtifactWeaponHandedness::Natural(natural) => natural.0.merit_dots,
+            ArtifactWeaponHandedness::Worn(worn, _) => worn.0.merit_dots,
+            ArtifactWeaponHandedness::OneHanded(one_handed, _) => one_handed.0.merit_dots,
+            ArtifactWeaponHandedness::TwoHanded(two_handed, _) => two_handed.0.merit_dots,
+        }
+    }
 }
 
 impl From<ArtifactWeaponBuilderWithHearthstoneSlots> for AddArtifactWeapon {
@@ -1,6 +1,7 @@
 mod add_base;
 mod base;
 pub(crate) mod builder;
+mod catalog;
 mod grouped;
 mod handedness;
 mod inner;
@@ -13,6 +14,7 @@ use std::ops::Deref;
 
 pub use add_base::AddBaseArtifactWeapon;
 pub(crate) use base::BaseArtifactWeapon;
+pub use catalog::CoreRulebookBaseArtifactWeapon;
 pub(crate) use grouped::{
     HandlessArtifactWeapon, HandlessArtifactWeaponMemo, HandlessArtifactWeaponNoAttunement,
     HandlessArtifactWeaponNoAttunementMemo, NonnaturalArtifactWeapon, NonnaturalArtifactWeaponMemo,
@@ -1,8 +1,13 @@
 use std::collections::HashSet;
 
 use crate::{
-    book_reference::BookReference, exaltation::exalt::essence::MoteCommitment,
-    hearthstones::hearthstone::Hearthstone, merits::merit::Merit,
+    artifact::{ArtifactName, MagicMaterial},
+    book_reference::BookReference,
+    charms::charm::evocation::EvokableName,
+    exaltation::exalt::essence::MoteCommitment,
+    hearthstones::hearthstone::Hearthstone,
+    merits::merit::Merit,
+    Character,
 };
 
 use self::{
@@ -126,6 +131,25 @@ impl<'source> Weapon<'source> {
         self.0.powers()
     }
 
+    /// If the weapon is an artifact, the Magic Material it is made from.
+    /// Always None for mundane weapons.
+    pub fn material(&self) -> Option<MagicMaterial> {
+        self.0.material()
+    }
+
+    /// The Evocations the character can currently use from this weapon.
+    /// Evocations which are disabled (for example, because the weapon is not
+    /// attuned) are omitted. Always empty for mundane weapons.
+    pub fn evocations(&self, character: &Character<'source>) -> impl Iterator<Item = &'source str> {
+        match self.name() {
+            WeaponName::Artifact(name) => {
+                character.available_evocations(EvokableName::Artifact(ArtifactName::Weapon(name)))
+            }
+            WeaponName::Mundane(_) | WeaponName::Unarmed => Vec::new(),
+        }
+        .into_iter()
+    }
+
     /// The number of hearthstone slots (occupied and unoccupied) in the
     /// weapon. Always 0 for mundane weapons.
     pub fn hearthstone_slots(&self) -> u8 {
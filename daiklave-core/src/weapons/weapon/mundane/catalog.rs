@@ -0,0 +1,398 @@
+use crate::{
+    book_reference::{Book, BookReference},
+    weapons::weapon::{OptionalWeaponTag, RangeBand, WeaponWeightClass},
+};
+
+use super::AddMundaneWeapon;
+
+/// The mundane weapons listed in the Core Rulebook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CoreRulebookMundaneWeapon {
+    /// Hatchet
+    Hatchet,
+    /// Hook Sword
+    HookSword,
+    /// Knife
+    Knife,
+    /// Short Sword
+    ShortSword,
+    /// Tiger Claws
+    TigerClaws,
+    /// Fighting Gauntlet
+    FightingGauntlet,
+    /// Axe
+    Axe,
+    /// Mace
+    Mace,
+    /// Spear
+    Spear,
+    /// Staff
+    Staff,
+    /// Straight Sword
+    StraightSword,
+    /// Seven-Section Staff
+    SevenSectionStaff,
+    /// Slashing Sword
+    SlashingSword,
+    /// Great Axe
+    GreatAxe,
+    /// Great Sword
+    GreatSword,
+    /// Poleaxe
+    Poleaxe,
+    /// Tetsubo
+    Tetsubo,
+    /// Sling
+    Sling,
+    /// Short Bow
+    ShortBow,
+    /// Long Bow
+    LongBow,
+    /// Crossbow
+    Crossbow,
+    /// Flame Piece
+    FlamePiece,
+    /// Firewand
+    Firewand,
+    /// Chakram
+    Chakram,
+    /// Javelin
+    Javelin,
+    /// Throwing Knife
+    ThrowingKnife,
+    /// Shield
+    Shield,
+}
+
+impl CoreRulebookMundaneWeapon {
+    /// The name of the item, as printed in the book.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Hatchet => "Hatchet",
+            Self::HookSword => "Hook Sword",
+            Self::Knife => "Knife",
+            Self::ShortSword => "Short Sword",
+            Self::TigerClaws => "Tiger Claws",
+            Self::FightingGauntlet => "Fighting Gauntlet",
+            Self::Axe => "Axe",
+            Self::Mace => "Mace",
+            Self::Spear => "Spear",
+            Self::Staff => "Staff",
+            Self::StraightSword => "Straight Sword",
+            Self::SevenSectionStaff => "Seven-Section Staff",
+            Self::SlashingSword => "Slashing Sword",
+            Self::GreatAxe => "Great Axe",
+            Self::GreatSword => "Great Sword",
+            Self::Poleaxe => "Poleaxe",
+            Self::Tetsubo => "Tetsubo",
+            Self::Sling => "Sling",
+            Self::ShortBow => "Short Bow",
+            Self::LongBow => "Long Bow",
+            Self::Crossbow => "Crossbow",
+            Self::FlamePiece => "Flame Piece",
+            Self::Firewand => "Firewand",
+            Self::Chakram => "Chakram",
+            Self::Javelin => "Javelin",
+            Self::ThrowingKnife => "Throwing Knife",
+            Self::Shield => "Shield",
+        }
+    }
+
+    /// The page of the Core Rulebook the item is listed on.
+    pub fn book_reference(&self) -> BookReference {
+        let page_number = match self {
+            Self::Hatchet
+            | Self::HookSword
+            | Self::Knife
+            | Self::ShortSword
+            | Self::TigerClaws
+            | Self::FightingGauntlet
+            | Self::Shield => 582,
+            Self::Axe
+            | Self::Mace
+            | Self::Spear
+            | Self::Staff
+            | Self::StraightSword
+            | Self::SevenSectionStaff
+            | Self::SlashingSword => 583,
+            Self::GreatAxe | Self::GreatSword | Self::Poleaxe | Self::Tetsubo => 584,
+            Self::Sling
+            | Self::ShortBow
+            | Self::LongBow
+            | Self::Crossbow
+            | Self::FlamePiece
+            | Self::Firewand
+            | Self::Chakram
+            | Self::Javelin
+            | Self::ThrowingKnife => 586,
+        };
+
+        BookReference::published(Book::CoreRulebook, page_number)
+    }
+
+    /// Iterates over every entry in the catalog, in book order.
+    pub fn iter() -> impl Iterator<Item = CoreRulebookMundaneWeapon> {
+        [
+            Self::Hatchet,
+            Self::HookSword,
+            Self::Knife,
+            Self::ShortSword,
+            Self::TigerClaws,
+            Self::FightingGauntlet,
+            Self::Axe,
+            Self::Mace,
+            Self::Spear,
+            Self::Staff,
+            Self::StraightSword,
+            Self::SevenSectionStaff,
+            Self::SlashingSword,
+            Self::GreatAxe,
+            Self::GreatSword,
+            Self::Poleaxe,
+            Self::Tetsubo,
+            Self::Sling,
+            Self::ShortBow,
+            Self::LongBow,
+            Self::Crossbow,
+            Self::FlamePiece,
+            Self::Firewand,
+            Self::Chakram,
+            Self::Javelin,
+            Self::ThrowingKnife,
+            Self::Shield,
+        ]
+        .into_iter()
+    }
+}
+
+impl AddMundaneWeapon {
+    /// Iterates over every item in the Core Rulebook catalog, ready to be
+    /// added to a character.
+    pub fn core_rulebook() -> impl Iterator<Item = AddMundaneWeapon> {
+        CoreRulebookMundaneWeapon::iter().map(Self::from_core_rulebook)
+    }
+
+    /// Constructs the given item from the Core Rulebook catalog.
+    pub fn from_core_rulebook(item: CoreRulebookMundaneWeapon) -> AddMundaneWeapon {
+        let name = item.name();
+        let book_reference = item.book_reference();
+
+        let weapon = match item {
+            CoreRulebookMundaneWeapon::Hatchet => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .lethal()
+                .melee()
+                .thrown_range(RangeBand::Short)
+                .tag(OptionalWeaponTag::Chopping),
+            CoreRulebookMundaneWeapon::HookSword => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Disarming),
+            CoreRulebookMundaneWeapon::Knife => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .lethal()
+                .melee()
+                .thrown_range(RangeBand::Short),
+            CoreRulebookMundaneWeapon::ShortSword => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Balanced),
+            CoreRulebookMundaneWeapon::TigerClaws => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .worn()
+                .lethal()
+                .brawl(),
+            CoreRulebookMundaneWeapon::FightingGauntlet => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .worn()
+                .bashing()
+                .brawl()
+                .tag(OptionalWeaponTag::Smashing),
+            CoreRulebookMundaneWeapon::Axe => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .melee()
+                .thrown_range(RangeBand::Short)
+                .tag(OptionalWeaponTag::Chopping),
+            CoreRulebookMundaneWeapon::Mace => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .bashing()
+                .melee()
+                .tag(OptionalWeaponTag::Smashing),
+            CoreRulebookMundaneWeapon::Spear => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .melee()
+                .thrown_range(RangeBand::Short)
+                .tag(OptionalWeaponTag::Piercing)
+                .tag(OptionalWeaponTag::Reaching),
+            CoreRulebookMundaneWeapon::Staff => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .two_handed()
+                .bashing()
+                .melee()
+                .tag(OptionalWeaponTag::Balanced)
+                .tag(OptionalWeaponTag::Reaching),
+            CoreRulebookMundaneWeapon::StraightSword => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Balanced),
+            CoreRulebookMundaneWeapon::SevenSectionStaff => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .two_handed()
+                .bashing()
+                .martial_arts()
+                .tag(OptionalWeaponTag::Disarming)
+                .tag(OptionalWeaponTag::Flexible),
+            CoreRulebookMundaneWeapon::SlashingSword => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Balanced)
+                .tag(OptionalWeaponTag::Mounted),
+            CoreRulebookMundaneWeapon::GreatAxe => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Heavy)
+                .two_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Chopping),
+            CoreRulebookMundaneWeapon::GreatSword => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Heavy)
+                .two_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Balanced)
+                .tag(OptionalWeaponTag::Reaching),
+            CoreRulebookMundaneWeapon::Poleaxe => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Heavy)
+                .two_handed()
+                .lethal()
+                .melee()
+                .tag(OptionalWeaponTag::Chopping)
+                .tag(OptionalWeaponTag::Reaching)
+                .tag(OptionalWeaponTag::Mounted),
+            CoreRulebookMundaneWeapon::Tetsubo => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Heavy)
+                .two_handed()
+                .bashing()
+                .melee()
+                .tag(OptionalWeaponTag::Reaching)
+                .tag(OptionalWeaponTag::Smashing),
+            CoreRulebookMundaneWeapon::Sling => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .bashing()
+                .archery()
+                .archery_range(RangeBand::Medium),
+            CoreRulebookMundaneWeapon::ShortBow => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .two_handed()
+                .lethal()
+                .archery()
+                .archery_range(RangeBand::Long)
+                .tag(OptionalWeaponTag::Mounted),
+            CoreRulebookMundaneWeapon::LongBow => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .two_handed()
+                .lethal()
+                .archery()
+                .archery_range(RangeBand::Extreme)
+                .tag(OptionalWeaponTag::Powerful),
+            CoreRulebookMundaneWeapon::Crossbow => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .two_handed()
+                .lethal()
+                .archery()
+                .archery_range(RangeBand::Long)
+                .tag(OptionalWeaponTag::Crossbow)
+                .tag(OptionalWeaponTag::Piercing)
+                .tag(OptionalWeaponTag::Powerful)
+                .tag(OptionalWeaponTag::Slow),
+            CoreRulebookMundaneWeapon::FlamePiece => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .archery()
+                .archery_range(RangeBand::Short)
+                .tag(OptionalWeaponTag::Flame)
+                .tag(OptionalWeaponTag::Mounted)
+                .tag(OptionalWeaponTag::Slow),
+            CoreRulebookMundaneWeapon::Firewand => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Heavy)
+                .two_handed()
+                .lethal()
+                .archery()
+                .archery_range(RangeBand::Short)
+                .tag(OptionalWeaponTag::Flame)
+                .tag(OptionalWeaponTag::Slow),
+            CoreRulebookMundaneWeapon::Chakram => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .lethal()
+                .thrown()
+                .thrown_range(RangeBand::Medium)
+                .tag(OptionalWeaponTag::Cutting),
+            CoreRulebookMundaneWeapon::Javelin => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Medium)
+                .one_handed()
+                .lethal()
+                .thrown()
+                .thrown_range(RangeBand::Medium)
+                .tag(OptionalWeaponTag::Piercing),
+            CoreRulebookMundaneWeapon::ThrowingKnife => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .lethal()
+                .thrown()
+                .thrown_range(RangeBand::Medium)
+                .tag(OptionalWeaponTag::Concealable),
+            CoreRulebookMundaneWeapon::Shield => Self::name(name)
+                .book_reference(book_reference)
+                .weight_class(WeaponWeightClass::Light)
+                .one_handed()
+                .bashing()
+                .melee()
+                .tag(OptionalWeaponTag::Shield),
+        };
+
+        weapon.build_mundane()
+    }
+}
@@ -5,6 +5,7 @@ use super::WeaponTag;
 use super::{base::BaseWeapon, equipped::Equipped};
 
 mod add;
+mod catalog;
 mod grouped;
 mod memo;
 mod name;
@@ -12,6 +13,7 @@ mod newtype;
 mod remove;
 
 pub use add::AddMundaneWeapon;
+pub use catalog::CoreRulebookMundaneWeapon;
 pub(crate) use grouped::{
     HandlessMundaneWeapon, HandlessMundaneWeaponMemo, NonnaturalMundaneWeapon,
     NonnaturalMundaneWeaponMemo,
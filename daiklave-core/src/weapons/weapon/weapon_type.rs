@@ -1,7 +1,7 @@
 use std::num::NonZeroU8;
 
 use crate::{
-    artifact::ArtifactName,
+    artifact::{ArtifactName, MagicMaterial},
     book_reference::{Book, BookReference},
     exaltation::exalt::essence::{MoteCommitment, MoteCommitmentName},
     hearthstones::hearthstone::Hearthstone,
@@ -85,9 +85,9 @@ impl<'view, 'source> WeaponType<'source> {
 
     pub fn book_reference(&self) -> Option<BookReference> {
         match self {
-            WeaponType::Mundane(_, mundane, _) => mundane.book_reference,
-            WeaponType::Artifact(_, artifact, _) => artifact.book_reference,
-            WeaponType::Unarmed => Some(BookReference::new(Book::CoreRulebook, 582)),
+            WeaponType::Mundane(_, mundane, _) => mundane.book_reference.clone(),
+            WeaponType::Artifact(_, artifact, _) => artifact.book_reference.clone(),
+            WeaponType::Unarmed => Some(BookReference::published(Book::CoreRulebook, 582)),
         }
     }
 
@@ -113,6 +113,13 @@ impl<'view, 'source> WeaponType<'source> {
         }
     }
 
+    pub fn material(&self) -> Option<MagicMaterial> {
+        match self {
+            WeaponType::Mundane(_, _, _) | WeaponType::Unarmed => None,
+            WeaponType::Artifact(_, artifact, _) => Some(artifact.magic_material),
+        }
+    }
+
     pub fn hearthstone_slots(&self) -> u8 {
         match self {
             WeaponType::Mundane(_, _, _) | WeaponType::Unarmed => 0,
@@ -0,0 +1,27 @@
+use daiklave_core::{
+    abilities::AbilityName, exaltation::exalt::exalt_type::solar::caste::EclipseAbility,
+    mutations::SetSolar, CharacterEvent, CharacterEventSource,
+};
+
+/// A newly created Eclipse caste Solar.
+pub fn solar() -> CharacterEventSource {
+    let mut event_source = CharacterEventSource::default();
+    SetSolar::eclipse()
+        .caste_ability(EclipseAbility::Linguistics)
+        .caste_ability(EclipseAbility::Occult)
+        .caste_ability(EclipseAbility::Presence)
+        .caste_ability(EclipseAbility::Sail)
+        .caste_ability(EclipseAbility::Socialize)
+        .supernal_ability(EclipseAbility::Occult)
+        .favored_ability(AbilityName::Awareness)
+        .favored_ability(AbilityName::Brawl)
+        .favored_ability(AbilityName::Lore)
+        .favored_ability(AbilityName::Medicine)
+        .favored_ability(AbilityName::Performance)
+        .limit_trigger("Seeing the innocent suffer")
+        .build()
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
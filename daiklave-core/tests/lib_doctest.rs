@@ -104,7 +104,7 @@ fn lib_doctest_inner() -> Result<(), CharacterMutationError> {
 
     // Add a martial arts style and give it some dots
     AddMartialArtsStyle::name("Single Point Shining Into the Void Style")
-        .book_reference(BookReference::new(Book::CoreRulebook, 434))
+        .book_reference(BookReference::new(Book::CoreRulebook, 434).unwrap())
         .description(
             "Single Point Shining Into the Void is a sword style that \
     emphasizes blinding speed and deadly-perfect finishing \
@@ -147,7 +147,7 @@ fn lib_doctest_inner() -> Result<(), CharacterMutationError> {
     // Add Sorcery
     AddSorcery::terrestrial_circle()
         .archetype_name("Pact with an Ifrit Lord")
-        .book_reference(BookReference::new(Book::CoreRulebook, 467))
+        .book_reference(BookReference::new(Book::CoreRulebook, 467).unwrap())
         .description("You have stood in the court of one of the ifrits or another elemental lord of fire[...]")
         .shaping_ritual_summary("Gain motes by extinguishing flames")
         .description("Whenever the sorcerer takes a shape sorcery action, she \
@@ -155,7 +155,7 @@ fn lib_doctest_inner() -> Result<(), CharacterMutationError> {
             any fire within medium range, coaxing its power into her \
             spell[...]")
         .control_spell_name("Cirrus Skiff")
-        .book_reference(BookReference::new(Book::CoreRulebook, 471))
+        .book_reference(BookReference::new(Book::CoreRulebook, 471).unwrap())
         .sorcerous_motes(NonZeroU8::new(15).unwrap())
         .willpower(NonZeroU8::new(1).unwrap())
         .duration("Until ended")
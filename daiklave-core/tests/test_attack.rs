@@ -0,0 +1,162 @@
+use daiklave_core::{
+    combat::{
+        attack::{Attack, AttackProfile, AttackStep, AttackType, Defense, DefenseProfile},
+        AddCombatant, Combat, CombatError, CombatMutation,
+    },
+    health::DamageLevel,
+    weapons::weapon::{AttackRange, RangeBand, WeaponName},
+    Character, CharacterEventSource,
+};
+
+fn roller(rolls: Vec<Vec<u8>>) -> impl FnMut(u8) -> Vec<u8> {
+    let mut rolls = rolls.into_iter();
+    move |dice: u8| {
+        let roll = rolls.next().unwrap();
+        assert_eq!(roll.len(), dice as usize);
+        roll
+    }
+}
+
+fn started_combat(character: &Character) -> Combat {
+    let mut combat = Combat::default();
+    for mutation in [
+        AddCombatant::character(character).into(),
+        AddCombatant::npc("Bandit", 4).into(),
+        CombatMutation::JoinBattle {
+            name: character.name().to_owned(),
+            successes: 5,
+        },
+        CombatMutation::JoinBattle {
+            name: "Bandit".to_owned(),
+            successes: 1,
+        },
+        CombatMutation::StartCombat,
+    ] {
+        combat.apply_mutation(&mutation).unwrap();
+    }
+    combat
+}
+
+fn punch(character: &Character, attack_type: AttackType, defense: Defense) -> Attack {
+    Attack::new(
+        AttackProfile::character(character, WeaponName::Unarmed, None, AttackRange::Melee).unwrap(),
+        DefenseProfile::npc("Bandit", 2, 1, 3, 0),
+        attack_type,
+        defense,
+    )
+}
+
+#[test]
+fn test_attack_profile() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+
+    // Unarmed: Dexterity 1 + Brawl 0 + accuracy 4; Strength 1 + damage 7
+    let punch = AttackProfile::character(&character, WeaponName::Unarmed, None, AttackRange::Melee)
+        .unwrap();
+    assert_eq!(punch.withering_pool(), 5);
+    assert_eq!(punch.decisive_pool(), 1);
+    assert_eq!(punch.damage(), 8);
+    assert!(matches!(
+        AttackProfile::character(
+            &character,
+            WeaponName::Unarmed,
+            None,
+            AttackRange::Ranged(RangeBand::Short)
+        ),
+        Err(CombatError::OutOfRange)
+    ));
+    assert!(matches!(
+        AttackProfile::character(
+            &character,
+            WeaponName::Mundane("Straight Sword"),
+            None,
+            AttackRange::Melee
+        ),
+        Err(CombatError::WeaponNotFound)
+    ));
+}
+
+#[test]
+fn test_attack_withering() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+    let mut combat = started_combat(&character);
+
+    // 4 successes (10s double) vs Parry 2, raw damage 8 + 2, minus 3 soak,
+    // rolls 5 successes and crashes the bandit
+    let withering = punch(&character, AttackType::Withering, Defense::Parry)
+        .resolve(
+            &combat,
+            &mut roller(vec![vec![10, 8, 7, 2, 1], vec![7, 7, 7, 7, 7, 1, 1]]),
+        )
+        .unwrap();
+    assert!(withering.is_hit());
+    assert!(withering.steps().any(|step| *step
+        == AttackStep::Soak {
+            soak: 3,
+            post_soak: 7
+        }));
+    assert!(withering.steps().any(|step| *step == AttackStep::Crash));
+    assert!(withering.damage().is_none());
+    for mutation in withering.combat_mutations() {
+        combat.apply_mutation(mutation).unwrap();
+    }
+    assert_eq!(combat.combatant("Bandit").unwrap().initiative(), Some(-1));
+    // 8 + 5 Initiative Break + 6 from the attack
+    assert_eq!(combat.combatant(hero).unwrap().initiative(), Some(19));
+
+    // The first attack's onslaught lowers Parry to 1; a miss deals no damage
+    let miss = punch(&character, AttackType::Withering, Defense::Parry)
+        .resolve(&combat, &mut roller(vec![vec![6, 5, 4, 3, 2]]))
+        .unwrap();
+    assert!(!miss.is_hit());
+    assert!(miss
+        .steps()
+        .any(|step| *step == AttackStep::Miss { defense: 1 }));
+}
+
+#[test]
+fn test_attack_decisive() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+    let mut combat = started_combat(&character);
+
+    // 8 Initiative dice vs Hardness 0, unarmed deals bashing
+    let decisive = punch(&character, AttackType::Decisive, Defense::Evasion)
+        .resolve(
+            &combat,
+            &mut roller(vec![vec![9], vec![10, 9, 8, 7, 1, 1, 1, 1]]),
+        )
+        .unwrap();
+    let damage = decisive.damage().unwrap();
+    assert_eq!(damage.level, DamageLevel::Bashing);
+    assert_eq!(damage.amount.get(), 4);
+    for mutation in decisive.combat_mutations() {
+        combat.apply_mutation(mutation).unwrap();
+    }
+    assert_eq!(combat.combatant(hero).unwrap().initiative(), Some(3));
+
+    // A missed decisive attack costs Initiative
+    let miss = punch(&character, AttackType::Decisive, Defense::Parry)
+        .resolve(&combat, &mut roller(vec![vec![1]]))
+        .unwrap();
+    assert!(miss
+        .steps()
+        .any(|step| *step == AttackStep::InitiativeLost(2)));
+
+    // Crashed combatants can't make decisive attacks
+    combat
+        .apply_mutation(&CombatMutation::SetInitiative {
+            name: hero.to_owned(),
+            initiative: 0,
+        })
+        .unwrap();
+    assert!(matches!(
+        punch(&character, AttackType::Decisive, Defense::Evasion)
+            .resolve(&combat, &mut roller(vec![])),
+        Err(CombatError::Crashed)
+    ));
+}
@@ -0,0 +1,167 @@
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    battle_group::{
+        BattleGroup, BattleGroupError, BattleGroupMutation, BattleGroupStatblock, Drill,
+    },
+    health::{DamageLevel, HealDamage, TakeDamage},
+};
+
+fn legionnaires() -> BattleGroup {
+    BattleGroupStatblock::name("Imperial Legionnaires")
+        .attack("Spear", 7, 12)
+        .action_pool("Senses", 4)
+        .parry(3)
+        .evasion(2)
+        .soak(7)
+        .willpower(5)
+        .battle_group(2, Drill::Average, 1)
+        .unwrap()
+}
+
+fn damage(level: DamageLevel, amount: u8) -> BattleGroupMutation {
+    BattleGroupMutation::TakeDamage(TakeDamage {
+        level,
+        amount: NonZeroU8::new(amount).unwrap(),
+    })
+}
+
+#[test]
+fn test_battle_group_traits() {
+    // Size, Might, and Drill modify pools and defenses
+    let battle_group = legionnaires();
+    assert_eq!(battle_group.attack("Spear"), Some((10, 15)));
+    assert_eq!(battle_group.action_pool("Senses"), Some(4));
+    assert_eq!(battle_group.parry(), 4);
+    assert_eq!(battle_group.evasion(), 3);
+    assert_eq!(battle_group.soak(), 9);
+
+    // Magnitude is a typical member's health levels plus Size
+    assert_eq!(battle_group.max_magnitude(), 9);
+    assert_eq!(battle_group.magnitude(), 9);
+    assert!(battle_group.rout_check().is_none());
+
+    // Bonuses cap out rather than overflowing
+    let giants = BattleGroupStatblock::name("Giants")
+        .attack("Club", 254, 255)
+        .battle_group(5, Drill::Elite, 3)
+        .unwrap();
+    assert_eq!(giants.attack("Club"), Some((255, 255)));
+    assert_eq!(giants.attacks().next(), Some(("Club", 255, 255)));
+
+    // And so does Magnitude, however many health levels members have
+    let health = serde_json::from_value(serde_json::json!({
+        "zero_boxes": 255,
+        "minus_one_boxes": 255,
+        "minus_two_boxes": 255,
+        "minus_four_boxes": 0,
+        "incapacitated_boxes": 1,
+        "bashing_damage": 0,
+        "lethal_damage": 0,
+        "aggravated_damage": 0,
+    }))
+    .unwrap();
+    let behemoths = BattleGroupStatblock::name("Behemoths")
+        .health(health)
+        .battle_group(5, Drill::Poor, 0)
+        .unwrap();
+    assert_eq!(behemoths.max_magnitude(), 255);
+
+    assert!(matches!(
+        BattleGroupStatblock::name("Mob").battle_group(6, Drill::Poor, 0),
+        Err(BattleGroupError::InvalidSize)
+    ));
+    assert!(matches!(
+        BattleGroupStatblock::name("Mob").battle_group(1, Drill::Poor, 4),
+        Err(BattleGroupError::InvalidMight)
+    ));
+}
+
+#[test]
+fn test_battle_group_magnitude() {
+    let mut battle_group = legionnaires();
+
+    // Damage reduces Magnitude; dropping to half prompts a rout check
+    battle_group
+        .apply_mutation(&damage(DamageLevel::Lethal, 5))
+        .unwrap();
+    assert_eq!(battle_group.magnitude(), 4);
+    let rout_check = battle_group.rout_check().unwrap();
+    assert_eq!(rout_check.pool, 5);
+    assert_eq!(rout_check.difficulty, 2);
+    battle_group
+        .apply_mutation(&BattleGroupMutation::ResolveRoutCheck(2))
+        .unwrap();
+    assert!(!battle_group.is_routed());
+    assert!(matches!(
+        battle_group.apply_mutation(&BattleGroupMutation::ResolveRoutCheck(2)),
+        Err(BattleGroupError::NoRoutCheck)
+    ));
+
+    // Healing restores Magnitude, but not more than the maximum
+    battle_group
+        .apply_mutation(&BattleGroupMutation::HealDamage(HealDamage(
+            NonZeroU8::new(2).unwrap(),
+        )))
+        .unwrap();
+    assert_eq!(battle_group.magnitude(), 6);
+    battle_group
+        .apply_mutation(&BattleGroupMutation::HealDamage(HealDamage(
+            NonZeroU8::new(200).unwrap(),
+        )))
+        .unwrap();
+    assert_eq!(battle_group.magnitude(), 9);
+
+    // Exhausting Magnitude breaks the group down by one Size
+    battle_group
+        .apply_mutation(&damage(DamageLevel::Bashing, 11))
+        .unwrap();
+    assert_eq!(battle_group.size(), 1);
+    assert_eq!(battle_group.max_magnitude(), 8);
+    assert_eq!(battle_group.magnitude(), 6);
+    assert_eq!(battle_group.attack("Spear"), Some((9, 14)));
+}
+
+#[test]
+fn test_battle_group_rout() {
+    let mut battle_group = legionnaires();
+    battle_group
+        .apply_mutation(&damage(DamageLevel::Lethal, 9))
+        .unwrap();
+
+    // Failing a rout check routs the group, until it is rallied
+    battle_group
+        .apply_mutation(&BattleGroupMutation::ResolveRoutCheck(0))
+        .unwrap();
+    assert!(battle_group.is_routed());
+    battle_group
+        .apply_mutation(&BattleGroupMutation::Rally)
+        .unwrap();
+    assert!(!battle_group.is_routed());
+}
+
+#[test]
+fn test_battle_group_broken() {
+    // Losing the last dot of Size breaks the group
+    let mut battle_group = legionnaires();
+    battle_group
+        .apply_mutation(&damage(DamageLevel::Lethal, 20))
+        .unwrap();
+    assert!(battle_group.is_broken());
+    assert_eq!(battle_group.magnitude(), 0);
+
+    // A broken group is destroyed, and can't be restored by any mutation
+    for mutation in [
+        damage(DamageLevel::Bashing, 1),
+        BattleGroupMutation::HealDamage(HealDamage(NonZeroU8::new(1).unwrap())),
+        BattleGroupMutation::SetSize(NonZeroU8::new(3).unwrap()),
+        BattleGroupMutation::Rally,
+    ] {
+        assert!(matches!(
+            battle_group.apply_mutation(&mutation),
+            Err(BattleGroupError::Defeated)
+        ));
+    }
+    assert!(battle_group.is_broken());
+    assert_eq!(battle_group.size(), 1);
+}
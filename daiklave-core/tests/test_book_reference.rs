@@ -0,0 +1,71 @@
+use daiklave_core::book_reference::{Book, BookReference, BookReferenceError};
+
+#[test]
+fn test_book_reference_published() {
+    let reference = BookReference::new(Book::LunarsFangsAtTheGate, 120).unwrap();
+    assert_eq!(reference.book(), Book::LunarsFangsAtTheGate);
+    assert_eq!(reference.page_number(), 120);
+    assert_eq!(reference.homebrew_source(), None);
+
+    assert!(matches!(
+        BookReference::new(Book::CoreRulebook, 0),
+        Err(BookReferenceError::PageOutOfRange)
+    ));
+    assert!(matches!(
+        BookReference::new(Book::CoreRulebook, 9999),
+        Err(BookReferenceError::PageOutOfRange)
+    ));
+    assert!(matches!(
+        BookReference::new(Book::Homebrew, 1),
+        Err(BookReferenceError::EmptySource)
+    ));
+}
+
+#[test]
+fn test_book_reference_homebrew() {
+    let homebrew = BookReference::homebrew("Tabletop Wiki", 9999).unwrap();
+    assert_eq!(homebrew.book(), Book::Homebrew);
+    assert_eq!(homebrew.homebrew_source(), Some("Tabletop Wiki"));
+
+    assert!(matches!(
+        BookReference::homebrew("Tabletop Wiki", -1),
+        Err(BookReferenceError::PageOutOfRange)
+    ));
+    assert!(matches!(
+        BookReference::homebrew("", 1),
+        Err(BookReferenceError::EmptySource)
+    ));
+}
+
+#[test]
+fn test_book_reference_serde() {
+    // Anything that can be constructed can be loaded again
+    for reference in [
+        BookReference::homebrew("Tabletop Wiki", 12).unwrap(),
+        BookReference::new(Book::CoreRulebook, 1).unwrap(),
+        BookReference::new(Book::LunarsFangsAtTheGate, 120).unwrap(),
+    ] {
+        let json = serde_json::to_string(&reference).unwrap();
+        assert_eq!(
+            serde_json::from_str::<BookReference>(&json).unwrap(),
+            reference
+        );
+    }
+
+    // References saved before homebrew sources existed still load
+    assert_eq!(
+        serde_json::from_str::<BookReference>(r#"{"book":"CoreRulebook","page_number":434}"#)
+            .unwrap(),
+        BookReference::new(Book::CoreRulebook, 434).unwrap()
+    );
+
+    // Deserialization is validated the same way as new
+    assert!(
+        serde_json::from_str::<BookReference>(r#"{"book":"CoreRulebook","page_number":9999}"#)
+            .is_err()
+    );
+    assert!(serde_json::from_str::<BookReference>(
+        r#"{"book":"Homebrew","page_number":1,"homebrew_source":""}"#
+    )
+    .is_err());
+}
@@ -0,0 +1,101 @@
+use daiklave_core::{
+    armor::armor_item::{
+        artifact::{AddBaseArtifactArmor, CoreRulebookBaseArtifactArmor},
+        mundane::{AddMundaneArmor, CoreRulebookMundaneArmor},
+        ArmorName, ArmorTag, ArmorWeightClass,
+    },
+    book_reference::Book,
+    weapons::weapon::{
+        artifact::{AddBaseArtifactWeapon, CoreRulebookBaseArtifactWeapon},
+        mundane::{AddMundaneWeapon, CoreRulebookMundaneWeapon},
+        AttackRange, RangeBand, WeaponName, WeaponTag, WeaponWeightClass,
+    },
+    CharacterEvent, CharacterEventSource,
+};
+
+#[test]
+fn test_catalog_entries() {
+    assert_eq!(AddMundaneWeapon::core_rulebook().count(), 27);
+    assert_eq!(AddBaseArtifactWeapon::core_rulebook().count(), 13);
+    assert_eq!(AddMundaneArmor::core_rulebook().count(), 7);
+    assert_eq!(AddBaseArtifactArmor::core_rulebook().count(), 6);
+
+    assert_eq!(CoreRulebookMundaneWeapon::Spear.name(), "Spear");
+    assert_eq!(
+        CoreRulebookMundaneWeapon::GreatSword
+            .book_reference()
+            .page_number(),
+        584
+    );
+    assert_eq!(
+        CoreRulebookBaseArtifactWeapon::Shield.name(),
+        "Shield (Artifact)"
+    );
+    assert_eq!(
+        CoreRulebookBaseArtifactArmor::SilkenArmor
+            .book_reference()
+            .page_number(),
+        600
+    );
+}
+
+#[test]
+fn test_catalog_weapons() {
+    let mut event_source = CharacterEventSource::default();
+    for weapon in [
+        CoreRulebookMundaneWeapon::StraightSword,
+        CoreRulebookMundaneWeapon::Spear,
+        CoreRulebookMundaneWeapon::LongBow,
+    ] {
+        AddMundaneWeapon::from_core_rulebook(weapon)
+            .apply_event(&mut event_source)
+            .unwrap();
+    }
+    let character = event_source.as_character().unwrap();
+
+    let sword = character
+        .weapons()
+        .get(WeaponName::Mundane("Straight Sword"), None)
+        .unwrap();
+    assert_eq!(sword.weight_class(), WeaponWeightClass::Medium);
+    assert!(sword.is_one_handed());
+    assert!(sword.tags().any(|tag| tag == WeaponTag::Balanced));
+    assert_eq!(sword.book_reference().unwrap().book(), Book::CoreRulebook);
+    assert!(sword.accuracy(AttackRange::Melee).is_some());
+
+    let spear = character
+        .weapons()
+        .get(WeaponName::Mundane("Spear"), None)
+        .unwrap();
+    assert!(spear.is_one_handed());
+    assert!(spear.tags().any(|tag| tag == WeaponTag::Reaching));
+    assert!(spear
+        .tags()
+        .any(|tag| tag == WeaponTag::Thrown(RangeBand::Short)));
+
+    let bow = character
+        .weapons()
+        .get(WeaponName::Mundane("Long Bow"), None)
+        .unwrap();
+    assert!(bow.is_two_handed());
+    assert!(bow.tags().any(|tag| tag == WeaponTag::Powerful));
+    assert!(bow
+        .tags()
+        .any(|tag| tag == WeaponTag::Archery(RangeBand::Extreme)));
+    assert!(bow.accuracy(AttackRange::Melee).is_none());
+}
+
+#[test]
+fn test_catalog_armor() {
+    let mut event_source = CharacterEventSource::default();
+    let character = AddMundaneArmor::from_core_rulebook(CoreRulebookMundaneArmor::ChainShirt)
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    let shirt = character
+        .armor()
+        .get(ArmorName::Mundane("Chain Shirt"))
+        .unwrap();
+    assert_eq!(shirt.weight_class(), ArmorWeightClass::Light);
+    assert!(shirt.tags().any(|tag| tag == ArmorTag::Concealable));
+}
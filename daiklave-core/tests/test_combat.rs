@@ -0,0 +1,152 @@
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    combat::{AddCombatant, Combat, CombatError, CombatMutation, CombatantKind},
+    Character, CharacterEventSource,
+};
+
+fn join_battle(name: &str, successes: u8) -> CombatMutation {
+    CombatMutation::JoinBattle {
+        name: name.to_owned(),
+        successes,
+    }
+}
+
+fn started_combat(character: &Character) -> Combat {
+    let mut combat = Combat::default();
+    for mutation in [
+        AddCombatant::character(character).into(),
+        AddCombatant::npc("Bandit", 4).into(),
+        AddCombatant::npc("Bandit Chief", 7).into(),
+        join_battle(character.name(), 2),
+        join_battle("Bandit", 1),
+        join_battle("Bandit Chief", 4),
+        CombatMutation::StartCombat,
+    ] {
+        combat.apply_mutation(&mutation).unwrap();
+    }
+    combat
+}
+
+#[test]
+fn test_combat_join_battle() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+
+    let mut combat = Combat::default();
+    combat
+        .apply_mutation(&AddCombatant::npc("Bandit", 4).into())
+        .unwrap();
+    assert!(matches!(
+        combat.apply_mutation(&AddCombatant::npc("Bandit", 4).into()),
+        Err(CombatError::DuplicateCombatant)
+    ));
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::StartCombat),
+        Err(CombatError::NotJoined)
+    ));
+    combat.apply_mutation(&join_battle("Bandit", 1)).unwrap();
+    assert!(matches!(
+        combat.apply_mutation(&join_battle("Bandit", 1)),
+        Err(CombatError::AlreadyJoined)
+    ));
+    assert!(matches!(
+        combat.apply_mutation(&join_battle("Bandit Chief", 1)),
+        Err(CombatError::NotFound)
+    ));
+
+    // Initiative is Join Battle successes + 3; turn order is by Initiative
+    let combat = started_combat(&character);
+    assert_eq!(combat.round(), 1);
+    let order = combat
+        .turn_order()
+        .map(|combatant| combatant.name())
+        .collect::<Vec<&str>>();
+    assert_eq!(order, vec!["Bandit Chief", character.name(), "Bandit"]);
+    let hero = combat.combatant(character.name()).unwrap();
+    assert_eq!(hero.kind(), CombatantKind::Character);
+    assert_eq!(hero.initiative(), Some(5));
+    assert_eq!(hero.join_battle_pool(), 1);
+}
+
+#[test]
+fn test_combat_initiative() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let mut combat = started_combat(&character);
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::StartCombat),
+        Err(CombatError::AlreadyStarted)
+    ));
+
+    // Spending Initiative for a Charm can't exceed current Initiative
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::SpendInitiative {
+            name: "Bandit".to_owned(),
+            amount: NonZeroU8::new(5).unwrap(),
+        }),
+        Err(CombatError::InsufficientInitiative)
+    ));
+
+    // Crashing a higher-Initiative opponent grants Initiative Break and an
+    // Initiative Shift
+    combat
+        .apply_mutation(&CombatMutation::EndTurn)
+        .unwrap()
+        .apply_mutation(&CombatMutation::LoseInitiative {
+            name: "Bandit Chief".to_owned(),
+            amount: NonZeroU8::new(8).unwrap(),
+            attacker: Some(character.name().to_owned()),
+        })
+        .unwrap();
+    assert!(combat.combatant("Bandit Chief").unwrap().is_crashed());
+    let hero = combat.combatant(character.name()).unwrap();
+    assert_eq!(hero.initiative(), Some(10));
+    assert!(hero.initiative_shift_pending());
+
+    combat
+        .apply_mutation(&CombatMutation::ResolveInitiativeShift {
+            name: character.name().to_owned(),
+            successes: 3,
+        })
+        .unwrap();
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::ResolveInitiativeShift {
+            name: character.name().to_owned(),
+            successes: 3,
+        }),
+        Err(CombatError::NoInitiativeShift)
+    ));
+    combat.apply_mutation(&CombatMutation::EndTurn).unwrap();
+    assert_eq!(combat.current_turn().unwrap().name(), character.name());
+    assert_eq!(
+        combat.combatant(character.name()).unwrap().initiative(),
+        Some(13)
+    );
+
+    // A combatant who ends three turns in Crash resets to 3 Initiative
+    for _ in 0..13 {
+        combat.apply_mutation(&CombatMutation::EndTurn).unwrap();
+    }
+    assert_eq!(combat.round(), 5);
+    let chief = combat.combatant("Bandit Chief").unwrap();
+    assert!(!chief.is_crashed());
+    assert_eq!(chief.initiative(), Some(3));
+}
+
+#[test]
+fn test_combat_end() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let mut combat = started_combat(&character);
+
+    // Ending combat clears Initiative but keeps combatants
+    combat.apply_mutation(&CombatMutation::EndCombat).unwrap();
+    assert!(!combat.is_started());
+    assert_eq!(combat.combatants().count(), 3);
+    assert!(combat.combatant("Bandit").unwrap().initiative().is_none());
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::EndTurn),
+        Err(CombatError::NotStarted)
+    ));
+}
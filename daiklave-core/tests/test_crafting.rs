@@ -0,0 +1,278 @@
+use daiklave_core::{
+    abilities::{AbilityNameQualified, SetAbility},
+    artifact::AddArtifact,
+    craft::{
+        AbandonCraftProject, ClaimCraftedArtifact, CraftError, CraftProject, RollCraftProject,
+    },
+    extended_roll::ExtendedRollStatus,
+    CharacterEvent, CharacterEventSource, CharacterMutationError,
+};
+
+/// A character with 5 dots in Craft (Blacksmithing).
+fn blacksmith() -> CharacterEventSource {
+    let mut event_source = CharacterEventSource::default();
+    SetAbility::new(AbilityNameQualified::Craft("Blacksmithing"), 5)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+fn roll(name: &str, successes: u8) -> RollCraftProject {
+    RollCraftProject {
+        name: name.to_owned(),
+        successes,
+    }
+}
+
+fn wonder(name: &str, merit_dots: u8) -> AddArtifact {
+    AddArtifact::wonder_builder(name)
+        .merit_dots(merit_dots)
+        .powers("Burns with the light of the Unconquered Sun")
+        .build()
+        .into()
+}
+
+fn claim(project: &str, artifact: AddArtifact) -> ClaimCraftedArtifact {
+    ClaimCraftedArtifact {
+        project: project.to_owned(),
+        artifact,
+    }
+}
+
+/// A blacksmith who has completed a rating 2 superior project.
+fn finished_sunfire_blade() -> CharacterEventSource {
+    let mut event_source = blacksmith();
+    CraftProject::superior("Sunfire Blade", "Blacksmithing", 2)
+        .unwrap()
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    roll("Sunfire Blade", 50)
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+#[test]
+fn test_crafting_start() {
+    // Need dots in the Craft focus to start a project
+    assert!(matches!(
+        CraftProject::basic("Iron pot", "Blacksmithing")
+            .build()
+            .apply_event(&mut CharacterEventSource::default()),
+        Err(CharacterMutationError::CraftError(CraftError::NoCraftDots))
+    ));
+
+    // Project names are unique
+    let mut event_source = blacksmith();
+    CraftProject::basic("Iron pot", "Blacksmithing")
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(matches!(
+        CraftProject::major("Iron pot", "Blacksmithing")
+            .build()
+            .apply_event(&mut event_source),
+        Err(CharacterMutationError::CraftError(
+            CraftError::DuplicateProject
+        ))
+    ));
+
+    // Superior projects are rated 2 to 5
+    assert!(matches!(
+        CraftProject::superior("Sword", "Blacksmithing", 6),
+        Err(CraftError::InvalidArtifactRating)
+    ));
+    let character = CraftProject::superior("Sunfire Blade", "Blacksmithing", 2)
+        .unwrap()
+        .terminus(3)
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    let extended_roll = character
+        .craft_project("Sunfire Blade")
+        .unwrap()
+        .extended_roll();
+    assert_eq!(extended_roll.goal(), 50);
+    assert_eq!(extended_roll.difficulty(), 5);
+    assert_eq!(extended_roll.terminus(), 3);
+    assert_eq!(extended_roll.interval_days(), 7);
+}
+
+#[test]
+fn test_crafting_basic() {
+    let mut event_source = blacksmith();
+    CraftProject::basic("Iron pot", "Blacksmithing")
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // Basic projects take one roll, and exceptional success earns extra
+    // silver experience
+    let character = roll("Iron pot", 4).apply_event(&mut event_source).unwrap();
+    let project = character.craft_project("Iron pot").unwrap();
+    assert_eq!(
+        project.extended_roll().status(),
+        ExtendedRollStatus::Completed
+    );
+    assert!(project.is_exceptional());
+    assert_eq!(character.experience().crafting()[0].current(), 3);
+    assert_eq!(
+        character.experience().entries().last().unwrap().reason(),
+        Some("Completed Iron pot")
+    );
+    assert!(matches!(
+        roll("Iron pot", 1).apply_event(&mut event_source),
+        Err(CharacterMutationError::CraftError(
+            CraftError::ProjectFinished
+        ))
+    ));
+
+    // Basic projects can't become artifacts
+    assert!(matches!(
+        claim("Iron pot", wonder("Iron pot", 2)).apply_event(&mut event_source),
+        Err(CharacterMutationError::CraftError(
+            CraftError::NotAnArtifactProject
+        ))
+    ));
+}
+
+#[test]
+fn test_crafting_extended() {
+    let mut event_source = blacksmith();
+    CraftProject::superior("Sunfire Blade", "Blacksmithing", 2)
+        .unwrap()
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // Rolls below the difficulty add nothing
+    roll("Sunfire Blade", 20)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = roll("Sunfire Blade", 4)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let project = character.craft_project("Sunfire Blade").unwrap();
+    assert_eq!(project.extended_roll().progress(), 20);
+    assert_eq!(
+        project.extended_roll().status(),
+        ExtendedRollStatus::InProgress
+    );
+
+    // Meeting the goal completes the project, awarding white experience
+    let character = roll("Sunfire Blade", 30)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(
+        character
+            .craft_project("Sunfire Blade")
+            .unwrap()
+            .extended_roll()
+            .status(),
+        ExtendedRollStatus::Completed
+    );
+    assert_eq!(character.experience().crafting()[2].current(), 3);
+
+    // A project that reaches its terminus fails
+    CraftProject::superior("Jade Hammer", "Blacksmithing", 3)
+        .unwrap()
+        .terminus(1)
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = roll("Jade Hammer", 10)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(
+        character
+            .craft_project("Jade Hammer")
+            .unwrap()
+            .extended_roll()
+            .status(),
+        ExtendedRollStatus::Failed
+    );
+    assert!(matches!(
+        roll("Missing", 10).apply_event(&mut event_source),
+        Err(CharacterMutationError::CraftError(
+            CraftError::ProjectNotFound
+        ))
+    ));
+}
+
+#[test]
+fn test_crafting_claim_artifact() {
+    // Can't claim an artifact before the project is complete
+    let mut event_source = blacksmith();
+    CraftProject::superior("Sunfire Blade", "Blacksmithing", 2)
+        .unwrap()
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(matches!(
+        claim("Sunfire Blade", wonder("Sunfire Blade", 2)).apply_event(&mut event_source),
+        Err(CharacterMutationError::CraftError(
+            CraftError::ProjectNotComplete
+        ))
+    ));
+
+    // The artifact must be rated the same as the project
+    let mut event_source = finished_sunfire_blade();
+    assert!(matches!(
+        claim("Sunfire Blade", wonder("Sunfire Blade", 5)).apply_event(&mut event_source),
+        Err(CharacterMutationError::CraftError(
+            CraftError::ArtifactRatingMismatch
+        ))
+    ));
+
+    // Claiming the artifact adds it and removes the project
+    let character = claim("Sunfire Blade", wonder("Sunfire Blade", 2))
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character.craft_project("Sunfire Blade").is_none());
+    assert!(character.wonders().get("Sunfire Blade").is_some());
+}
+
+#[test]
+fn test_crafting_claim_legendary() {
+    let mut event_source = blacksmith();
+    CraftProject::legendary("Daybreak", "Blacksmithing")
+        .terminus(1)
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    roll("Daybreak", 255)
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // Legendary projects only create N/A artifacts
+    assert!(matches!(
+        claim("Daybreak", wonder("Daybreak", 5)).apply_event(&mut event_source),
+        Err(CharacterMutationError::CraftError(
+            CraftError::ArtifactRatingMismatch
+        ))
+    ));
+    let character = claim("Daybreak", wonder("Daybreak", 6))
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character.wonders().get("Daybreak").is_some());
+}
+
+#[test]
+fn test_crafting_abandon() {
+    let mut event_source = finished_sunfire_blade();
+    let character = AbandonCraftProject("Sunfire Blade".to_owned())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.craft_projects().count(), 0);
+
+    // Abandoning doesn't take back awarded experience
+    assert_eq!(character.experience().crafting()[2].current(), 3);
+    assert!(matches!(
+        AbandonCraftProject("Sunfire Blade".to_owned()).apply_event(&mut event_source),
+        Err(CharacterMutationError::CraftError(
+            CraftError::ProjectNotFound
+        ))
+    ));
+}
@@ -1,3 +1,28 @@
+mod common;
+
+use std::num::{NonZeroU16, NonZeroU8};
+
+use daiklave_core::{
+    exaltation::exalt::essence::{
+        essence_for_experience, CommitMotes, MotePoolName, RecoverMotes, SetEssenceProgression,
+        SetEssenceRating, SpendMotes,
+    },
+    mutations::{GainExaltExperience, GainExperience, SpendExaltExperience, SpendExperience},
+    CharacterEvent, CharacterEventSource, CharacterMutationError,
+};
+
+/// A Solar with Essence progression enabled and 60 unspent experience.
+fn progressing_solar() -> CharacterEventSource {
+    let mut event_source = common::solar();
+    SetEssenceProgression(true)
+        .apply_event(&mut event_source)
+        .unwrap();
+    GainExperience(NonZeroU16::new(60).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
 #[test]
 fn test_essence() {
     // Mortals should not have essence
@@ -13,3 +38,164 @@ fn test_essence() {
     // Changing or lowering essence rating should end all mote commitments
     // and refill essence to full
 }
+
+#[test]
+fn test_recover_motes() {
+    assert!(matches!(
+        RecoverMotes(NonZeroU8::new(1).unwrap()).apply_event(&mut CharacterEventSource::default()),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+    let mut event_source = common::solar();
+    SpendMotes {
+        first: MotePoolName::Peripheral,
+        amount: NonZeroU8::new(36).unwrap(),
+    }
+    .apply_event(&mut event_source)
+    .unwrap();
+
+    // Recovery refills peripheral first, then personal
+    let character = RecoverMotes(NonZeroU8::new(35).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    let (peripheral, personal) = character
+        .essence()
+        .unwrap()
+        .motes()
+        .peripheral_and_personal();
+    assert_eq!((peripheral.available(), peripheral.spent()), (33, 0));
+    assert_eq!((personal.available(), personal.spent()), (12, 1));
+
+    // Can't recover more motes than have been spent
+    assert!(matches!(
+        RecoverMotes(NonZeroU8::new(2).unwrap()).apply_event(&mut event_source),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+    let character = RecoverMotes(NonZeroU8::new(1).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    let (_, personal) = character
+        .essence()
+        .unwrap()
+        .motes()
+        .peripheral_and_personal();
+    assert_eq!((personal.available(), personal.spent()), (13, 0));
+}
+
+#[test]
+fn test_essence_for_experience() {
+    assert_eq!(essence_for_experience(0).get(), 1);
+    assert_eq!(essence_for_experience(49).get(), 1);
+    assert_eq!(essence_for_experience(50).get(), 2);
+    assert_eq!(essence_for_experience(124).get(), 2);
+    assert_eq!(essence_for_experience(300).get(), 5);
+    assert_eq!(essence_for_experience(u16::MAX).get(), 5);
+}
+
+#[test]
+fn test_essence_progression_mortal() {
+    // Mortals may opt in, but have no expected Essence
+    let mut event_source = CharacterEventSource::default();
+    let character = SetEssenceProgression(true)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character.essence_progression());
+    assert!(character.expected_essence_rating().is_none());
+    assert!(character.essence_mismatch().is_none());
+}
+
+#[test]
+fn test_essence_progression_spend() {
+    let mut event_source = progressing_solar();
+    CommitMotes {
+        effect_name: "Indefinite Charm".into(),
+        first: MotePoolName::Peripheral,
+        amount: NonZeroU8::new(5).unwrap(),
+    }
+    .apply_event(&mut event_source)
+    .unwrap();
+
+    // Spending below the threshold doesn't raise Essence
+    let character = SpendExperience(NonZeroU16::new(40).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.essence().unwrap().rating(), 1);
+
+    // Crossing it does, keeping commitments and adding the new motes
+    let character = SpendExperience(NonZeroU16::new(10).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    let essence = character.essence().unwrap();
+    assert_eq!(essence.rating(), 2);
+    assert_eq!(essence.motes().peripheral().available(), 28 + 7);
+    assert_eq!(essence.motes().personal().available(), 13 + 3);
+    assert_eq!(essence.motes().committed().count(), 1);
+    assert!(character.essence_mismatch().is_none());
+
+    // Undoing the spend lowers it again
+    let character = event_source.undo().unwrap();
+    assert_eq!(character.essence().unwrap().rating(), 1);
+}
+
+#[test]
+fn test_essence_progression_exalt_experience() {
+    // Exalt experience counts toward the same thresholds
+    let mut event_source = progressing_solar();
+    SpendExperience(NonZeroU16::new(30).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    GainExaltExperience(NonZeroU16::new(20).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = SpendExaltExperience(NonZeroU16::new(20).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.expected_essence_rating().unwrap().get(), 2);
+    assert_eq!(character.essence().unwrap().rating(), 2);
+}
+
+#[test]
+fn test_essence_progression_opt_in() {
+    // Without progression, spending leaves Essence alone
+    let mut event_source = common::solar();
+    GainExperience(NonZeroU16::new(60).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = SpendExperience(NonZeroU16::new(50).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.essence().unwrap().rating(), 1);
+    assert!(character.essence_mismatch().is_none());
+
+    // Opting in catches up immediately
+    let character = SetEssenceProgression(true)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.essence().unwrap().rating(), 2);
+}
+
+#[test]
+fn test_essence_progression_mismatch() {
+    let mut event_source = progressing_solar();
+
+    // A manual override is allowed, but reported
+    let character = SetEssenceRating::dots(NonZeroU8::new(4).unwrap())
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    let mismatch = character.essence_mismatch().unwrap();
+    assert_eq!(mismatch.expected().get(), 1);
+    assert_eq!(mismatch.actual().get(), 4);
+
+    // Progression never lowers Essence
+    let character = SpendExperience(NonZeroU16::new(50).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.essence().unwrap().rating(), 4);
+    assert_eq!(character.essence_mismatch().unwrap().expected().get(), 2);
+
+    // Opting out silences the warning
+    let character = SetEssenceProgression(false)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character.essence_mismatch().is_none());
+}
@@ -0,0 +1,376 @@
+mod common;
+
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    armor::{
+        armor_item::{
+            artifact::{AddBaseArtifactArmor, ArtifactError, CoreRulebookBaseArtifactArmor},
+            mundane::{AddMundaneArmor, CoreRulebookMundaneArmor},
+            ArmorName,
+        },
+        ArmorError,
+    },
+    artifact::{AddArtifact, ArtifactName, MagicMaterial, RemoveArtifact},
+    charms::{
+        charm::{
+            evocation::{AddEvocation, EvokableName},
+            CharmName,
+        },
+        CharmActionType, CharmError,
+    },
+    exaltation::exalt::essence::{MotePoolName, SetEssenceRating},
+    hearthstones::HearthstoneError,
+    weapons::{
+        weapon::{
+            artifact::{AddBaseArtifactWeapon, CoreRulebookBaseArtifactWeapon},
+            mundane::{AddMundaneWeapon, CoreRulebookMundaneWeapon},
+            WeaponName,
+        },
+        WeaponError,
+    },
+    CharacterEvent, CharacterEventSource, CharacterMutationError,
+};
+
+/// A Solar with two orichalcum wonders: the Sunfire Blade, which must be
+/// attuned (but isn't yet), and the Sun Disk, which needs no attunement.
+fn wonder_bearer() -> CharacterEventSource {
+    let mut event_source = common::solar();
+    for (name, attunement_cost) in [("Sunfire Blade", Some(5)), ("Sun Disk", None)] {
+        let mut builder =
+            AddArtifact::wonder_builder(name).magic_material(MagicMaterial::Orichalcum);
+        if let Some(attunement_cost) = attunement_cost {
+            builder = builder.attunement_cost(attunement_cost);
+        }
+        AddArtifact::from(
+            builder
+                .merit_dots(3)
+                .powers("Burns with the light of the Unconquered Sun")
+                .build(),
+        )
+        .apply_event(&mut event_source)
+        .unwrap();
+    }
+    event_source
+}
+
+/// A wonder bearer who has attuned the Sunfire Blade and learned an
+/// Evocation from each wonder, the second building on the first.
+fn evoker() -> CharacterEventSource {
+    let mut event_source = wonder_bearer();
+    attune(ArtifactName::Wonder("Sunfire Blade"))
+        .apply_event(&mut event_source)
+        .unwrap();
+    evocation(wonder("Sunfire Blade"), "Radiant Edge", 1, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+    evocation(wonder("Sun Disk"), "Dawn's Herald", 1, Some("Radiant Edge"))
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+fn wonder(name: &str) -> EvokableName<'_> {
+    EvokableName::Artifact(ArtifactName::Wonder(name))
+}
+
+fn attune(name: ArtifactName<'_>) -> impl CharacterEvent<'_> + '_ {
+    name.attune(MotePoolName::Peripheral)
+}
+
+fn evocation(
+    evokable_name: EvokableName<'_>,
+    name: &str,
+    essence: u8,
+    prerequisite: Option<&str>,
+) -> AddEvocation {
+    let mut builder = evokable_name
+        .with_evocation(name)
+        .essence_required(NonZeroU8::new(essence).unwrap());
+    if let Some(prerequisite) = prerequisite {
+        builder = builder.evocation_prerequisite(prerequisite);
+    }
+    builder
+        .action_type(CharmActionType::Simple)
+        .duration("Instant".to_owned())
+        .description("Blazes with the light of the sun.".to_owned())
+        .build()
+}
+
+fn is_disabled(event_source: &CharacterEventSource, name: &str) -> bool {
+    event_source
+        .as_character()
+        .unwrap()
+        .charms()
+        .is_disabled(CharmName::Evocation(name))
+}
+
+#[test]
+fn test_evocation_requires_evokable() {
+    // Evocations require the artifact or hearthstone they are drawn from
+    let mut event_source = common::solar();
+    assert!(matches!(
+        evocation(wonder("Sunfire Blade"), "Radiant Edge", 1, None).apply_event(&mut event_source),
+        Err(CharacterMutationError::ArtifactError(
+            ArtifactError::NotFound
+        ))
+    ));
+    assert!(matches!(
+        evocation(
+            EvokableName::Artifact(ArtifactName::Weapon("Volcano Cutter")),
+            "Magma Kraken Form",
+            1,
+            None
+        )
+        .apply_event(&mut event_source),
+        Err(CharacterMutationError::WeaponError(WeaponError::NotFound))
+    ));
+    assert!(matches!(
+        evocation(
+            EvokableName::Artifact(ArtifactName::Armor("Brilliant Sentinel")),
+            "Sentinel's Glare",
+            1,
+            None
+        )
+        .apply_event(&mut event_source),
+        Err(CharacterMutationError::ArmorError(ArmorError::NotFound))
+    ));
+    assert!(matches!(
+        evocation(
+            EvokableName::Hearthstone("Gem of Dawn"),
+            "Dawn's Glow",
+            1,
+            None
+        )
+        .apply_event(&mut event_source),
+        Err(CharacterMutationError::HearthstoneError(
+            HearthstoneError::NotFound
+        ))
+    ));
+}
+
+#[test]
+fn test_evocation_requires_attunement() {
+    // Artifacts which require attunement must be attuned
+    let mut event_source = wonder_bearer();
+    assert!(matches!(
+        evocation(wonder("Sunfire Blade"), "Radiant Edge", 1, None).apply_event(&mut event_source),
+        Err(CharacterMutationError::CharmError(CharmError::NotAttuned))
+    ));
+    attune(ArtifactName::Wonder("Sunfire Blade"))
+        .apply_event(&mut event_source)
+        .unwrap();
+    evocation(wonder("Sunfire Blade"), "Radiant Edge", 1, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // Others don't
+    evocation(wonder("Sun Disk"), "Dawn's Herald", 1, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+}
+
+#[test]
+fn test_evocation_requires_essence() {
+    let mut event_source = wonder_bearer();
+    assert!(matches!(
+        evocation(wonder("Sun Disk"), "Burning Noon", 2, None).apply_event(&mut event_source),
+        Err(CharacterMutationError::CharmError(
+            CharmError::PrerequisitesNotMet
+        ))
+    ));
+}
+
+#[test]
+fn test_evocations_unattuned() {
+    let event_source = evoker();
+    let character = event_source.as_character().unwrap();
+    let wonders = character.wonders();
+    assert_eq!(
+        wonders
+            .get("Sunfire Blade")
+            .unwrap()
+            .evocations(&character)
+            .collect::<Vec<&str>>(),
+        vec!["Radiant Edge"]
+    );
+    assert_eq!(
+        wonders
+            .get("Sun Disk")
+            .unwrap()
+            .evocations(&character)
+            .collect::<Vec<&str>>(),
+        vec!["Dawn's Herald"]
+    );
+
+    // Changing Essence ends all attunements, disabling the artifact's
+    // Evocations and any which build on them
+    let mut event_source = evoker();
+    SetEssenceRating::dots(NonZeroU8::new(2).unwrap())
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(is_disabled(&event_source, "Radiant Edge"));
+    assert!(is_disabled(&event_source, "Dawn's Herald"));
+    let character = event_source.as_character().unwrap();
+    assert!(character
+        .charms()
+        .get(CharmName::Evocation("Radiant Edge"))
+        .is_some());
+    let wonders = character.wonders();
+    assert_eq!(
+        wonders
+            .get("Sunfire Blade")
+            .unwrap()
+            .evocations(&character)
+            .count(),
+        0
+    );
+    assert_eq!(
+        wonders
+            .get("Sun Disk")
+            .unwrap()
+            .evocations(&character)
+            .count(),
+        0
+    );
+
+    // Can't build on disabled Evocations
+    assert!(matches!(
+        evocation(wonder("Sun Disk"), "Noon Flare", 1, Some("Radiant Edge"))
+            .apply_event(&mut event_source),
+        Err(CharacterMutationError::CharmError(
+            CharmError::PrerequisitesNotMet
+        ))
+    ));
+
+    // Reattuning restores them
+    attune(ArtifactName::Wonder("Sunfire Blade"))
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(!is_disabled(&event_source, "Radiant Edge"));
+    assert!(!is_disabled(&event_source, "Dawn's Herald"));
+}
+
+#[test]
+fn test_evocations_lost_artifact() {
+    // Losing the artifact disables its Evocations without removing them
+    let mut event_source = evoker();
+    let character = RemoveArtifact::new(ArtifactName::Wonder("Sunfire Blade"))
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character
+        .charms()
+        .get(CharmName::Evocation("Radiant Edge"))
+        .is_some());
+    assert_eq!(
+        character
+            .wonders()
+            .get("Sun Disk")
+            .unwrap()
+            .evocations(&character)
+            .count(),
+        0
+    );
+    assert!(is_disabled(&event_source, "Radiant Edge"));
+    assert!(is_disabled(&event_source, "Dawn's Herald"));
+
+    // Regaining it restores them
+    event_source.undo().unwrap();
+    assert!(!is_disabled(&event_source, "Radiant Edge"));
+    assert!(!is_disabled(&event_source, "Dawn's Herald"));
+}
+
+#[test]
+fn test_evocations_weapon() {
+    let mut event_source = common::solar();
+    AddBaseArtifactWeapon::from_core_rulebook(CoreRulebookBaseArtifactWeapon::Daiklave)
+        .unique_name("Volcano Cutter")
+        .material(MagicMaterial::Orichalcum)
+        .merit_dots(3)
+        .hearthstone_slots(0)
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    AddMundaneWeapon::from_core_rulebook(CoreRulebookMundaneWeapon::StraightSword)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let volcano_cutter = EvokableName::Artifact(ArtifactName::Weapon("Volcano Cutter"));
+    assert!(matches!(
+        evocation(volcano_cutter, "Magma Kraken Form", 1, None).apply_event(&mut event_source),
+        Err(CharacterMutationError::CharmError(CharmError::NotAttuned))
+    ));
+    attune(ArtifactName::Weapon("Volcano Cutter"))
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = evocation(volcano_cutter, "Magma Kraken Form", 1, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    let weapons = character.weapons();
+    assert_eq!(
+        weapons
+            .get(WeaponName::Artifact("Volcano Cutter"), None)
+            .unwrap()
+            .evocations(&character)
+            .collect::<Vec<&str>>(),
+        vec!["Magma Kraken Form"]
+    );
+
+    // Mundane weapons have no Evocations
+    assert_eq!(
+        weapons
+            .get(WeaponName::Mundane("Straight Sword"), None)
+            .unwrap()
+            .evocations(&character)
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn test_evocations_armor() {
+    let mut event_source = common::solar();
+    AddBaseArtifactArmor::from_core_rulebook(CoreRulebookBaseArtifactArmor::Breastplate)
+        .unique_name("Brilliant Sentinel")
+        .material(MagicMaterial::Orichalcum)
+        .merit_dots(3)
+        .hearthstone_slots(0)
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    AddMundaneArmor::from_core_rulebook(CoreRulebookMundaneArmor::ChainShirt)
+        .apply_event(&mut event_source)
+        .unwrap();
+    attune(ArtifactName::Armor("Brilliant Sentinel"))
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = evocation(
+        EvokableName::Artifact(ArtifactName::Armor("Brilliant Sentinel")),
+        "Sentinel's Glare",
+        1,
+        None,
+    )
+    .apply_event(&mut event_source)
+    .unwrap();
+
+    let armor = character.armor();
+    assert_eq!(
+        armor
+            .get(ArmorName::Artifact("Brilliant Sentinel"))
+            .unwrap()
+            .evocations(&character)
+            .collect::<Vec<&str>>(),
+        vec!["Sentinel's Glare"]
+    );
+
+    // Mundane armor has no Evocations
+    assert_eq!(
+        armor
+            .get(ArmorName::Mundane("Chain Shirt"))
+            .unwrap()
+            .evocations(&character)
+            .count(),
+        0
+    );
+}
@@ -0,0 +1,189 @@
+mod common;
+
+use std::num::NonZeroU16;
+
+use daiklave_core::{
+    abilities::{AbilityNameQualified, AbilityNameVanilla, SetAbility},
+    experience::{
+        AwardSessionExperience, ExperienceEntry, ExperienceEntryKind, ExperienceError,
+        ExperiencePoolName, SessionAwardReason,
+    },
+    mutations::{GainExaltExperience, GainExperience, SpendExperience},
+    Character, CharacterEvent, CharacterEventSource, CharacterMemo, CharacterMutationError,
+};
+use serde_json::json;
+
+fn integrity(dots: u8) -> SetAbility {
+    SetAbility::new(
+        AbilityNameQualified::Vanilla(AbilityNameVanilla::Integrity),
+        dots,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_experience_session_award() {
+    let mut event_source = CharacterEventSource::default();
+
+    // Session awards are broken down by reason, one entry each
+    let character = AwardSessionExperience::new(1)
+        .standard(SessionAwardReason::Session)
+        .standard(SessionAwardReason::Roleplaying)
+        .award(
+            ExperiencePoolName::SilverCraft,
+            SessionAwardReason::Custom("Forged a sword".to_owned()),
+            NonZeroU16::new(2).unwrap(),
+        )
+        .at(1_700_000_000)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let experience = character.experience();
+    assert_eq!(experience.base().current(), 3);
+    assert_eq!(experience.crafting()[0].current(), 2);
+    assert_eq!(experience.session(1).count(), 3);
+    let entry = experience.entries().next().unwrap();
+    assert_eq!(entry.reason(), Some("Session"));
+    assert_eq!(entry.amount().get(), 2);
+    assert_eq!(entry.timestamp(), Some(1_700_000_000));
+}
+
+#[test]
+fn test_experience_exalt() {
+    // Mortals have no Exalt experience
+    let mut event_source = CharacterEventSource::default();
+    assert!(event_source
+        .as_character()
+        .unwrap()
+        .experience()
+        .exalt()
+        .is_none());
+    assert!(matches!(
+        GainExaltExperience(NonZeroU16::new(1).unwrap()).apply_event(&mut event_source),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+
+    // Exalts keep it in its own pool
+    let mut event_source = common::solar();
+    let character = GainExaltExperience(NonZeroU16::new(4).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.experience().exalt().unwrap().current(), 4);
+    assert_eq!(character.experience().base().current(), 0);
+}
+
+#[test]
+fn test_experience_unlinked_mutations() {
+    // The old mutations still work, as entries without reasons
+    let mut event_source = CharacterEventSource::default();
+    let character = GainExperience(NonZeroU16::new(10).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.experience().base().current(), 10);
+    assert!(character
+        .experience()
+        .entries()
+        .last()
+        .unwrap()
+        .reason()
+        .is_none());
+
+    let character = SpendExperience(NonZeroU16::new(4).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.experience().base().current(), 6);
+    assert!(matches!(
+        SpendExperience(NonZeroU16::new(7).unwrap()).apply_event(&mut event_source),
+        Err(CharacterMutationError::ExperienceError(
+            ExperienceError::InsufficientExperience
+        ))
+    ));
+}
+
+#[test]
+fn test_experience_linked_spend() {
+    let mut event_source = CharacterEventSource::default();
+    GainExperience(NonZeroU16::new(13).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // Spending can be linked to what it paid for, applying both together
+    let character = ExperienceEntry::spend(ExperiencePoolName::Base, NonZeroU16::new(6).unwrap())
+        .reason("Raised Integrity")
+        .session(2)
+        .on(integrity(2))
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(
+        character
+            .abilities()
+            .get(AbilityNameQualified::Vanilla(AbilityNameVanilla::Integrity))
+            .unwrap()
+            .dots(),
+        2
+    );
+    let pool = character.experience().base();
+    assert_eq!(pool.current(), 7);
+    assert_eq!(pool.total(), 13);
+    let entry = character.experience().entries().last().unwrap();
+    assert_eq!(entry.kind(), ExperienceEntryKind::Spend);
+    assert!(entry.linked_mutation().is_some());
+
+    // A failed spend doesn't apply its linked mutation
+    assert!(matches!(
+        ExperienceEntry::spend(ExperiencePoolName::Base, NonZeroU16::new(8).unwrap())
+            .on(integrity(3))
+            .build()
+            .apply_event(&mut event_source),
+        Err(CharacterMutationError::ExperienceError(
+            ExperienceError::InsufficientExperience
+        ))
+    ));
+    let character = event_source.as_character().unwrap();
+    assert_eq!(
+        character
+            .abilities()
+            .get(AbilityNameQualified::Vanilla(AbilityNameVanilla::Integrity))
+            .unwrap()
+            .dots(),
+        2
+    );
+    assert_eq!(character.experience().entries().count(), 2);
+}
+
+#[test]
+fn test_experience_legacy_memo() {
+    // A Solar saved before the ledger, with 8 experience (3 spent) and 6
+    // Solar Experience (2 spent)
+    let event_source = common::solar();
+    let character = event_source.as_character().unwrap();
+    let mut json = serde_json::to_value(CharacterMemo::from(character)).unwrap();
+    json["experience"] = json!({ "unspent": 5, "spent": 3 });
+    json["exaltation"]["Exalt"]["exalt_type"]["Solar"]["experience"] =
+        json!({ "unspent": 4, "spent": 2 });
+    let memo: CharacterMemo = serde_json::from_value(json).unwrap();
+
+    // Both pools become opening entries in the ledger
+    let character = Character::from(&memo);
+    let experience = character.experience();
+    assert_eq!(
+        (experience.base().current(), experience.base().total()),
+        (5, 8)
+    );
+    let exalt = experience.exalt().unwrap();
+    assert_eq!((exalt.current(), exalt.total()), (4, 6));
+    assert_eq!(experience.entries().count(), 4);
+    assert!(experience
+        .entries()
+        .all(|entry| entry.reason() == Some("Opening balance")));
+
+    // Saving again stores the ledger, which loads unchanged
+    let json = serde_json::to_value(CharacterMemo::from(character)).unwrap();
+    assert!(json["exaltation"]["Exalt"]["exalt_type"]["Solar"]["experience"].is_null());
+    let memo: CharacterMemo = serde_json::from_value(json).unwrap();
+    let character = Character::from(&memo);
+    let exalt = character.experience().exalt().unwrap();
+    assert_eq!((exalt.current(), exalt.total()), (4, 6));
+    assert_eq!(character.experience().base().total(), 8);
+    assert_eq!(character.experience().entries().count(), 4);
+}
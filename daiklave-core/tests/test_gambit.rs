@@ -0,0 +1,267 @@
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    combat::{
+        attack::{AttackProfile, AttackStep, Defense, DefenseProfile},
+        AddCombatant, Combat, CombatError, CombatMutation, CustomGambit, Gambit, GambitKind,
+        StatusName,
+    },
+    weapons::weapon::{
+        mundane::{AddMundaneWeapon, CoreRulebookMundaneWeapon},
+        Equipped, WeaponName,
+    },
+    Character, CharacterEvent, CharacterEventSource,
+};
+
+const SWORD: WeaponName<'static> = WeaponName::Mundane("Straight Sword");
+
+fn armed_hero() -> CharacterEventSource {
+    let mut event_source = CharacterEventSource::default();
+    AddMundaneWeapon::from_core_rulebook(CoreRulebookMundaneWeapon::StraightSword)
+        .apply_event(&mut event_source)
+        .unwrap();
+    SWORD
+        .equip_main_hand()
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+fn started_combat(character: &Character) -> Combat {
+    let mut combat = Combat::default();
+    for mutation in [
+        AddCombatant::character(character).into(),
+        AddCombatant::npc("Bandit", 4).into(),
+        AddCombatant::npc("Thug", 2).into(),
+        CombatMutation::JoinBattle {
+            name: character.name().to_owned(),
+            successes: 1,
+        },
+        CombatMutation::JoinBattle {
+            name: "Bandit".to_owned(),
+            successes: 7,
+        },
+        CombatMutation::JoinBattle {
+            name: "Thug".to_owned(),
+            successes: 0,
+        },
+        CombatMutation::StartCombat,
+    ] {
+        combat.apply_mutation(&mutation).unwrap();
+    }
+    combat
+}
+
+fn bandit_gambit(character: &Character, defense: Defense, kind: GambitKind) -> Gambit {
+    Gambit::new(
+        AttackProfile::npc("Bandit", 8, 3),
+        DefenseProfile::character(character, SWORD, Some(Equipped::MainHand)).unwrap(),
+        defense,
+        kind,
+    )
+}
+
+fn set_initiative(name: &str, initiative: i8) -> CombatMutation {
+    CombatMutation::SetInitiative {
+        name: name.to_owned(),
+        initiative,
+    }
+}
+
+fn hits(n: u8) -> Vec<u8> {
+    vec![10; n as usize]
+}
+
+#[test]
+fn test_gambit_disarm() {
+    let mut event_source = armed_hero();
+    let character = event_source.as_character().unwrap();
+    let mut combat = started_combat(&character);
+    let disarm = bandit_gambit(
+        &character,
+        Defense::Parry,
+        GambitKind::Disarm(SWORD.unequip(Equipped::MainHand).unwrap()),
+    );
+
+    // A missed disarm leaves the weapon in hand
+    let outcome = disarm
+        .resolve(&combat, &mut |n: u8| vec![1; n as usize])
+        .unwrap();
+    assert!(!outcome.is_hit());
+    assert!(outcome.disarm().is_none());
+
+    // A successful disarm spends difficulty 3 + 1 Initiative...
+    let outcome = disarm.resolve(&combat, &mut hits).unwrap();
+    assert!(outcome.steps().any(|step| matches!(
+        step,
+        AttackStep::Gambit {
+            difficulty: 3,
+            succeeded: true
+        }
+    )));
+    for mutation in outcome.combat_mutations() {
+        combat.apply_mutation(mutation).unwrap();
+    }
+    assert_eq!(combat.combatant("Bandit").unwrap().initiative(), Some(6));
+
+    // ...but the weapon stays equipped until the caller applies the disarm
+    // to the defending character
+    let unequip = outcome.disarm().unwrap().clone();
+    let character = event_source.as_character().unwrap();
+    assert!(character
+        .weapons()
+        .get(SWORD, Some(Equipped::MainHand))
+        .is_some());
+    let character = unequip.clone().apply_event(&mut event_source).unwrap();
+    assert!(character
+        .weapons()
+        .get(SWORD, Some(Equipped::MainHand))
+        .is_none());
+    assert!(character.weapons().get(SWORD, None).is_some());
+
+    // The disarm can't be applied twice
+    assert!(unequip.apply_event(&mut event_source).is_err());
+}
+
+#[test]
+fn test_gambit_distract() {
+    let event_source = armed_hero();
+    let character = event_source.as_character().unwrap();
+    let mut combat = started_combat(&character);
+
+    // A distraction grants the ally Initiative for each success over the
+    // difficulty
+    let distract = bandit_gambit(
+        &character,
+        Defense::Parry,
+        GambitKind::Distract {
+            ally: "Thug".to_owned(),
+        },
+    );
+    let outcome = distract.resolve(&combat, &mut hits).unwrap();
+    assert!(outcome.disarm().is_none());
+    for mutation in outcome.combat_mutations() {
+        combat.apply_mutation(mutation).unwrap();
+    }
+    assert_eq!(combat.combatant("Thug").unwrap().initiative(), Some(10));
+    assert_eq!(combat.combatant("Bandit").unwrap().initiative(), Some(6));
+
+    // The ally must be someone else in the fight
+    let distract = bandit_gambit(
+        &character,
+        Defense::Parry,
+        GambitKind::Distract {
+            ally: "Bandit".to_owned(),
+        },
+    );
+    assert!(matches!(
+        distract.resolve(&combat, &mut hits),
+        Err(CombatError::NotFound)
+    ));
+}
+
+#[test]
+fn test_gambit_unhorse() {
+    let event_source = armed_hero();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+    let mut combat = started_combat(&character);
+    let unhorse = bandit_gambit(&character, Defense::Evasion, GambitKind::Unhorse);
+
+    // A failed gambit still costs Initiative, which can crash the attacker
+    combat.apply_mutation(&set_initiative("Bandit", 2)).unwrap();
+    let outcome = unhorse.resolve(&combat, &mut hits).unwrap();
+    assert!(outcome.is_hit());
+    assert!(outcome.steps().any(|step| matches!(
+        step,
+        AttackStep::Gambit {
+            difficulty: 4,
+            succeeded: false
+        }
+    )));
+    for mutation in outcome.combat_mutations() {
+        combat.apply_mutation(mutation).unwrap();
+    }
+    assert!(combat.combatant("Bandit").unwrap().is_crashed());
+    assert!(combat
+        .combatant(hero)
+        .unwrap()
+        .status(StatusName::Prone)
+        .is_none());
+    assert!(matches!(
+        unhorse.resolve(&combat, &mut hits),
+        Err(CombatError::Crashed)
+    ));
+
+    // A successful unhorsing knocks the defender prone
+    combat.apply_mutation(&set_initiative("Bandit", 8)).unwrap();
+    for mutation in unhorse
+        .resolve(&combat, &mut hits)
+        .unwrap()
+        .combat_mutations()
+    {
+        combat.apply_mutation(mutation).unwrap();
+    }
+    assert!(combat
+        .combatant(hero)
+        .unwrap()
+        .status(StatusName::Prone)
+        .is_some());
+}
+
+#[test]
+fn test_gambit_custom() {
+    let event_source = armed_hero();
+    let character = event_source.as_character().unwrap();
+    let mut combat = started_combat(&character);
+
+    // The storyteller registers custom gambits with their own difficulty
+    let trip = CustomGambit::new(
+        "Sand in the Eyes",
+        NonZeroU8::new(2).unwrap(),
+        "The target cannot see until they spend an action wiping their eyes.",
+    );
+    let custom = bandit_gambit(
+        &character,
+        Defense::Evasion,
+        GambitKind::Custom("Sand in the Eyes".to_owned()),
+    );
+    assert!(matches!(
+        custom.resolve(&combat, &mut hits),
+        Err(CombatError::GambitNotFound)
+    ));
+    combat
+        .apply_mutation(&CombatMutation::AddCustomGambit(trip.clone()))
+        .unwrap();
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::AddCustomGambit(trip)),
+        Err(CombatError::DuplicateGambit)
+    ));
+    assert_eq!(
+        combat
+            .custom_gambit("Sand in the Eyes")
+            .unwrap()
+            .difficulty(),
+        2
+    );
+    let outcome = custom.resolve(&combat, &mut hits).unwrap();
+    assert!(outcome
+        .steps()
+        .any(|step| step == &AttackStep::InitiativeLost(3)));
+
+    // Custom gambits persist between combats until removed
+    combat
+        .apply_mutation(&CombatMutation::EndCombat)
+        .unwrap()
+        .apply_mutation(&CombatMutation::RemoveCustomGambit(
+            "Sand in the Eyes".to_owned(),
+        ))
+        .unwrap();
+    assert_eq!(combat.custom_gambits().count(), 0);
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::RemoveCustomGambit(
+            "Sand in the Eyes".to_owned(),
+        )),
+        Err(CombatError::GambitNotFound)
+    ));
+}
@@ -0,0 +1,345 @@
+use daiklave_core::{
+    combat::{
+        attack::{AttackProfile, AttackStep, Defense, DefenseProfile},
+        AddCombatant, Combat, CombatError, CombatMutation, GrappleAction, GrappleGambit,
+        StatusEffect, StatusName,
+    },
+    weapons::weapon::{RangeBand, WeaponName},
+    Character, CharacterEventSource,
+};
+
+fn started_combat(character: &Character) -> Combat {
+    let hero = character.name();
+    let mut combat = Combat::default();
+    for mutation in [
+        AddCombatant::character(character).into(),
+        AddCombatant::npc("Wrestler", 4).into(),
+        AddCombatant::npc("Bandit", 2).into(),
+        CombatMutation::JoinBattle {
+            name: "Wrestler".to_owned(),
+            successes: 5,
+        },
+        CombatMutation::JoinBattle {
+            name: hero.to_owned(),
+            successes: 2,
+        },
+        CombatMutation::JoinBattle {
+            name: "Bandit".to_owned(),
+            successes: 0,
+        },
+        CombatMutation::SetRange {
+            first: "Wrestler".to_owned(),
+            second: hero.to_owned(),
+            band: RangeBand::Close,
+        },
+        CombatMutation::SetRange {
+            first: "Wrestler".to_owned(),
+            second: "Bandit".to_owned(),
+            band: RangeBand::Medium,
+        },
+        CombatMutation::StartCombat,
+    ] {
+        combat.apply_mutation(&mutation).unwrap();
+    }
+    combat
+}
+
+/// A combat where the Wrestler already holds the hero, on the Wrestler's
+/// turn.
+fn grappled(character: &Character, control_rounds: u8) -> Combat {
+    let mut combat = started_combat(character);
+    combat
+        .apply_mutation(&CombatMutation::AddStatus {
+            name: character.name().to_owned(),
+            status: StatusEffect::Clinched {
+                controller: "Wrestler".to_owned(),
+                control_rounds,
+            },
+        })
+        .unwrap();
+    combat
+}
+
+fn grapple(action: GrappleAction) -> CombatMutation {
+    CombatMutation::Grapple {
+        name: "Wrestler".to_owned(),
+        action,
+    }
+}
+
+fn wrestler_gambit(character: &Character) -> GrappleGambit {
+    GrappleGambit::new(
+        AttackProfile::npc("Wrestler", 6, 4),
+        DefenseProfile::character(character, WeaponName::Unarmed, None).unwrap(),
+        Defense::Evasion,
+        8,
+        GrappleGambit::resist_pool(character),
+    )
+}
+
+#[test]
+fn test_grapple_pools() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+
+    // Strength 1 + Brawl 0; the higher of Strength or Dexterity + Brawl 0
+    assert_eq!(GrappleGambit::control_pool(&character), 1);
+    assert_eq!(GrappleGambit::resist_pool(&character), 1);
+}
+
+#[test]
+fn test_grapple_gambit() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+    let mut combat = started_combat(&character);
+    let gambit = wrestler_gambit(&character);
+
+    // Can't grapple someone out of reach
+    let far = GrappleGambit::new(
+        AttackProfile::npc("Wrestler", 6, 4),
+        DefenseProfile::npc("Bandit", 2, 2, 1, 0),
+        Defense::Parry,
+        8,
+        2,
+    );
+    assert!(matches!(
+        far.resolve(&combat, &mut |n: u8| vec![10; n as usize]),
+        Err(CombatError::OutOfRange)
+    ));
+
+    // A missed gambit costs Initiative like a missed decisive attack
+    let outcome = gambit
+        .resolve(&combat, &mut |n: u8| vec![1; n as usize])
+        .unwrap();
+    assert!(!outcome.is_hit());
+    assert!(outcome
+        .steps()
+        .any(|step| step == &AttackStep::InitiativeLost(2)));
+
+    // Attack roll hits; Initiative roll of 8 dice gets 3 successes against
+    // difficulty 2; control roll of 8 dice gets 4 against 1 die resisting
+    let mut rolls = vec![
+        vec![10, 10, 10, 1, 1, 1],
+        vec![7, 7, 7, 1, 1, 1, 1, 1],
+        vec![8, 8, 8, 8, 1, 1, 1, 1],
+        vec![1],
+    ]
+    .into_iter();
+    let outcome = gambit
+        .resolve(&combat, &mut |_: u8| rolls.next().unwrap())
+        .unwrap();
+    assert!(outcome.is_hit());
+    assert!(outcome.steps().any(|step| matches!(
+        step,
+        AttackStep::Gambit {
+            difficulty: 2,
+            succeeded: true
+        }
+    )));
+    assert!(outcome
+        .steps()
+        .any(|step| step == &AttackStep::ControlRounds(4)));
+    for mutation in outcome.combat_mutations() {
+        combat.apply_mutation(mutation).unwrap();
+    }
+    assert_eq!(combat.combatant("Wrestler").unwrap().initiative(), Some(5));
+    assert_eq!(combat.grapple_victim("Wrestler").unwrap().name(), hero);
+    assert_eq!(combat.grappler_of(hero).unwrap().name(), "Wrestler");
+
+    // Neither can start another grapple while this one holds
+    assert!(matches!(
+        gambit.resolve(&combat, &mut |n: u8| vec![10; n as usize]),
+        Err(CombatError::AlreadyGrappling)
+    ));
+}
+
+#[test]
+fn test_grapple_gambit_protector() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let mut combat = started_combat(&character);
+    combat
+        .apply_mutation(&CombatMutation::AddStatus {
+            name: "Bandit".to_owned(),
+            status: StatusEffect::DefendingOther(character.name().to_owned()),
+        })
+        .unwrap();
+
+    // Grapples are attacks, so a protector can parry them too
+    let mut misses = |n: u8| vec![1; n as usize];
+    assert!(matches!(
+        wrestler_gambit(&character).resolve(&combat, &mut misses),
+        Err(CombatError::NoProtector)
+    ));
+    let outcome = wrestler_gambit(&character)
+        .with_protector(DefenseProfile::npc("Bandit", 3, 2, 1, 0))
+        .resolve(&combat, &mut misses)
+        .unwrap();
+    assert!(outcome
+        .steps()
+        .any(|step| step == &AttackStep::DefendedBy("Bandit".to_owned())));
+}
+
+#[test]
+fn test_grapple_actions() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+    let mut combat = grappled(&character, 3);
+
+    // The wrestler restrains, and may take only one grapple action per turn
+    assert_eq!(
+        combat.grapple_actions("Wrestler"),
+        vec![
+            GrappleAction::Restrain,
+            GrappleAction::Savage,
+            GrappleAction::Throw,
+            GrappleAction::Slam,
+            GrappleAction::Release
+        ]
+    );
+    combat
+        .apply_mutation(&grapple(GrappleAction::Restrain))
+        .unwrap();
+    assert!(combat
+        .combatant(hero)
+        .unwrap()
+        .status(StatusName::Restrained)
+        .is_some());
+    assert!(combat.grapple_actions("Wrestler").is_empty());
+    assert!(matches!(
+        combat.apply_mutation(&grapple(GrappleAction::Savage)),
+        Err(CombatError::AlreadyActed)
+    ));
+
+    // Grapple actions are only taken on the grappler's turn
+    combat.apply_mutation(&CombatMutation::EndTurn).unwrap();
+    assert!(matches!(
+        combat.apply_mutation(&grapple(GrappleAction::Savage)),
+        Err(CombatError::NotTheirTurn)
+    ));
+
+    // Restraint ends when the wrestler's next turn begins; savaging uses
+    // their grapple action, and a control round at the end of the turn
+    combat
+        .apply_mutation(&CombatMutation::EndTurn)
+        .unwrap()
+        .apply_mutation(&CombatMutation::EndTurn)
+        .unwrap();
+    assert!(combat
+        .combatant(hero)
+        .unwrap()
+        .status(StatusName::Restrained)
+        .is_none());
+    combat
+        .apply_mutation(&grapple(GrappleAction::Savage))
+        .unwrap();
+    for _ in 0..3 {
+        combat.apply_mutation(&CombatMutation::EndTurn).unwrap();
+    }
+    assert_eq!(
+        combat.combatant(hero).unwrap().status(StatusName::Clinched),
+        Some(&StatusEffect::Clinched {
+            controller: "Wrestler".to_owned(),
+            control_rounds: 1
+        })
+    );
+
+    // With one control round left, the wrestler can't slam, but can throw
+    assert!(!combat
+        .grapple_actions("Wrestler")
+        .contains(&GrappleAction::Slam));
+    assert!(matches!(
+        combat.apply_mutation(&grapple(GrappleAction::Slam)),
+        Err(CombatError::InsufficientControl)
+    ));
+    combat
+        .apply_mutation(&grapple(GrappleAction::Throw))
+        .unwrap();
+    let thrown = combat.combatant(hero).unwrap();
+    assert!(thrown.status(StatusName::Clinched).is_none());
+    assert!(thrown.status(StatusName::Prone).is_some());
+    assert_eq!(combat.range("Wrestler", hero), Some(RangeBand::Short));
+    assert!(combat.grapple_victim("Wrestler").is_none());
+}
+
+#[test]
+fn test_grapple_slam_and_release() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+
+    // Slamming leaves the victim prone where they stand
+    let mut combat = grappled(&character, 2);
+    combat
+        .apply_mutation(&grapple(GrappleAction::Slam))
+        .unwrap();
+    let slammed = combat.combatant(hero).unwrap();
+    assert!(slammed.status(StatusName::Prone).is_some());
+    assert!(combat.grappler_of(hero).is_none());
+    assert_eq!(combat.range("Wrestler", hero), Some(RangeBand::Close));
+
+    // Releasing just lets go
+    let mut combat = grappled(&character, 2);
+    combat
+        .apply_mutation(&grapple(GrappleAction::Release))
+        .unwrap();
+    assert!(combat.grappler_of(hero).is_none());
+    assert!(combat
+        .combatant(hero)
+        .unwrap()
+        .status(StatusName::Prone)
+        .is_none());
+    assert!(matches!(
+        combat.apply_mutation(&grapple(GrappleAction::Restrain)),
+        Err(CombatError::NotGrappling)
+    ));
+}
+
+#[test]
+fn test_grapple_break_free() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+
+    // Ending the wrestler's turn spends a control round, and breaking free
+    // removes one for each success
+    let mut combat = grappled(&character, 3);
+    combat
+        .apply_mutation(&CombatMutation::EndTurn)
+        .unwrap()
+        .apply_mutation(&CombatMutation::BreakFree {
+            name: hero.to_owned(),
+            successes: 1,
+        })
+        .unwrap();
+    assert_eq!(
+        combat.combatant(hero).unwrap().status(StatusName::Clinched),
+        Some(&StatusEffect::Clinched {
+            controller: "Wrestler".to_owned(),
+            control_rounds: 1
+        })
+    );
+    combat
+        .apply_mutation(&CombatMutation::BreakFree {
+            name: hero.to_owned(),
+            successes: 3,
+        })
+        .unwrap();
+    assert!(combat.grappler_of(hero).is_none());
+
+    // Only clinched combatants can break free
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::BreakFree {
+            name: hero.to_owned(),
+            successes: 1,
+        }),
+        Err(CombatError::NotGrappling)
+    ));
+
+    // A grapple ends automatically when control rounds run out
+    let mut combat = grappled(&character, 1);
+    combat.apply_mutation(&CombatMutation::EndTurn).unwrap();
+    assert!(combat.grappler_of(hero).is_none());
+}
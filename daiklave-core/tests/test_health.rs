@@ -1,3 +1,18 @@
+use std::{
+    collections::HashMap,
+    num::{NonZeroU16, NonZeroU8},
+};
+
+use daiklave_core::{
+    attributes::AttributeName,
+    health::{
+        AddHealthLevels, DamageLevel, HealNaturally, HealthError, HealthLevelSource,
+        RemoveHealthLevels, SetHealthTrack, TakeDamage, WoundPenalty,
+    },
+    merits::merit::AddMerit,
+    Character, CharacterEvent, CharacterEventSource, CharacterMemo, CharacterMutationError,
+};
+
 #[test]
 fn test_health() {
     // Check default health
@@ -7,3 +22,296 @@ fn test_health() {
     // Check we can undo the full history
     // Check we can redo the full history
 }
+
+fn hours(n: u16) -> NonZeroU16 {
+    NonZeroU16::new(n).unwrap()
+}
+
+fn take_damage(level: DamageLevel, amount: u8) -> TakeDamage {
+    TakeDamage {
+        level,
+        amount: NonZeroU8::new(amount).unwrap(),
+    }
+}
+
+/// A mortal with lethal in the -0 box and bashing in both -1 boxes.
+fn wounded_mortal() -> CharacterEventSource {
+    let mut event_source = CharacterEventSource::default();
+    take_damage(DamageLevel::Lethal, 1)
+        .apply_event(&mut event_source)
+        .unwrap();
+    take_damage(DamageLevel::Bashing, 2)
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+#[test]
+fn test_natural_healing_rates() {
+    // Aggravated damage never heals naturally
+    assert_eq!(
+        DamageLevel::Aggravated.natural_healing_hours(WoundPenalty::Zero, true),
+        None
+    );
+    assert_eq!(
+        DamageLevel::Lethal.natural_healing_hours(WoundPenalty::Incapacitated, false),
+        Some(720)
+    );
+    assert_eq!(
+        DamageLevel::Bashing.natural_healing_hours(WoundPenalty::MinusOne, true),
+        Some(12)
+    );
+
+    let event_source = wounded_mortal();
+    let character = event_source.as_character().unwrap();
+    assert!(!character.has_exalted_healing());
+    assert_eq!(character.health().hours_until_next_box(false), Some(24));
+    assert_eq!(character.health().hours_until_healed(false), Some(96));
+    assert_eq!(character.health().hours_until_next_box(true), Some(12));
+}
+
+#[test]
+fn test_natural_healing_progress() {
+    let mut event_source = wounded_mortal();
+
+    // Too short a rest heals nothing, but progress carries over
+    let mut character = event_source.as_character().unwrap();
+    let report = character.heal_naturally(hours(12)).unwrap();
+    assert_eq!(report.healed().count(), 0);
+    assert_eq!(report.hours_until_next_box(), Some(12));
+
+    HealNaturally(hours(12))
+        .apply_event(&mut event_source)
+        .unwrap();
+    let mut character = event_source.as_character().unwrap();
+    let report = character.heal_naturally(hours(30)).unwrap();
+    assert_eq!(
+        report.healed().collect::<Vec<_>>(),
+        vec![(WoundPenalty::MinusOne, DamageLevel::Bashing)]
+    );
+    assert_eq!(report.hours_until_next_box(), Some(6));
+    assert_eq!(report.hours_until_healed(), Some(54));
+
+    // Undoing the rest undoes its progress too
+    let character = HealNaturally(hours(30))
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.health().hours_until_next_box(false), Some(6));
+    let character = event_source.undo().unwrap();
+    assert_eq!(character.health().hours_until_next_box(false), Some(12));
+}
+
+#[test]
+fn test_natural_healing_exalted() {
+    let mut event_source = wounded_mortal();
+    HealNaturally(hours(42))
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // Exalted Healing speeds things up, so the next box is ready to heal
+    let character = AddMerit::ExaltedHealing
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character.has_exalted_healing());
+    assert_eq!(character.health().hours_until_next_box(true), Some(0));
+
+    // Fresh wounds interrupt healing in progress
+    let character = take_damage(DamageLevel::Aggravated, 1)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.health().hours_until_next_box(true), Some(12));
+
+    // Only the aggravated box remains once the rest heals
+    let character = HealNaturally(hours(100))
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(
+        character.health().iter().next(),
+        Some((WoundPenalty::Zero, Some(DamageLevel::Aggravated)))
+    );
+    assert_eq!(
+        character
+            .health()
+            .iter()
+            .filter(|(_, damage)| damage.is_some())
+            .count(),
+        1
+    );
+    assert_eq!(character.health().hours_until_next_box(true), None);
+    assert_eq!(character.health().hours_until_healed(true), None);
+}
+
+fn total_boxes(event_source: &CharacterEventSource) -> usize {
+    event_source.as_character().unwrap().health().iter().count()
+}
+
+fn toughness() -> AddHealthLevels {
+    AddHealthLevels::merit("Toughness", [(WoundPenalty::Zero, 1)].into_iter().collect())
+}
+
+/// A character with 3 lethal damage, Stamina 5, one purchase of Ox-Body
+/// Technique, and a Toughness merit.
+fn tough_character() -> CharacterEventSource {
+    let mut event_source = CharacterEventSource::default();
+    take_damage(DamageLevel::Lethal, 3)
+        .apply_event(&mut event_source)
+        .unwrap();
+    AddHealthLevels::ox_body_technique()
+        .apply_event(&mut event_source)
+        .unwrap();
+    AttributeName::Stamina
+        .set_dots(5)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    toughness().apply_event(&mut event_source).unwrap();
+    event_source
+}
+
+#[test]
+fn test_health_levels_ox_body() {
+    let mut event_source = CharacterEventSource::default();
+
+    // Every box starts out from the base track
+    let character = take_damage(DamageLevel::Lethal, 3)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character
+        .health_level_sources()
+        .iter()
+        .all(|(source, _)| source == &HealthLevelSource::Base));
+    assert_eq!(total_boxes(&event_source), 7);
+
+    // Ox-Body Technique at Stamina 1 adds a -1 and a -2 box, keeping damage
+    let character = AddHealthLevels::ox_body_technique()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.health().iter().count(), 9);
+    assert_eq!(
+        character
+            .health()
+            .iter()
+            .filter(|(_, damage)| damage == &Some(DamageLevel::Lethal))
+            .count(),
+        3
+    );
+    assert_eq!(
+        character.health_level_sources()[3],
+        (HealthLevelSource::OxBodyTechnique, WoundPenalty::MinusOne)
+    );
+
+    // Raising Stamina improves every purchase
+    AttributeName::Stamina
+        .set_dots(5)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(total_boxes(&event_source), 11);
+}
+
+#[test]
+fn test_health_levels_sources() {
+    let mut event_source = tough_character();
+    assert_eq!(total_boxes(&event_source), 12);
+
+    // Merits and other sources can each be added once
+    assert!(matches!(
+        toughness().apply_event(&mut event_source),
+        Err(CharacterMutationError::HealthError(
+            HealthError::DuplicateSource
+        ))
+    ));
+
+    // Replacing the base track keeps the other sources
+    let base: HashMap<WoundPenalty, u8> = [
+        (WoundPenalty::Zero, 1),
+        (WoundPenalty::MinusOne, 1),
+        (WoundPenalty::Incapacitated, 1),
+    ]
+    .into_iter()
+    .collect();
+    let character = SetHealthTrack(base).apply_event(&mut event_source).unwrap();
+    assert_eq!(character.health().iter().count(), 8);
+    assert_eq!(
+        character.health().iter().last(),
+        Some((WoundPenalty::Incapacitated, None))
+    );
+}
+
+#[test]
+fn test_health_levels_remove() {
+    let mut event_source = tough_character();
+    let base: HashMap<WoundPenalty, u8> = [
+        (WoundPenalty::Zero, 1),
+        (WoundPenalty::MinusOne, 1),
+        (WoundPenalty::Incapacitated, 1),
+    ]
+    .into_iter()
+    .collect();
+    SetHealthTrack(base).apply_event(&mut event_source).unwrap();
+
+    // The base track can't be removed, and each source only once
+    assert!(matches!(
+        RemoveHealthLevels(HealthLevelSource::Base).apply_event(&mut event_source),
+        Err(CharacterMutationError::HealthError(HealthError::BaseTrack))
+    ));
+    RemoveHealthLevels(HealthLevelSource::OxBodyTechnique)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(matches!(
+        RemoveHealthLevels(HealthLevelSource::OxBodyTechnique).apply_event(&mut event_source),
+        Err(CharacterMutationError::HealthError(HealthError::NotFound))
+    ));
+
+    // Removing sources shrinks the track, rolling damage over if needed
+    let character = RemoveHealthLevels(HealthLevelSource::Merit("Toughness".to_owned()))
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.health().iter().count(), 3);
+    assert_eq!(
+        character.health().iter().last(),
+        Some((WoundPenalty::Incapacitated, Some(DamageLevel::Lethal)))
+    );
+}
+
+#[test]
+fn test_health_levels_legacy_memo() {
+    // A character saved before health levels were tracked, with a custom
+    // track of one -0, one -1, and one Incapacitated box
+    let mut event_source = CharacterEventSource::default();
+    let base: HashMap<WoundPenalty, u8> = [
+        (WoundPenalty::Zero, 1),
+        (WoundPenalty::MinusOne, 1),
+        (WoundPenalty::Incapacitated, 1),
+    ]
+    .into_iter()
+    .collect();
+    let character = SetHealthTrack(base).apply_event(&mut event_source).unwrap();
+    let mut json = serde_json::to_value(CharacterMemo::from(character)).unwrap();
+    json.as_object_mut().unwrap().remove("health_levels");
+    let memo: CharacterMemo = serde_json::from_value(json).unwrap();
+
+    // The stored track becomes the base, so adding levels keeps it
+    let mut character = Character::from(&memo);
+    assert!(character
+        .health_level_sources()
+        .iter()
+        .all(|(source, _)| source == &HealthLevelSource::Base));
+    character
+        .add_health_levels(&AddHealthLevels::ox_body_technique())
+        .unwrap();
+    assert_eq!(
+        character
+            .health()
+            .iter()
+            .map(|(wound_penalty, _)| wound_penalty)
+            .collect::<Vec<WoundPenalty>>(),
+        vec![
+            WoundPenalty::Zero,
+            WoundPenalty::MinusOne,
+            WoundPenalty::MinusOne,
+            WoundPenalty::MinusTwo,
+            WoundPenalty::Incapacitated
+        ]
+    );
+}
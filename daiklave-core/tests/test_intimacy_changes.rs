@@ -0,0 +1,153 @@
+use daiklave_core::{
+    intimacies::intimacy::{
+        AddIntimacy, ChangeTieEmotion, IntimacyChangeKind, IntimacyError, IntimacyLevel,
+        IntimacyType, StrengthenIntimacy, WeakenIntimacy,
+    },
+    CharacterEvent, CharacterEventSource, CharacterMutationError,
+};
+
+const RIVAL: IntimacyType<'static> = IntimacyType::Tie("Rival", "Respect");
+const HONOR: IntimacyType<'static> = IntimacyType::Principle("Honor above all");
+
+/// A character with a Minor Tie of respect for a rival and a Major Principle.
+fn honorable() -> CharacterEventSource {
+    let mut event_source = CharacterEventSource::default();
+    AddIntimacy::tie("Rival")
+        .description("Respect")
+        .level(IntimacyLevel::Minor)
+        .apply_event(&mut event_source)
+        .unwrap();
+    AddIntimacy::principle("Honor above all")
+        .level(IntimacyLevel::Major)
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+fn level_of(
+    event_source: &CharacterEventSource,
+    intimacy_type: IntimacyType<'_>,
+) -> Option<IntimacyLevel> {
+    event_source
+        .as_character()
+        .unwrap()
+        .intimacies()
+        .iter()
+        .find(|intimacy| intimacy.intimacy_type() == intimacy_type)
+        .map(|intimacy| intimacy.level())
+}
+
+#[test]
+fn test_intimacy_strengthen() {
+    let mut event_source = honorable();
+
+    // Strengthening moves up one level, to a maximum of Defining
+    let character = StrengthenIntimacy::new(HONOR, "Saved by an honorable foe")
+        .apply_event(&mut event_source)
+        .unwrap();
+    let history = character.intimacy_history().collect::<Vec<_>>();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].cause(), "Saved by an honorable foe");
+    assert_eq!(
+        history[0].kind(),
+        &IntimacyChangeKind::Strengthened {
+            from: IntimacyLevel::Major,
+            to: IntimacyLevel::Defining
+        }
+    );
+    assert_eq!(
+        level_of(&event_source, HONOR),
+        Some(IntimacyLevel::Defining)
+    );
+    assert!(matches!(
+        StrengthenIntimacy::new(HONOR, "Session reflection").apply_event(&mut event_source),
+        Err(CharacterMutationError::IntimacyError(
+            IntimacyError::AlreadyDefining
+        ))
+    ));
+    assert!(matches!(
+        StrengthenIntimacy::new(IntimacyType::Principle("Greed"), "Gold")
+            .apply_event(&mut event_source),
+        Err(CharacterMutationError::IntimacyError(
+            IntimacyError::NotFound
+        ))
+    ));
+
+    // Undoing a change also removes it from the history
+    let character = event_source.undo().unwrap();
+    assert_eq!(character.intimacy_history().count(), 0);
+}
+
+#[test]
+fn test_intimacy_change_tie_emotion() {
+    let mut event_source = honorable();
+
+    // Ties can change their emotional context while keeping their level
+    let character = ChangeTieEmotion::new(RIVAL, "Hatred", "Betrayed at the duel")
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character
+        .intimacies()
+        .iter()
+        .all(|intimacy| intimacy.intimacy_type() != RIVAL));
+    let hatred = IntimacyType::Tie("Rival", "Hatred");
+    let history = character.intimacy_history().collect::<Vec<_>>();
+    assert_eq!(history[0].intimacy_type(), hatred);
+    assert_eq!(
+        history[0].kind(),
+        &IntimacyChangeKind::EmotionChanged {
+            from: "Respect".to_owned()
+        }
+    );
+    assert_eq!(level_of(&event_source, hatred), Some(IntimacyLevel::Minor));
+
+    // Principles have no emotion, and Ties can't merge into another Tie
+    assert!(matches!(
+        ChangeTieEmotion::new(HONOR, "Doubt", "Nothing").apply_event(&mut event_source),
+        Err(CharacterMutationError::IntimacyError(
+            IntimacyError::NotATie
+        ))
+    ));
+    AddIntimacy::tie("Rival")
+        .description("Envy")
+        .level(IntimacyLevel::Minor)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(matches!(
+        ChangeTieEmotion::new(hatred, "Envy", "Jealous of their skill")
+            .apply_event(&mut event_source),
+        Err(CharacterMutationError::IntimacyError(
+            IntimacyError::DuplicateIntimacy
+        ))
+    ));
+}
+
+#[test]
+fn test_intimacy_weaken() {
+    let mut event_source = honorable();
+
+    // Weakening moves down one level
+    WeakenIntimacy::new(HONOR, "Saw the cost of honor")
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(level_of(&event_source, HONOR), Some(IntimacyLevel::Minor));
+
+    // Weakening a Minor Intimacy removes it
+    let character = WeakenIntimacy::new(RIVAL, "Forgave the rival")
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.intimacies().iter().count(), 1);
+    assert_eq!(
+        character.intimacy_history().last().unwrap().kind(),
+        &IntimacyChangeKind::Weakened {
+            from: IntimacyLevel::Minor,
+            to: None
+        }
+    );
+    assert!(matches!(
+        WeakenIntimacy::new(RIVAL, "Again").apply_event(&mut event_source),
+        Err(CharacterMutationError::IntimacyError(
+            IntimacyError::NotFound
+        ))
+    ));
+}
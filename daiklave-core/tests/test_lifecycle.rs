@@ -0,0 +1,227 @@
+mod common;
+
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    exaltation::exalt::essence::{CommitMotes, CommitSceneMotes, MotePoolName, SpendMotes},
+    lifecycle::{EndScene, EndSession, EndStory, Rest},
+    mutations::{GainLimit, SpendWillpower},
+    CharacterEvent, CharacterEventSource, CharacterMutation,
+};
+
+fn commit(name: &str, first: MotePoolName, amount: u8) -> CommitMotes {
+    CommitMotes {
+        effect_name: name.into(),
+        first,
+        amount: NonZeroU8::new(amount).unwrap(),
+    }
+}
+
+fn spend(first: MotePoolName, amount: u8) -> SpendMotes {
+    SpendMotes {
+        first,
+        amount: NonZeroU8::new(amount).unwrap(),
+    }
+}
+
+#[test]
+fn test_lifecycle_mortal() {
+    // Mortals have no motes or Limit, but still rest
+    let mut event_source = CharacterEventSource::default();
+    SpendWillpower(NonZeroU8::new(1).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    EndScene.apply_event(&mut event_source).unwrap();
+    EndSession.apply_event(&mut event_source).unwrap();
+    let character = Rest(NonZeroU8::new(8).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.willpower().current(), 3);
+
+    // Scene-length commitments need motes just like any other
+    assert!(
+        CommitSceneMotes(commit("Scene Charm", MotePoolName::Peripheral, 1))
+            .apply_event(&mut event_source)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_lifecycle_end_scene() {
+    let mut event_source = common::solar();
+    spend(MotePoolName::Peripheral, 20)
+        .apply_event(&mut event_source)
+        .unwrap();
+    CommitSceneMotes(commit("Scene Charm", MotePoolName::Peripheral, 10))
+        .apply_event(&mut event_source)
+        .unwrap();
+    commit("Indefinite Charm", MotePoolName::Personal, 5)
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // Scene commitments share names with other commitments
+    assert!(
+        CommitSceneMotes(commit("Indefinite Charm", MotePoolName::Personal, 1))
+            .apply_event(&mut event_source)
+            .is_err()
+    );
+
+    // Ending the scene releases only the scene-length commitment, as spent
+    // motes
+    let character = EndScene.apply_event(&mut event_source).unwrap();
+    let (peripheral, personal) = character
+        .essence()
+        .unwrap()
+        .motes()
+        .peripheral_and_personal();
+    assert_eq!((peripheral.available(), peripheral.spent()), (3, 30));
+    assert_eq!((personal.available(), personal.spent()), (8, 0));
+    assert_eq!(character.essence().unwrap().motes().committed().count(), 1);
+
+    // Undoing the end of the scene restores the commitment
+    let character = event_source.undo().unwrap();
+    assert_eq!(character.essence().unwrap().motes().committed().count(), 2);
+
+    // Scene-length commitments are ordinary commitments until then
+    let mut character = event_source.as_character().unwrap();
+    assert!(character
+        .commit_scene_motes(
+            "Scene Charm",
+            MotePoolName::Peripheral,
+            NonZeroU8::new(1).unwrap()
+        )
+        .is_err());
+}
+
+#[test]
+fn test_lifecycle_rest() {
+    let mut event_source = common::solar();
+    spend(MotePoolName::Peripheral, 30)
+        .apply_event(&mut event_source)
+        .unwrap();
+    SpendWillpower(NonZeroU8::new(2).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // A short rest recovers ten motes an hour, but no Willpower
+    let character = Rest(NonZeroU8::new(2).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    let (peripheral, _) = character
+        .essence()
+        .unwrap()
+        .motes()
+        .peripheral_and_personal();
+    assert_eq!(peripheral.spent(), 10);
+    assert_eq!(character.willpower().current(), 3);
+
+    // A full night's rest recovers all spent motes and a point of Willpower
+    let character = Rest(NonZeroU8::new(8).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    let (peripheral, _) = character
+        .essence()
+        .unwrap()
+        .motes()
+        .peripheral_and_personal();
+    assert_eq!((peripheral.available(), peripheral.spent()), (33, 0));
+    assert_eq!(character.willpower().current(), 4);
+
+    let mutation: CharacterMutation = Rest(NonZeroU8::new(1).unwrap()).into();
+    assert_eq!(
+        mutation,
+        CharacterMutation::Rest(Rest(NonZeroU8::new(1).unwrap()))
+    );
+}
+
+#[test]
+fn test_lifecycle_end_session() {
+    let mut event_source = common::solar();
+
+    // Ending the session with no Limit reduces nothing
+    let character = EndSession.apply_event(&mut event_source).unwrap();
+    assert_eq!(character.limit().unwrap().track(), 0);
+
+    // Otherwise, it reduces Limit by one and ends the scene
+    GainLimit(NonZeroU8::new(2).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    CommitSceneMotes(commit("Scene Charm", MotePoolName::Peripheral, 5))
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = EndSession.apply_event(&mut event_source).unwrap();
+    assert_eq!(character.limit().unwrap().track(), 1);
+    assert_eq!(character.essence().unwrap().motes().committed().count(), 0);
+}
+
+#[test]
+fn test_lifecycle_end_story() {
+    let mut event_source = common::solar();
+    commit("Indefinite Charm", MotePoolName::Personal, 5)
+        .apply_event(&mut event_source)
+        .unwrap();
+    spend(MotePoolName::Personal, 3)
+        .apply_event(&mut event_source)
+        .unwrap();
+    SpendWillpower(NonZeroU8::new(2).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // Ending the story restores motes and Willpower, keeping commitments
+    let character = EndStory.apply_event(&mut event_source).unwrap();
+    let (_, personal) = character
+        .essence()
+        .unwrap()
+        .motes()
+        .peripheral_and_personal();
+    assert_eq!((personal.available(), personal.spent()), (8, 0));
+    assert_eq!(character.willpower().current(), 5);
+    assert_eq!(character.essence().unwrap().motes().committed().count(), 1);
+}
+
+#[test]
+fn test_lifecycle_report() {
+    let mut event_source = common::solar();
+    spend(MotePoolName::Peripheral, 20)
+        .apply_event(&mut event_source)
+        .unwrap();
+    CommitSceneMotes(commit("Scene Charm", MotePoolName::Peripheral, 10))
+        .apply_event(&mut event_source)
+        .unwrap();
+    GainLimit(NonZeroU8::new(2).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    SpendWillpower(NonZeroU8::new(2).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    let mut character = event_source.as_character().unwrap();
+
+    // Each lifecycle step reports what it changed
+    let report = character.end_scene().unwrap();
+    assert_eq!(
+        report.released_commitments().collect::<Vec<_>>(),
+        vec!["Scene Charm"]
+    );
+    assert_eq!(report.motes_recovered(), 0);
+    assert_eq!(report.sorcerous_motes_lost(), 0);
+    assert!(!report.limit_break_ended());
+
+    let report = character.rest(NonZeroU8::new(8).unwrap()).unwrap();
+    assert_eq!(report.released_commitments().count(), 0);
+    assert_eq!(report.motes_recovered(), 30);
+    assert_eq!(report.willpower_recovered(), 1);
+    assert_eq!(report.limit_reduced(), 0);
+
+    let report = character.end_session().unwrap();
+    assert_eq!(report.limit_reduced(), 1);
+    assert_eq!(report.willpower_recovered(), 0);
+
+    // Ending the story includes the session it ends
+    let report = character.end_story().unwrap();
+    assert_eq!(report.limit_reduced(), 1);
+    assert_eq!(report.willpower_recovered(), 1);
+    assert_eq!(report.motes_recovered(), 0);
+
+    // Once everything has been recovered, nothing changes
+    assert!(character.end_story().unwrap().is_empty());
+}
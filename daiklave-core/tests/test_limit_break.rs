@@ -0,0 +1,217 @@
+mod common;
+
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    exaltation::exalt::limit::{CoreVirtueFlaw, VirtueFlaw, VirtueFlawDuration},
+    intimacies::intimacy::{AddIntimacy, IntimacyLevel, IntimacyType},
+    lifecycle::{EndScene, EndSession, Rest},
+    mutations::{GainLimit, ReduceLimit, ResolveLimitBreak},
+    social::{SocialAction, SocialInfluence},
+    CharacterEvent, CharacterEventSource, CharacterMutation, CharacterMutationError,
+};
+
+/// A Solar whose Limit has broken into the given Virtue Flaw.
+fn broken(virtue_flaw: VirtueFlaw) -> CharacterEventSource {
+    let mut event_source = common::solar();
+    virtue_flaw.set().apply_event(&mut event_source).unwrap();
+    GainLimit(NonZeroU8::new(10).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+fn full_night() -> Rest {
+    Rest(NonZeroU8::new(8).unwrap())
+}
+
+#[test]
+fn test_limit_virtue_flaw() {
+    // Mortals have no Virtue Flaw
+    let mut event_source = CharacterEventSource::default();
+    assert!(matches!(
+        VirtueFlaw::core(CoreVirtueFlaw::BerserkAnger)
+            .set()
+            .apply_event(&mut event_source),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+
+    // Choose a Virtue Flaw from the core book
+    let mut event_source = common::solar();
+    let character = VirtueFlaw::core(CoreVirtueFlaw::RedRageOfCompassion)
+        .set()
+        .apply_event(&mut event_source)
+        .unwrap();
+    let limit = character.limit().unwrap();
+    let virtue_flaw = limit.virtue_flaw().unwrap();
+    assert_eq!(virtue_flaw.name(), "Red Rage of Compassion");
+    assert_eq!(virtue_flaw.duration(), VirtueFlawDuration::Scene);
+    assert!(!limit.is_broken());
+
+    // Or a custom one
+    let character = VirtueFlaw::custom(
+        "Heart of the Storm",
+        VirtueFlawDuration::UntilResolved,
+        "The Solar destroys something they love",
+    )
+    .set()
+    .apply_event(&mut event_source)
+    .unwrap();
+    assert_eq!(
+        character
+            .limit()
+            .unwrap()
+            .virtue_flaw()
+            .unwrap()
+            .resolution(),
+        "The Solar destroys something they love"
+    );
+}
+
+#[test]
+fn test_limit_gain() {
+    let mut event_source = common::solar();
+
+    // Limit accumulates without breaking below 10
+    let character = GainLimit(NonZeroU8::new(7).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.limit().unwrap().track(), 7);
+    assert!(!character.limit().unwrap().is_broken());
+
+    // The track stops at 10, breaking
+    let character = GainLimit(NonZeroU8::new(5).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.limit().unwrap().track(), 10);
+    assert!(character.limit().unwrap().is_broken());
+
+    // Mortals have no Limit to gain
+    assert!(matches!(
+        GainLimit(NonZeroU8::new(1).unwrap()).apply_event(&mut CharacterEventSource::default()),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+}
+
+#[test]
+fn test_limit_break_resolve() {
+    // Can't resolve a Limit Break that hasn't happened
+    let mut event_source = common::solar();
+    assert!(matches!(
+        ResolveLimitBreak.apply_event(&mut event_source),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+
+    // Limit can't be reduced while broken, even by ending the session
+    let mut event_source = broken(VirtueFlaw::core(CoreVirtueFlaw::HeartOfFlint));
+    assert!(matches!(
+        ReduceLimit(NonZeroU8::new(1).unwrap()).apply_event(&mut event_source),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+    let character = EndSession.apply_event(&mut event_source).unwrap();
+    assert_eq!(character.limit().unwrap().track(), 10);
+    assert!(character.limit().unwrap().is_broken());
+
+    // Resolving the break resets the track
+    let character = ResolveLimitBreak.apply_event(&mut event_source).unwrap();
+    assert_eq!(character.limit().unwrap().track(), 0);
+    assert!(!character.limit().unwrap().is_broken());
+
+    // Undo restores the break
+    let character = event_source.undo().unwrap();
+    assert!(character.limit().unwrap().is_broken());
+
+    let mutation: CharacterMutation = ResolveLimitBreak.into();
+    assert_eq!(mutation, CharacterMutation::ResolveLimitBreak);
+}
+
+#[test]
+fn test_limit_break_duration() {
+    // Scene-long Virtue Flaws end with the scene
+    let mut event_source = broken(VirtueFlaw::core(CoreVirtueFlaw::BerserkAnger));
+    assert!(event_source
+        .as_character()
+        .unwrap()
+        .end_scene()
+        .unwrap()
+        .limit_break_ended());
+    let character = EndScene.apply_event(&mut event_source).unwrap();
+    assert!(!character.limit().unwrap().is_broken());
+    assert_eq!(character.limit().unwrap().track(), 0);
+
+    // Others last a number of days, each ending with a full night's rest
+    let mut event_source = broken(VirtueFlaw::core(CoreVirtueFlaw::WaywardDivinity));
+    EndScene.apply_event(&mut event_source).unwrap();
+    Rest(NonZeroU8::new(4).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    for _ in 0..6 {
+        full_night().apply_event(&mut event_source).unwrap();
+    }
+    assert!(event_source
+        .as_character()
+        .unwrap()
+        .limit()
+        .unwrap()
+        .is_broken());
+    assert!(event_source
+        .as_character()
+        .unwrap()
+        .rest(NonZeroU8::new(8).unwrap())
+        .unwrap()
+        .limit_break_ended());
+    let character = full_night().apply_event(&mut event_source).unwrap();
+    assert!(!character.limit().unwrap().is_broken());
+
+    // Custom flaws may only end when resolved
+    let mut event_source = broken(VirtueFlaw::custom(
+        "Heart of the Storm",
+        VirtueFlawDuration::UntilResolved,
+        "The Solar destroys something they love",
+    ));
+    EndScene.apply_event(&mut event_source).unwrap();
+    let character = full_night().apply_event(&mut event_source).unwrap();
+    assert!(character.limit().unwrap().is_broken());
+}
+
+#[test]
+fn test_limit_resist_defining() {
+    let mut event_source = common::solar();
+    AddIntimacy::principle("Protect the weak")
+        .level(IntimacyLevel::Defining)
+        .apply_event(&mut event_source)
+        .unwrap();
+    AddIntimacy::principle("Keep my word")
+        .level(IntimacyLevel::Major)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let protect_the_weak = IntimacyType::Principle("Protect the weak");
+    let keep_my_word = IntimacyType::Principle("Keep my word");
+    let character = event_source.as_character().unwrap();
+
+    // Resisting with a Major Intimacy only costs Willpower
+    let outcome = SocialInfluence::new(SocialAction::Persuade, 10)
+        .opposed_by(keep_my_word)
+        .resolve(&character)
+        .unwrap();
+    assert_eq!(
+        outcome.resist_mutations(&character, keep_my_word).unwrap(),
+        vec![outcome.resist(&character, keep_my_word).unwrap().into()]
+    );
+
+    // Resisting influence that would violate a Defining Intimacy adds Limit
+    let outcome = SocialInfluence::new(SocialAction::Persuade, 10)
+        .opposed_by(protect_the_weak)
+        .resolve(&character)
+        .unwrap();
+    let mutations = outcome
+        .resist_mutations(&character, protect_the_weak)
+        .unwrap();
+    assert_eq!(mutations.len(), 2);
+    for mutation in mutations {
+        mutation.apply_event(&mut event_source).unwrap();
+    }
+    let character = event_source.as_character().unwrap();
+    assert_eq!(character.limit().unwrap().track(), 1);
+    assert_eq!(character.willpower().current(), 4);
+}
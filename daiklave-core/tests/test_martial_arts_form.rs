@@ -0,0 +1,273 @@
+mod common;
+
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    abilities::AbilityNameQualified,
+    armor::armor_item::{
+        mundane::{AddMundaneArmor, CoreRulebookMundaneArmor},
+        ArmorName, ArmorWeightClass, UnequipArmor,
+    },
+    charms::CharmActionType,
+    combat::{attack::AttackProfile, CombatError},
+    martial_arts::{
+        charm::{MartialArtsCharmBonus, MartialArtsCharmKeyword},
+        style::{AddMartialArtsStyle, MartialArtsStyleName},
+        ActivateMartialArtsForm, EndMartialArtsForm,
+    },
+    weapons::weapon::{
+        mundane::{AddMundaneWeapon, CoreRulebookMundaneWeapon, RemoveMundaneWeapon},
+        AttackRange, Equipped, UnequipWeapon, WeaponName,
+    },
+    CharacterEvent, CharacterEventSource, CharacterMutationError,
+};
+
+const STYLE: &str = "Single Point Shining Into the Void Style";
+const FORM: &str = "Single Point Shining Into the Void Form";
+
+/// A Solar who knows a sword-only style with a Form and a non-Form Charm,
+/// and who owns (but has not equipped) an axe and a straight sword.
+fn swordsman() -> CharacterEventSource {
+    let mut event_source = common::solar();
+
+    AddMartialArtsStyle::name(STYLE)
+        .description("A sword style of blinding speed.")
+        .weapon("Straight Sword")
+        .max_armor_weight(ArmorWeightClass::Light)
+        .apply_event(&mut event_source)
+        .unwrap();
+    AbilityNameQualified::MartialArts(STYLE)
+        .set_dots(3)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    let one = NonZeroU8::new(1).unwrap();
+    for (name, keyword) in [
+        (FORM, Some(MartialArtsCharmKeyword::Form)),
+        ("Shining Starfall Execution", None),
+    ] {
+        let mut builder = MartialArtsStyleName::from(STYLE)
+            .new_charm()
+            .mastery("Adds a die of damage.".to_owned())
+            .terrestrial("Costs an extra mote.".to_owned());
+        if let Some(keyword) = keyword {
+            builder = builder.keyword(keyword);
+        }
+        builder
+            .name(name)
+            .essence_required(one)
+            .ability_required(one)
+            .action_type(CharmActionType::Simple)
+            .duration("One scene".to_owned())
+            .description("Flows into the style's perfect stance.".to_owned())
+            .build()
+            .apply_event(&mut event_source)
+            .unwrap();
+    }
+
+    AddMundaneWeapon::from_core_rulebook(CoreRulebookMundaneWeapon::Axe)
+        .apply_event(&mut event_source)
+        .unwrap();
+    AddMundaneWeapon::from_core_rulebook(CoreRulebookMundaneWeapon::StraightSword)
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+/// A swordsman with the sword in hand and the Form active.
+fn in_form() -> CharacterEventSource {
+    let mut event_source = swordsman();
+    WeaponName::Mundane("Straight Sword")
+        .equip_main_hand()
+        .apply_event(&mut event_source)
+        .unwrap();
+    ActivateMartialArtsForm(FORM.into())
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+fn active_form(event_source: &CharacterEventSource) -> Option<String> {
+    event_source
+        .as_character()
+        .unwrap()
+        .martial_arts()
+        .active_form()
+        .map(|form| form.name().to_owned())
+}
+
+#[test]
+fn test_martial_arts_form_activate() {
+    // Only Form Charms the character knows can be activated
+    let mut event_source = swordsman();
+    assert!(matches!(
+        ActivateMartialArtsForm("Shining Starfall Execution".into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::MartialArtsError(_))
+    ));
+    assert!(matches!(
+        ActivateMartialArtsForm("Unknown Form".into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::MartialArtsError(_))
+    ));
+    assert!(matches!(
+        EndMartialArtsForm.apply_event(&mut event_source),
+        Err(CharacterMutationError::MartialArtsError(_))
+    ));
+    assert_eq!(active_form(&event_source), None);
+
+    // Solars receive the Mastery effects of Martial Arts Charms
+    let character = event_source.as_character().unwrap();
+    assert_eq!(
+        character.martial_arts().charm_bonus(FORM),
+        Some(MartialArtsCharmBonus::Mastery("Adds a die of damage."))
+    );
+
+    // Activating and ending a Form
+    let mut event_source = in_form();
+    let character = event_source.as_character().unwrap();
+    assert_eq!(
+        character
+            .martial_arts()
+            .style(STYLE)
+            .unwrap()
+            .active_form()
+            .unwrap()
+            .name(),
+        FORM
+    );
+    EndMartialArtsForm.apply_event(&mut event_source).unwrap();
+    assert_eq!(active_form(&event_source), None);
+}
+
+#[test]
+fn test_martial_arts_form_weapons() {
+    // Fighting empty-handed counts as Unarmed, which this style doesn't allow
+    let mut event_source = swordsman();
+    assert!(matches!(
+        ActivateMartialArtsForm(FORM.into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::MartialArtsError(_))
+    ));
+
+    // Neither does it allow an axe
+    WeaponName::Mundane("Axe")
+        .equip_main_hand()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(matches!(
+        ActivateMartialArtsForm(FORM.into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::MartialArtsError(_))
+    ));
+
+    WeaponName::Mundane("Straight Sword")
+        .equip_main_hand()
+        .apply_event(&mut event_source)
+        .unwrap();
+    ActivateMartialArtsForm(FORM.into())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(active_form(&event_source).as_deref(), Some(FORM));
+}
+
+#[test]
+fn test_martial_arts_form_attacks() {
+    // Style attacks may only use the style's weapons
+    let event_source = in_form();
+    let character = event_source.as_character().unwrap();
+    let slash = AttackProfile::martial_arts(
+        &character,
+        STYLE,
+        WeaponName::Mundane("Straight Sword"),
+        Some(Equipped::MainHand),
+        AttackRange::Melee,
+    )
+    .unwrap();
+    let punch = AttackProfile::character(&character, WeaponName::Unarmed, None, AttackRange::Melee)
+        .unwrap();
+    assert!(slash.withering_pool() > punch.withering_pool());
+    assert!(matches!(
+        AttackProfile::martial_arts(
+            &character,
+            STYLE,
+            WeaponName::Unarmed,
+            None,
+            AttackRange::Melee
+        ),
+        Err(CombatError::WeaponNotAllowed)
+    ));
+    assert!(matches!(
+        AttackProfile::martial_arts(
+            &character,
+            "Unknown Style",
+            WeaponName::Mundane("Straight Sword"),
+            Some(Equipped::MainHand),
+            AttackRange::Melee
+        ),
+        Err(CombatError::StyleNotFound)
+    ));
+}
+
+#[test]
+fn test_martial_arts_form_unequip_weapon() {
+    // Putting away the sword leaves the character Unarmed, ending the Form
+    let mut event_source = in_form();
+    UnequipWeapon::new(WeaponName::Mundane("Straight Sword"), Equipped::MainHand)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(active_form(&event_source), None);
+
+    // Undoing restores it
+    event_source.undo().unwrap();
+    assert_eq!(active_form(&event_source).as_deref(), Some(FORM));
+}
+
+#[test]
+fn test_martial_arts_form_remove_weapon() {
+    // The sword in hand can't be removed out from under the Form
+    let mut event_source = in_form();
+    assert!(matches!(
+        RemoveMundaneWeapon::name("Straight Sword").apply_event(&mut event_source),
+        Err(CharacterMutationError::WeaponError(_))
+    ));
+    assert_eq!(active_form(&event_source).as_deref(), Some(FORM));
+
+    // Removing other weapons leaves it active
+    RemoveMundaneWeapon::name("Axe")
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(active_form(&event_source).as_deref(), Some(FORM));
+}
+
+#[test]
+fn test_martial_arts_form_armor() {
+    // Light armor is fine, but heavier armor ends the Form
+    let mut event_source = in_form();
+    AddMundaneArmor::from_core_rulebook(CoreRulebookMundaneArmor::ChainShirt)
+        .apply_event(&mut event_source)
+        .unwrap();
+    ArmorName::Mundane("Chain Shirt")
+        .equip()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(active_form(&event_source).as_deref(), Some(FORM));
+
+    AddMundaneArmor::from_core_rulebook(CoreRulebookMundaneArmor::Breastplate)
+        .apply_event(&mut event_source)
+        .unwrap();
+    ArmorName::Mundane("Breastplate")
+        .equip()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(active_form(&event_source), None);
+    assert!(matches!(
+        ActivateMartialArtsForm(FORM.into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::MartialArtsError(_))
+    ));
+
+    // Taking the armor off allows the Form again
+    UnequipArmor.apply_event(&mut event_source).unwrap();
+    ActivateMartialArtsForm(FORM.into())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(active_form(&event_source).as_deref(), Some(FORM));
+}
@@ -0,0 +1,299 @@
+use daiklave_core::{
+    combat::{
+        attack::{Attack, AttackProfile, AttackType, Defense, DefenseProfile},
+        AddCombatant, Combat, CombatError, CombatMutation, MoveDirection,
+    },
+    weapons::weapon::{
+        mundane::{AddMundaneWeapon, CoreRulebookMundaneWeapon},
+        AttackRange, RangeBand, WeaponName,
+    },
+    Character, CharacterEvent, CharacterEventSource,
+};
+
+fn archer(event_source: &mut CharacterEventSource) -> Character<'_> {
+    AddMundaneWeapon::from_core_rulebook(CoreRulebookMundaneWeapon::LongBow)
+        .apply_event(event_source)
+        .unwrap()
+}
+
+fn set_range(first: &str, second: &str, band: RangeBand) -> CombatMutation {
+    CombatMutation::SetRange {
+        first: first.to_owned(),
+        second: second.to_owned(),
+        band,
+    }
+}
+
+fn move_relative(name: &str, target: &str, direction: MoveDirection) -> CombatMutation {
+    CombatMutation::Move {
+        name: name.to_owned(),
+        target: target.to_owned(),
+        direction,
+    }
+}
+
+fn combat_at_range(character: &Character, band: RangeBand) -> Combat {
+    let mut combat = Combat::default();
+    for mutation in [
+        AddCombatant::character(character).into(),
+        AddCombatant::npc("Bandit", 4).into(),
+        set_range(character.name(), "Bandit", band),
+    ] {
+        combat.apply_mutation(&mutation).unwrap();
+    }
+    combat
+}
+
+#[test]
+fn test_positioning_attack_range() {
+    let mut event_source = CharacterEventSource::default();
+    let character = archer(&mut event_source);
+    let hero = character.name();
+    let bow = character
+        .weapons()
+        .get(WeaponName::Mundane("Long Bow"), None)
+        .unwrap();
+    let fists = character.weapons().get(WeaponName::Unarmed, None).unwrap();
+    let combat = combat_at_range(&character, RangeBand::Medium);
+
+    // Ranges are symmetric, and pick the weapon's accuracy for that band
+    assert_eq!(combat.range("Bandit", hero), Some(RangeBand::Medium));
+    assert_eq!(
+        combat.attack_range(hero, "Bandit", &bow).unwrap(),
+        (
+            AttackRange::Ranged(RangeBand::Medium),
+            bow.accuracy(AttackRange::Ranged(RangeBand::Medium))
+                .unwrap()
+        )
+    );
+    assert!(matches!(
+        combat.attack_range(hero, "Bandit", &fists),
+        Err(CombatError::OutOfRange)
+    ));
+    assert!(matches!(
+        combat.attack_range(hero, "Bandit Chief", &fists),
+        Err(CombatError::NotFound)
+    ));
+    assert!(matches!(
+        Combat::default()
+            .apply_mutation(&AddCombatant::character(&character).into())
+            .unwrap()
+            .apply_mutation(&AddCombatant::npc("Bandit", 4).into())
+            .unwrap()
+            .attack_range(hero, "Bandit", &fists),
+        Err(CombatError::NoRange)
+    ));
+}
+
+#[test]
+fn test_positioning_rush() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+    let mut combat = combat_at_range(&character, RangeBand::Medium);
+
+    // A rushing bandit closes in and follows the hero when they retreat
+    combat
+        .apply_mutation(&CombatMutation::Rush {
+            name: "Bandit".to_owned(),
+            target: hero.to_owned(),
+        })
+        .unwrap()
+        .apply_mutation(&move_relative(hero, "Bandit", MoveDirection::Away))
+        .unwrap();
+    assert!(combat.is_rushing("Bandit", hero));
+    assert_eq!(combat.range(hero, "Bandit"), Some(RangeBand::Short));
+
+    // Moving ends the rusher's own rush
+    combat
+        .apply_mutation(&move_relative("Bandit", hero, MoveDirection::Toward))
+        .unwrap();
+    assert!(!combat.is_rushing("Bandit", hero));
+    assert_eq!(combat.range(hero, "Bandit"), Some(RangeBand::Close));
+
+    let mut far = combat_at_range(&character, RangeBand::Long);
+    assert!(matches!(
+        far.apply_mutation(&CombatMutation::Rush {
+            name: "Bandit".to_owned(),
+            target: hero.to_owned(),
+        }),
+        Err(CombatError::TooFarToRush)
+    ));
+}
+
+#[test]
+fn test_positioning_disengage() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+    let mut combat = combat_at_range(&character, RangeBand::Close);
+
+    // Leaving Close range requires disengaging
+    assert!(matches!(
+        combat.apply_mutation(&move_relative(hero, "Bandit", MoveDirection::Away)),
+        Err(CombatError::Engaged)
+    ));
+    combat
+        .apply_mutation(&CombatMutation::Disengage {
+            name: hero.to_owned(),
+            from: "Bandit".to_owned(),
+        })
+        .unwrap();
+    assert_eq!(combat.range(hero, "Bandit"), Some(RangeBand::Short));
+    assert!(matches!(
+        combat.apply_mutation(&CombatMutation::Disengage {
+            name: hero.to_owned(),
+            from: "Bandit".to_owned(),
+        }),
+        Err(CombatError::InvalidMove)
+    ));
+
+    // Nothing is farther than Extreme range
+    combat
+        .apply_mutation(&set_range(hero, "Bandit", RangeBand::Extreme))
+        .unwrap();
+    assert!(matches!(
+        combat.apply_mutation(&move_relative(hero, "Bandit", MoveDirection::Away)),
+        Err(CombatError::InvalidMove)
+    ));
+}
+
+#[test]
+fn test_positioning_pairs() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+    let hero = character.name();
+    let mut combat = combat_at_range(&character, RangeBand::Medium);
+    combat
+        .apply_mutation(&AddCombatant::npc("Archer", 4).into())
+        .unwrap()
+        .apply_mutation(&set_range(hero, "Archer", RangeBand::Long))
+        .unwrap();
+
+    // Ranges are only known between pairs that have been set
+    assert!(combat.range("Bandit", "Archer").is_none());
+    assert!(matches!(
+        combat.apply_mutation(&move_relative("Bandit", "Archer", MoveDirection::Toward)),
+        Err(CombatError::NoRange)
+    ));
+
+    // A move only changes the range to the mover's target
+    combat
+        .apply_mutation(&move_relative(hero, "Bandit", MoveDirection::Toward))
+        .unwrap();
+    assert_eq!(combat.range(hero, "Bandit"), Some(RangeBand::Short));
+    assert_eq!(combat.range(hero, "Archer"), Some(RangeBand::Long));
+
+    // Removing a combatant removes their ranges
+    combat
+        .apply_mutation(&CombatMutation::RemoveCombatant("Archer".to_owned()))
+        .unwrap();
+    assert!(combat.range(hero, "Archer").is_none());
+    assert_eq!(combat.range(hero, "Bandit"), Some(RangeBand::Short));
+}
+
+#[test]
+fn test_positioning_independent_pairs() {
+    let mut combat = Combat::default();
+    for mutation in [
+        AddCombatant::npc("A", 4).into(),
+        AddCombatant::npc("B", 4).into(),
+        AddCombatant::npc("C", 4).into(),
+        set_range("A", "B", RangeBand::Short),
+        set_range("A", "C", RangeBand::Short),
+    ] {
+        combat.apply_mutation(&mutation).unwrap();
+    }
+
+    // Setting two ranges from A doesn't put B and C on top of each other
+    assert!(combat.range("B", "C").is_none());
+    combat
+        .apply_mutation(&set_range("B", "C", RangeBand::Medium))
+        .unwrap();
+    combat
+        .apply_mutation(&move_relative("B", "C", MoveDirection::Away))
+        .unwrap();
+    assert_eq!(combat.range("B", "C"), Some(RangeBand::Long));
+    assert_eq!(combat.range("A", "B"), Some(RangeBand::Short));
+    assert_eq!(combat.range("A", "C"), Some(RangeBand::Short));
+
+    // Resetting a range replaces it rather than adding another
+    combat
+        .apply_mutation(&set_range("C", "A", RangeBand::Close))
+        .unwrap();
+    assert_eq!(combat.range("A", "C"), Some(RangeBand::Close));
+    assert!(matches!(
+        combat.apply_mutation(&move_relative("C", "A", MoveDirection::Away)),
+        Err(CombatError::Engaged)
+    ));
+}
+
+#[test]
+fn test_positioning_attack_reach() {
+    let mut event_source = CharacterEventSource::default();
+    let character = archer(&mut event_source);
+    let hero = character.name();
+    let mut combat = combat_at_range(&character, RangeBand::Medium);
+    for mutation in [
+        CombatMutation::JoinBattle {
+            name: hero.to_owned(),
+            successes: 2,
+        },
+        CombatMutation::JoinBattle {
+            name: "Bandit".to_owned(),
+            successes: 1,
+        },
+        CombatMutation::StartCombat,
+    ] {
+        combat.apply_mutation(&mutation).unwrap();
+    }
+    let bandit = DefenseProfile::npc("Bandit", 2, 2, 3, 0);
+    let mut misses = |n: u8| vec![1; n as usize];
+
+    // Attacks must be able to reach the defender at their current range
+    let punch = Attack::new(
+        AttackProfile::character(&character, WeaponName::Unarmed, None, AttackRange::Melee)
+            .unwrap(),
+        bandit.clone(),
+        AttackType::Withering,
+        Defense::Parry,
+    );
+    assert!(matches!(
+        punch.resolve(&combat, &mut misses),
+        Err(CombatError::OutOfRange)
+    ));
+    let shot = Attack::new(
+        AttackProfile::character(
+            &character,
+            WeaponName::Mundane("Long Bow"),
+            None,
+            AttackRange::Ranged(RangeBand::Medium),
+        )
+        .unwrap(),
+        bandit,
+        AttackType::Withering,
+        Defense::Evasion,
+    );
+    assert!(shot.resolve(&combat, &mut misses).is_ok());
+
+    // NPC attacks are in melee unless specified
+    let stab = Attack::new(
+        AttackProfile::npc("Bandit", 6, 9),
+        DefenseProfile::npc(hero, 1, 1, 1, 0),
+        AttackType::Withering,
+        Defense::Parry,
+    );
+    assert!(matches!(
+        stab.resolve(&combat, &mut misses),
+        Err(CombatError::OutOfRange)
+    ));
+    combat
+        .apply_mutation(&set_range(hero, "Bandit", RangeBand::Close))
+        .unwrap();
+    assert!(stab.resolve(&combat, &mut misses).is_ok());
+    assert!(punch.resolve(&combat, &mut misses).is_ok());
+    assert!(matches!(
+        shot.resolve(&combat, &mut misses),
+        Err(CombatError::OutOfRange)
+    ));
+}
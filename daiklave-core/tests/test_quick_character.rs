@@ -0,0 +1,137 @@
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    battle_group::{BattleGroupStatblock, Drill},
+    exaltation::exalt::essence::{MotePoolName, RecoverMotes, SpendMotes},
+    health::{DamageLevel, TakeDamage, WoundPenalty},
+    quick_character::{QuickCharacter, QuickStatName, SetQuickEssence},
+    CharacterMutationError, QuickCharacterMemo, QuickCharacterMutation,
+};
+
+fn bandit_chief() -> QuickCharacterMemo {
+    [
+        QuickCharacterMutation::SetName("Bandit Chief".to_owned()),
+        QuickCharacterMutation::SetActionPool("Read Intentions".to_owned(), 6),
+        QuickCharacterMutation::SetAttack {
+            name: "Sword".to_owned(),
+            accuracy: 9,
+            damage: 12,
+        },
+        QuickCharacterMutation::SetStat(QuickStatName::Parry, 4),
+        QuickCharacterMutation::SetStat(QuickStatName::Resolve, 3),
+        QuickCharacterMutation::AddCharm {
+            name: "Heaven Thunder Hammer".to_owned(),
+            summary: "Knocks the target back".to_owned(),
+        },
+    ]
+    .iter()
+    .fold(QuickCharacterMemo::default(), |memo, mutation| {
+        memo.apply_mutation(mutation).unwrap()
+    })
+}
+
+fn spend(first: MotePoolName, amount: u8) -> QuickCharacterMutation {
+    SpendMotes {
+        first,
+        amount: NonZeroU8::new(amount).unwrap(),
+    }
+    .into()
+}
+
+#[test]
+fn test_quick_character_traits() {
+    let memo = bandit_chief()
+        .apply_mutation(
+            &TakeDamage {
+                level: DamageLevel::Lethal,
+                amount: NonZeroU8::new(2).unwrap(),
+            }
+            .into(),
+        )
+        .unwrap();
+    let quick_character = QuickCharacter::from(&memo);
+
+    assert_eq!(quick_character.name(), "Bandit Chief");
+    assert_eq!(quick_character.action_pool("Read Intentions"), Some(6));
+    assert_eq!(quick_character.attack("Sword"), Some((9, 12)));
+    assert_eq!(quick_character.stats().get(QuickStatName::Parry), 4);
+    assert_eq!(quick_character.stats().get(QuickStatName::Guile), 0);
+    assert_eq!(
+        quick_character.charm("Heaven Thunder Hammer"),
+        Some("Knocks the target back")
+    );
+    assert_eq!(
+        quick_character.health().current_wound_penalty(),
+        WoundPenalty::MinusOne
+    );
+
+    // Charms can't be duplicated
+    assert!(memo
+        .apply_mutation(&QuickCharacterMutation::AddCharm {
+            name: "Heaven Thunder Hammer".to_owned(),
+            summary: "Duplicate".to_owned(),
+        })
+        .is_err());
+
+    // Quick Characters can be the base for a battle group
+    let battle_group = BattleGroupStatblock::from(&quick_character)
+        .battle_group(1, Drill::Poor, 0)
+        .unwrap();
+    assert_eq!(battle_group.attack("Sword"), Some((10, 13)));
+    assert_eq!(battle_group.magnitude(), 8);
+}
+
+#[test]
+fn test_quick_character_motes() {
+    // Quick Characters without Essence can't use motes
+    assert!(matches!(
+        bandit_chief().apply_mutation(&spend(MotePoolName::Peripheral, 1)),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+
+    let memo = bandit_chief()
+        .apply_mutation(&SetQuickEssence::new(NonZeroU8::new(2).unwrap(), 10, 20).into())
+        .unwrap();
+
+    // Spending overflows from the first pool to the other
+    let memo = memo
+        .apply_mutation(&spend(MotePoolName::Peripheral, 22))
+        .unwrap();
+    let quick_character = QuickCharacter::from(&memo);
+    let essence = quick_character.essence().unwrap();
+    assert_eq!(essence.available(MotePoolName::Peripheral), 0);
+    assert_eq!(essence.available(MotePoolName::Personal), 8);
+
+    assert!(matches!(
+        memo.apply_mutation(&spend(MotePoolName::Personal, 9)),
+        Err(CharacterMutationError::EssenceError(_))
+    ));
+
+    // Recovery fills peripheral first and stops at the pools' maximums
+    let memo = memo
+        .apply_mutation(&RecoverMotes(NonZeroU8::new(255).unwrap()).into())
+        .unwrap();
+    let quick_character = QuickCharacter::from(&memo);
+    let essence = quick_character.essence().unwrap();
+    assert_eq!(essence.available(MotePoolName::Peripheral), 20);
+    assert_eq!(essence.available(MotePoolName::Personal), 10);
+}
+
+#[test]
+fn test_quick_character_large_essence() {
+    // Ancient spirits may exceed Essence 5 and have very large pools
+    let memo = bandit_chief()
+        .apply_mutation(&SetQuickEssence::new(NonZeroU8::new(8).unwrap(), 200, 200).into())
+        .unwrap();
+    let quick_character = QuickCharacter::from(&memo);
+    assert_eq!(quick_character.essence().unwrap().rating().get(), 8);
+
+    // Pools totalling more than 255 motes don't overflow
+    let memo = memo
+        .apply_mutation(&spend(MotePoolName::Personal, 255))
+        .unwrap();
+    let quick_character = QuickCharacter::from(&memo);
+    let essence = quick_character.essence().unwrap();
+    assert_eq!(essence.available(MotePoolName::Personal), 0);
+    assert_eq!(essence.available(MotePoolName::Peripheral), 145);
+}
@@ -0,0 +1,146 @@
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    abilities::{AbilityNameQualified, AbilityNameVanilla, SetAbility},
+    attributes::AttributeName,
+    intimacies::intimacy::{AddIntimacy, IntimacyLevel, IntimacyType},
+    mutations::SpendWillpower,
+    social::{SocialAction, SocialError, SocialInfluence},
+    CharacterEvent, CharacterEventSource,
+};
+
+const MOTHER: IntimacyType<'static> = IntimacyType::Tie("Mother", "Love");
+const NEVER_LIE: IntimacyType<'static> = IntimacyType::Principle("Never lie");
+const GOLD: IntimacyType<'static> = IntimacyType::Principle("Gold is good");
+
+/// A mortal with Resolve 3 and a Defining, a Major, and a Minor Intimacy.
+fn resolute_target() -> CharacterEventSource {
+    let mut event_source = CharacterEventSource::default();
+    AttributeName::Wits
+        .set_dots(3)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    SetAbility::new(
+        AbilityNameQualified::Vanilla(AbilityNameVanilla::Integrity),
+        3,
+    )
+    .unwrap()
+    .apply_event(&mut event_source)
+    .unwrap();
+    for intimacy in [
+        AddIntimacy::tie("Mother")
+            .description("Love")
+            .level(IntimacyLevel::Defining),
+        AddIntimacy::principle("Never lie").level(IntimacyLevel::Major),
+        AddIntimacy::principle("Gold is good").level(IntimacyLevel::Minor),
+    ] {
+        intimacy.apply_event(&mut event_source).unwrap();
+    }
+    event_source
+}
+
+#[test]
+fn test_social_guile() {
+    let event_source = CharacterEventSource::default();
+    let character = event_source.as_character().unwrap();
+
+    // Guile is (Manipulation 1 + Socialize 0) / 2, rounded up
+    assert_eq!(character.guile(), 1);
+    let outcome = SocialInfluence::new(SocialAction::ReadIntentions, 1)
+        .resolve(&character)
+        .unwrap();
+    assert!(outcome.succeeded());
+    assert_eq!(outcome.difficulty(), 1);
+    assert!(!SocialInfluence::new(SocialAction::ReadIntentions, 0)
+        .resolve(&character)
+        .unwrap()
+        .succeeded());
+}
+
+#[test]
+fn test_social_resolve() {
+    let event_source = resolute_target();
+    let character = event_source.as_character().unwrap();
+    assert_eq!(character.resolve(), 3);
+
+    // Successes must meet or exceed Resolve
+    let outcome = SocialInfluence::new(SocialAction::Persuade, 3)
+        .resolve(&character)
+        .unwrap();
+    assert!(outcome.succeeded());
+    assert_eq!(outcome.difficulty(), 3);
+
+    // A supporting Minor Intimacy lowers Resolve by 1
+    let outcome = SocialInfluence::new(SocialAction::Bargain, 2)
+        .supported_by(GOLD)
+        .resolve(&character)
+        .unwrap();
+    assert_eq!(outcome.difficulty(), 2);
+    assert!(outcome.succeeded());
+
+    // Only the strongest opposing Intimacy applies: Defining raises it by 4
+    let outcome = SocialInfluence::new(SocialAction::Threaten, 5)
+        .opposed_by(MOTHER)
+        .opposed_by(GOLD)
+        .resolve(&character)
+        .unwrap();
+    assert_eq!(outcome.base_difficulty(), 3);
+    assert_eq!(outcome.difficulty(), 7);
+    assert!(!outcome.succeeded());
+    assert_eq!(outcome.decision_points().count(), 0);
+
+    // Only the target's own Intimacies can be cited
+    assert!(matches!(
+        SocialInfluence::new(SocialAction::Inspire, 5)
+            .supported_by(IntimacyType::Principle("Might makes right"))
+            .resolve(&character),
+        Err(SocialError::IntimacyNotFound)
+    ));
+}
+
+#[test]
+fn test_social_decision_point() {
+    let mut event_source = resolute_target();
+    let character = event_source.as_character().unwrap();
+
+    // Successful influence opposed by a Major Intimacy allows a Decision
+    // Point, spending Willpower to resist
+    let outcome = SocialInfluence::new(SocialAction::Instill, 5)
+        .supported_by(GOLD)
+        .opposed_by(NEVER_LIE)
+        .resolve(&character)
+        .unwrap();
+    assert_eq!(outcome.difficulty(), 5);
+    assert!(outcome.succeeded());
+    assert_eq!(
+        outcome.decision_points().collect::<Vec<_>>(),
+        vec![NEVER_LIE]
+    );
+    assert!(matches!(
+        outcome.resist(&character, GOLD),
+        Err(SocialError::NoDecisionPoint)
+    ));
+    let character = outcome
+        .resist(&character, NEVER_LIE)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.willpower().current(), 2);
+
+    // Failed influence doesn't need resisting
+    let failed = SocialInfluence::new(SocialAction::Instill, 4)
+        .opposed_by(NEVER_LIE)
+        .resolve(&character)
+        .unwrap();
+    assert_eq!(failed.decision_points().count(), 0);
+
+    // Resisting needs Willpower to spend
+    let character = SpendWillpower(NonZeroU8::new(2).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(matches!(
+        outcome.resist(&character, NEVER_LIE),
+        Err(SocialError::InsufficientWillpower)
+    ));
+}
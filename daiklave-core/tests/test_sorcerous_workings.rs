@@ -0,0 +1,257 @@
+mod common;
+
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    abilities::{AbilityNameQualified, AbilityNameVanilla, SetAbility},
+    extended_roll::ExtendedRollStatus,
+    sorcery::{
+        AbandonSorcerousWorking, RollSorcerousWorking, SorcerousWorking, Sorcery,
+        SorceryArchetypeName, SorceryCircle, WorkingAmbition,
+    },
+    CharacterEvent, CharacterEventSource, CharacterMutationError,
+};
+
+/// A Terrestrial Circle Solar sorcerer.
+fn sorcerer() -> CharacterEventSource {
+    let mut event_source = common::solar();
+    SetAbility::new(AbilityNameQualified::Vanilla(AbilityNameVanilla::Occult), 3)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    let shaping_ritual = SorceryArchetypeName::from("Bargain with Mara").new_shaping_ritual(
+        "Mara's favor",
+        None,
+        "Mara grants two sorcerous motes when the sorcerer pays her price.",
+    );
+    Sorcery::builder()
+        .terrestrial()
+        .archetype_name("Bargain with Mara")
+        .description("The sorcerer has bargained with the shadow-courtesan Mara.")
+        .shaping_ritual(shaping_ritual)
+        .unwrap()
+        .control_spell_name("Death of Obsidian Butterflies")
+        .sorcerous_motes(NonZeroU8::new(15).unwrap())
+        .willpower(NonZeroU8::new(1).unwrap())
+        .duration("Instant")
+        .description("A swarm of obsidian butterflies scythes through the sorcerer's foes.")
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+/// A sorcerer who has started a finesse 3 greater working.
+fn warding() -> CharacterEventSource {
+    let mut event_source = sorcerer();
+    SorcerousWorking::greater("Ward the Manse", SorceryCircle::Terrestrial)
+        .finesse(3)
+        .unwrap()
+        .means("A tithe of jade from the Guild")
+        .interval_days(3)
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+fn roll(name: &str, successes: u8, botch: Option<&str>) -> RollSorcerousWorking {
+    RollSorcerousWorking {
+        name: name.to_owned(),
+        successes,
+        botch: botch.map(|consequence| consequence.to_owned()),
+    }
+}
+
+#[test]
+fn test_start_sorcerous_working() {
+    let event_source = warding();
+    let character = event_source.as_character().unwrap();
+    let working = character
+        .sorcery()
+        .unwrap()
+        .working("Ward the Manse")
+        .unwrap();
+    assert_eq!(working.circle(), SorceryCircle::Terrestrial);
+    assert_eq!(working.ambition(), WorkingAmbition::Greater);
+    assert_eq!(working.finesse(), 3);
+    assert_eq!(
+        working.means().collect::<Vec<_>>(),
+        vec!["A tithe of jade from the Guild"]
+    );
+
+    // Finesse is the difficulty, and the goal number follows the ambition
+    let extended_roll = working.extended_roll();
+    assert_eq!(extended_roll.difficulty(), 3);
+    assert_eq!(extended_roll.goal(), 15);
+    assert_eq!(extended_roll.terminus(), 6);
+    assert_eq!(extended_roll.interval_days(), 3);
+    assert_eq!(extended_roll.status(), ExtendedRollStatus::InProgress);
+}
+
+#[test]
+fn test_start_sorcerous_working_errors() {
+    // Finesse must be between 1 and 5
+    assert!(
+        SorcerousWorking::lesser("Too Fine", SorceryCircle::Terrestrial)
+            .finesse(6)
+            .is_err()
+    );
+
+    // Non-sorcerers can't start workings
+    assert!(matches!(
+        SorcerousWorking::lesser("Ward the Manse", SorceryCircle::Terrestrial)
+            .build()
+            .apply_event(&mut CharacterEventSource::default()),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Names must be unique, and the circle must be known
+    let mut event_source = warding();
+    assert!(matches!(
+        SorcerousWorking::lesser("Ward the Manse", SorceryCircle::Terrestrial)
+            .build()
+            .apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+    assert!(matches!(
+        SorcerousWorking::lesser("Call the Storm", SorceryCircle::Celestial)
+            .build()
+            .apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+}
+
+#[test]
+fn test_roll_sorcerous_working() {
+    let mut event_source = warding();
+
+    // Rolls below the finesse difficulty add nothing
+    roll("Ward the Manse", 6, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = roll("Ward the Manse", 2, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let working = character
+        .sorcery()
+        .unwrap()
+        .working("Ward the Manse")
+        .unwrap();
+    assert_eq!(working.extended_roll().progress(), 6);
+    assert_eq!(working.extended_roll().rolls().len(), 2);
+
+    // Reaching the goal number completes the working
+    let character = roll("Ward the Manse", 9, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let sorcery = character.sorcery().unwrap();
+    assert_eq!(
+        sorcery
+            .working("Ward the Manse")
+            .unwrap()
+            .extended_roll()
+            .status(),
+        ExtendedRollStatus::Completed
+    );
+    assert_eq!(
+        sorcery
+            .completed_workings()
+            .map(|working| working.name())
+            .collect::<Vec<_>>(),
+        vec!["Ward the Manse"]
+    );
+
+    // Finished and unknown workings can't be rolled
+    assert!(matches!(
+        roll("Ward the Manse", 5, None).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+    assert!(matches!(
+        roll("Bind the River", 5, None).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+}
+
+#[test]
+fn test_roll_sorcerous_working_botch() {
+    let mut event_source = warding();
+    roll("Ward the Manse", 8, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    // A botch destroys all progress and records its consequence
+    let character = roll(
+        "Ward the Manse",
+        0,
+        Some("The ward attracts a hungry ghost"),
+    )
+    .apply_event(&mut event_source)
+    .unwrap();
+    let working = character
+        .sorcery()
+        .unwrap()
+        .working("Ward the Manse")
+        .unwrap();
+    assert_eq!(working.extended_roll().progress(), 0);
+    assert_eq!(
+        working.extended_roll().botches().collect::<Vec<_>>(),
+        vec!["The ward attracts a hungry ghost"]
+    );
+    assert_eq!(character.sorcery().unwrap().completed_workings().count(), 0);
+
+    // Later rolls count again
+    let character = roll("Ward the Manse", 7, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let working = character
+        .sorcery()
+        .unwrap()
+        .working("Ward the Manse")
+        .unwrap();
+    assert_eq!(working.extended_roll().progress(), 7);
+}
+
+#[test]
+fn test_roll_sorcerous_working_terminus() {
+    // Running out of rolls fails the working
+    let mut event_source = sorcerer();
+    SorcerousWorking::superior("Bind the River", SorceryCircle::Terrestrial)
+        .terminus(1)
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = roll("Bind the River", 10, None)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let extended_roll = character
+        .sorcery()
+        .unwrap()
+        .working("Bind the River")
+        .unwrap()
+        .extended_roll();
+    assert_eq!(extended_roll.goal(), 25);
+    assert_eq!(extended_roll.status(), ExtendedRollStatus::Failed);
+}
+
+#[test]
+fn test_abandon_sorcerous_working() {
+    let mut event_source = warding();
+    let character = AbandonSorcerousWorking("Ward the Manse".to_owned())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(character.sorcery().unwrap().workings().count(), 0);
+    assert!(matches!(
+        AbandonSorcerousWorking("Ward the Manse".to_owned()).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Undo restores it
+    let character = event_source.undo().unwrap();
+    assert!(character
+        .sorcery()
+        .unwrap()
+        .working("Ward the Manse")
+        .is_some());
+}
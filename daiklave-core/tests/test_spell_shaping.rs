@@ -0,0 +1,258 @@
+mod common;
+
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    abilities::{AbilityNameQualified, AbilityNameVanilla, SetAbility},
+    attributes::{AttributeName, SetAttribute},
+    mutations::{EndScene, SpendWillpower},
+    sorcery::{
+        spell::Spell, CastSpell, DistractShaping, InterruptShaping, ShapeSorcery, Sorcery,
+        SorceryArchetypeName, SorceryCircle,
+    },
+    CharacterEvent, CharacterEventSource, CharacterMutationError,
+};
+
+const BUTTERFLIES: &str = "Death of Obsidian Butterflies";
+
+/// A Terrestrial Circle Solar sorcerer with Intelligence 4 and Occult 3, who
+/// knows a 15-mote control spell, a 10-mote spell, and a ritual.
+fn sorcerer() -> CharacterEventSource {
+    let mut event_source = common::solar();
+    SetAbility::new(AbilityNameQualified::Vanilla(AbilityNameVanilla::Occult), 3)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+    SetAttribute::new(AttributeName::Intelligence, 4)
+        .unwrap()
+        .apply_event(&mut event_source)
+        .unwrap();
+
+    let shaping_ritual = SorceryArchetypeName::from("Bargain with Mara")
+        .new_shaping_ritual(
+            "Mara's favor",
+            None,
+            "Mara grants two sorcerous motes when the sorcerer pays her price.",
+        )
+        .bonus_motes(2);
+    Sorcery::builder()
+        .terrestrial()
+        .archetype_name("Bargain with Mara")
+        .description("The sorcerer has bargained with the shadow-courtesan Mara.")
+        .shaping_ritual(shaping_ritual)
+        .unwrap()
+        .control_spell_name(BUTTERFLIES)
+        .sorcerous_motes(NonZeroU8::new(15).unwrap())
+        .willpower(NonZeroU8::new(1).unwrap())
+        .duration("Instant")
+        .description("A swarm of obsidian butterflies scythes through the sorcerer's foes.")
+        .build()
+        .apply_event(&mut event_source)
+        .unwrap();
+    Spell::with_name("Cirrus Skiff")
+        .sorcerous_motes(NonZeroU8::new(10).unwrap())
+        .willpower(NonZeroU8::new(1).unwrap())
+        .duration("Until the sorcerer dismounts")
+        .description("The sorcerer summons a cloud to ride.")
+        .build(SorceryCircle::Terrestrial)
+        .apply_event(&mut event_source)
+        .unwrap();
+    Spell::with_name("Mists of Eventide")
+        .ritual()
+        .willpower(NonZeroU8::new(1).unwrap())
+        .duration("Until dawn")
+        .description("The sorcerer calls up a soporific mist.")
+        .build(SorceryCircle::Terrestrial)
+        .apply_event(&mut event_source)
+        .unwrap();
+    event_source
+}
+
+fn shape(spell: &str, successes: u8) -> ShapeSorcery {
+    ShapeSorcery {
+        spell: spell.into(),
+        successes,
+        shaping_ritual: None,
+    }
+}
+
+fn shaped_motes(event_source: &CharacterEventSource) -> Option<u8> {
+    event_source
+        .as_character()
+        .unwrap()
+        .sorcery()
+        .unwrap()
+        .shaping()
+        .map(|shaping| shaping.motes())
+}
+
+#[test]
+fn test_shape_sorcery_pool() {
+    let event_source = sorcerer();
+    let character = event_source.as_character().unwrap();
+    assert_eq!(character.shape_sorcery_pool(), 7);
+}
+
+#[test]
+fn test_shape_sorcery() {
+    // Shaping requires a known, non-ritual spell
+    assert!(matches!(
+        shape(BUTTERFLIES, 3).apply_event(&mut CharacterEventSource::default()),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+    let mut event_source = sorcerer();
+    assert!(matches!(
+        shape("Unknown Spell", 3).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+    assert!(matches!(
+        shape("Mists of Eventide", 3).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Successes and shaping ritual bonus motes accumulate
+    let character = ShapeSorcery {
+        spell: BUTTERFLIES.into(),
+        successes: 4,
+        shaping_ritual: Some(SorceryCircle::Terrestrial),
+    }
+    .apply_event(&mut event_source)
+    .unwrap();
+    let shaping = character.sorcery().unwrap().shaping().unwrap();
+    assert_eq!(shaping.spell(), BUTTERFLIES);
+    assert_eq!(shaping.motes(), 6);
+    shape(BUTTERFLIES, 5)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert_eq!(shaped_motes(&event_source), Some(11));
+
+    // Only known shaping rituals grant bonus motes
+    assert!(matches!(
+        ShapeSorcery {
+            spell: BUTTERFLIES.into(),
+            successes: 4,
+            shaping_ritual: Some(SorceryCircle::Celestial),
+        }
+        .apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Shaping a different spell starts over
+    let character = shape("Cirrus Skiff", 3)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let shaping = character.sorcery().unwrap().shaping().unwrap();
+    assert_eq!(shaping.spell(), "Cirrus Skiff");
+    assert_eq!(shaping.motes(), 3);
+}
+
+#[test]
+fn test_cast_spell() {
+    let mut event_source = sorcerer();
+    assert!(matches!(
+        CastSpell(BUTTERFLIES.into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Not enough motes to cast yet
+    shape(BUTTERFLIES, 11)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(matches!(
+        CastSpell(BUTTERFLIES.into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Can only cast the spell being shaped
+    assert!(matches!(
+        CastSpell("Cirrus Skiff".into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Casting consumes the motes and the Willpower cost
+    shape(BUTTERFLIES, 5)
+        .apply_event(&mut event_source)
+        .unwrap();
+    let character = CastSpell(BUTTERFLIES.into())
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(character.sorcery().unwrap().shaping().is_none());
+    assert_eq!(character.willpower().current(), 4);
+
+    // And requires Willpower to spend
+    SpendWillpower(NonZeroU8::new(4).unwrap())
+        .apply_event(&mut event_source)
+        .unwrap();
+    shape(BUTTERFLIES, 15)
+        .apply_event(&mut event_source)
+        .unwrap();
+    assert!(matches!(
+        CastSpell(BUTTERFLIES.into()).apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+}
+
+#[test]
+fn test_distract_shaping() {
+    let mut event_source = sorcerer();
+    assert!(matches!(
+        DistractShaping {
+            difficulty: 2,
+            successes: 2,
+        }
+        .apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Resisting a distraction keeps the motes
+    shape(BUTTERFLIES, 8)
+        .apply_event(&mut event_source)
+        .unwrap();
+    DistractShaping {
+        difficulty: 2,
+        successes: 2,
+    }
+    .apply_event(&mut event_source)
+    .unwrap();
+    assert_eq!(shaped_motes(&event_source), Some(8));
+
+    // Failing loses them
+    DistractShaping {
+        difficulty: 2,
+        successes: 1,
+    }
+    .apply_event(&mut event_source)
+    .unwrap();
+    assert_eq!(shaped_motes(&event_source), None);
+}
+
+#[test]
+fn test_interrupt_shaping() {
+    let mut event_source = sorcerer();
+    assert!(matches!(
+        InterruptShaping.apply_event(&mut event_source),
+        Err(CharacterMutationError::SorceryError(_))
+    ));
+
+    // Being interrupted loses the motes
+    shape(BUTTERFLIES, 3)
+        .apply_event(&mut event_source)
+        .unwrap();
+    InterruptShaping.apply_event(&mut event_source).unwrap();
+    assert_eq!(shaped_motes(&event_source), None);
+}
+
+#[test]
+fn test_shaping_end_scene() {
+    // Sorcerous motes vanish at the end of the scene
+    let mut event_source = sorcerer();
+    shape(BUTTERFLIES, 3)
+        .apply_event(&mut event_source)
+        .unwrap();
+    EndScene.apply_event(&mut event_source).unwrap();
+    assert_eq!(shaped_motes(&event_source), None);
+
+    // Undoing the end of the scene restores them
+    event_source.undo().unwrap();
+    assert_eq!(shaped_motes(&event_source), Some(3));
+}
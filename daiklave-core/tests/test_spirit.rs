@@ -0,0 +1,141 @@
+use std::num::NonZeroU8;
+
+use daiklave_core::{
+    charms::{
+        charm::{
+            spirit::{AddSpiritCharm, SpiritCharm},
+            Charm,
+        },
+        CharmActionType, CharmError,
+    },
+    exaltation::exalt::essence::RecoverMotes,
+    quick_character::QuickStatName,
+    spirit::{Spirit, SpiritError},
+    CharacterMutationError, QuickCharacterMutation, SpiritMemo, SpiritMutation,
+};
+
+fn spirit_charm(name: &str, essence: u8, eclipse: bool) -> AddSpiritCharm {
+    let builder = SpiritCharm::builder(name.to_owned())
+        .essence_required(NonZeroU8::new(essence).unwrap())
+        .action_type(CharmActionType::Simple)
+        .duration("Instant".to_owned())
+        .description("A power of the spirit courts.".to_owned());
+    if eclipse {
+        builder.eclipse().into()
+    } else {
+        builder.non_eclipse().into()
+    }
+}
+
+fn lady_of_the_amber_falls() -> SpiritMemo {
+    [
+        QuickCharacterMutation::SetName("Lady of the Amber Falls".to_owned()).into(),
+        QuickCharacterMutation::SetStat(QuickStatName::Parry, 4).into(),
+        SpiritMutation::SetEssence(NonZeroU8::new(3).unwrap()),
+        SpiritMutation::AddCharm(spirit_charm("Hurry Home", 1, false)),
+        SpiritMutation::AddCharm(spirit_charm("Measure the Wind", 1, true)),
+        SpiritMutation::SetSanctum {
+            name: "Grotto Behind the Falls".to_owned(),
+            description: "A cave of amber light".to_owned(),
+        },
+    ]
+    .iter()
+    .fold(SpiritMemo::default(), |memo, mutation| {
+        memo.apply_mutation(mutation).unwrap()
+    })
+}
+
+#[test]
+fn test_spirit_traits() {
+    let memo = lady_of_the_amber_falls();
+    let spirit = Spirit::from(&memo);
+
+    assert_eq!(spirit.name(), "Lady of the Amber Falls");
+    assert_eq!(spirit.statblock().stats().get(QuickStatName::Parry), 4);
+    assert_eq!(spirit.essence().rating().get(), 3);
+    assert_eq!(spirit.essence().motes_max(), 80);
+    assert_eq!(spirit.sanctum().unwrap().name(), "Grotto Behind the Falls");
+
+    // Essence is set with the spirit-specific mutation, up to 10
+    assert!(matches!(
+        memo.apply_mutation(&QuickCharacterMutation::RemoveEssence.into()),
+        Err(CharacterMutationError::SpiritError(
+            SpiritError::StatblockMutation
+        ))
+    ));
+    assert!(matches!(
+        memo.apply_mutation(&SpiritMutation::SetEssence(NonZeroU8::new(11).unwrap())),
+        Err(CharacterMutationError::SpiritError(
+            SpiritError::InvalidEssence
+        ))
+    ));
+}
+
+#[test]
+fn test_spirit_charms() {
+    let memo = lady_of_the_amber_falls();
+    let spirit = Spirit::from(&memo);
+    assert!(matches!(
+        spirit.charm("Hurry Home"),
+        Some(Charm::SpiritOnly(_))
+    ));
+    assert!(matches!(
+        spirit.charm("Measure the Wind"),
+        Some(Charm::Eclipse(_))
+    ));
+    assert_eq!(spirit.charm_names().count(), 2);
+
+    // Charms require sufficient Essence
+    assert!(matches!(
+        memo.apply_mutation(&SpiritMutation::AddCharm(spirit_charm(
+            "Principle of Motion",
+            5,
+            false
+        ))),
+        Err(CharacterMutationError::CharmError(
+            CharmError::PrerequisitesNotMet
+        ))
+    ));
+}
+
+#[test]
+fn test_spirit_materialization() {
+    let memo = lady_of_the_amber_falls();
+    assert!(matches!(
+        memo.apply_mutation(&SpiritMutation::Dematerialize),
+        Err(CharacterMutationError::SpiritError(
+            SpiritError::NotMaterialized
+        ))
+    ));
+
+    let memo = memo
+        .apply_mutation(&SpiritMutation::Materialize(NonZeroU8::new(40).unwrap()))
+        .unwrap();
+    let spirit = Spirit::from(&memo);
+    assert!(spirit.is_materialized());
+    assert_eq!(spirit.essence().motes_available(), 40);
+    assert!(matches!(
+        memo.apply_mutation(&SpiritMutation::Materialize(NonZeroU8::new(1).unwrap())),
+        Err(CharacterMutationError::SpiritError(
+            SpiritError::AlreadyMaterialized
+        ))
+    ));
+
+    // Entering the Sanctum dematerializes the spirit
+    let memo = memo
+        .apply_mutation(&SpiritMutation::EnterSanctum)
+        .unwrap()
+        .apply_mutation(&RecoverMotes(NonZeroU8::new(100).unwrap()).into())
+        .unwrap();
+    let spirit = Spirit::from(&memo);
+    assert!(spirit.is_in_sanctum());
+    assert!(!spirit.is_materialized());
+    assert_eq!(spirit.essence().motes_available(), 80);
+
+    // ...which requires having a Sanctum
+    let memo = memo.apply_mutation(&SpiritMutation::RemoveSanctum).unwrap();
+    assert!(matches!(
+        memo.apply_mutation(&SpiritMutation::EnterSanctum),
+        Err(CharacterMutationError::SpiritError(SpiritError::NoSanctum))
+    ));
+}
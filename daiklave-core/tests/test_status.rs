@@ -0,0 +1,229 @@
+use daiklave_core::combat::{
+    attack::{Attack, AttackProfile, AttackStep, AttackType, Defense, DefenseProfile},
+    AddCombatant, Combat, CombatError, CombatMutation, CoverLevel, StatusEffect, StatusExpiry,
+    StatusName,
+};
+
+fn add(name: &str, status: StatusEffect) -> CombatMutation {
+    CombatMutation::AddStatus {
+        name: name.to_owned(),
+        status,
+    }
+}
+
+fn started_combat() -> Combat {
+    let mut combat = Combat::default();
+    for mutation in [
+        AddCombatant::npc("Archer", 5).into(),
+        AddCombatant::npc("Brawler", 5).into(),
+        AddCombatant::npc("Squire", 2).into(),
+        CombatMutation::JoinBattle {
+            name: "Archer".to_owned(),
+            successes: 6,
+        },
+        CombatMutation::JoinBattle {
+            name: "Brawler".to_owned(),
+            successes: 4,
+        },
+        CombatMutation::JoinBattle {
+            name: "Squire".to_owned(),
+            successes: 1,
+        },
+        CombatMutation::StartCombat,
+    ] {
+        combat.apply_mutation(&mutation).unwrap();
+    }
+    combat
+}
+
+#[test]
+fn test_status_modifiers() {
+    let mut combat = started_combat();
+
+    // Aiming and cover
+    combat
+        .apply_mutation(&add("Archer", StatusEffect::Aiming))
+        .unwrap()
+        .apply_mutation(&add("Archer", StatusEffect::Cover(CoverLevel::Heavy)))
+        .unwrap();
+    let archer = combat.combatant("Archer").unwrap();
+    assert_eq!(archer.pool_modifier(), 3);
+    assert_eq!(archer.defense_modifier(), 2);
+
+    // Prone, clinched, and two attacks of onslaught
+    combat
+        .apply_mutation(&add(
+            "Squire",
+            StatusEffect::Clinched {
+                controller: "Brawler".to_owned(),
+                control_rounds: 2,
+            },
+        ))
+        .unwrap()
+        .apply_mutation(&add("Brawler", StatusEffect::Prone))
+        .unwrap()
+        .apply_mutation(&add("Squire", StatusEffect::Onslaught(1)))
+        .unwrap()
+        .apply_mutation(&add("Squire", StatusEffect::Onslaught(1)))
+        .unwrap();
+    assert_eq!(combat.combatant("Brawler").unwrap().pool_modifier(), -3);
+    assert_eq!(combat.combatant("Squire").unwrap().defense_modifier(), -4);
+
+    // Can't clinch or defend someone who isn't in the fight
+    assert!(matches!(
+        combat.apply_mutation(&add(
+            "Squire",
+            StatusEffect::Clinched {
+                controller: "Nobody".to_owned(),
+                control_rounds: 1
+            }
+        )),
+        Err(CombatError::NotFound)
+    ));
+    assert!(matches!(
+        combat.apply_mutation(&add(
+            "Squire",
+            StatusEffect::DefendingOther("Squire".to_owned())
+        )),
+        Err(CombatError::NotFound)
+    ));
+}
+
+#[test]
+fn test_status_expiry() {
+    let mut combat = started_combat();
+    assert_eq!(StatusEffect::Aiming.expiry(), StatusExpiry::EndOfNextTurn);
+    assert_eq!(
+        StatusEffect::Onslaught(1).expiry(),
+        StatusExpiry::StartOfNextTurn
+    );
+
+    // The Archer aims and takes cover; the Brawler clinches the Squire
+    combat
+        .apply_mutation(&add("Archer", StatusEffect::Aiming))
+        .unwrap()
+        .apply_mutation(&add("Archer", StatusEffect::Cover(CoverLevel::Heavy)))
+        .unwrap()
+        .apply_mutation(&CombatMutation::EndTurn)
+        .unwrap()
+        .apply_mutation(&add(
+            "Squire",
+            StatusEffect::Clinched {
+                controller: "Brawler".to_owned(),
+                control_rounds: 2,
+            },
+        ))
+        .unwrap()
+        .apply_mutation(&add("Squire", StatusEffect::Onslaught(1)))
+        .unwrap();
+
+    // Ending the Brawler's turn spends a control round; the Squire's
+    // onslaught resets as their turn begins
+    combat.apply_mutation(&CombatMutation::EndTurn).unwrap();
+    let squire = combat.combatant("Squire").unwrap();
+    assert!(squire.status(StatusName::Onslaught).is_none());
+    assert_eq!(
+        squire.status(StatusName::Clinched),
+        Some(&StatusEffect::Clinched {
+            controller: "Brawler".to_owned(),
+            control_rounds: 1
+        })
+    );
+
+    // The Squire defends the Archer until their next turn
+    combat
+        .apply_mutation(&add(
+            "Squire",
+            StatusEffect::DefendingOther("Archer".to_owned()),
+        ))
+        .unwrap()
+        .apply_mutation(&CombatMutation::EndTurn)
+        .unwrap();
+    assert_eq!(combat.protector("Archer").unwrap().name(), "Squire");
+
+    // The Archer doesn't shoot, so their aim expires at the end of their
+    // turn, but cover remains
+    combat.apply_mutation(&CombatMutation::EndTurn).unwrap();
+    let archer = combat.combatant("Archer").unwrap();
+    assert!(archer.status(StatusName::Aiming).is_none());
+    assert!(archer.status(StatusName::Cover).is_some());
+
+    // The grapple ends when control rounds run out
+    combat.apply_mutation(&CombatMutation::EndTurn).unwrap();
+    assert!(combat
+        .combatant("Squire")
+        .unwrap()
+        .status(StatusName::Clinched)
+        .is_none());
+
+    // Full defense lasts until the start of the combatant's next turn
+    combat
+        .apply_mutation(&add("Squire", StatusEffect::FullDefense))
+        .unwrap()
+        .apply_mutation(&CombatMutation::EndTurn)
+        .unwrap();
+    assert!(combat.protector("Archer").is_none());
+    assert!(combat
+        .combatant("Squire")
+        .unwrap()
+        .status(StatusName::FullDefense)
+        .is_some());
+}
+
+#[test]
+fn test_status_defend_other() {
+    let mut combat = started_combat();
+    combat
+        .apply_mutation(&add(
+            "Squire",
+            StatusEffect::DefendingOther("Archer".to_owned()),
+        ))
+        .unwrap();
+    let punch = Attack::new(
+        AttackProfile::npc("Brawler", 5, 4),
+        DefenseProfile::npc("Archer", 1, 1, 2, 0),
+        AttackType::Withering,
+        Defense::Evasion,
+    );
+    let mut misses = |n: u8| vec![1; n as usize];
+
+    // Attacks on the ward need their protector's defenses
+    assert!(matches!(
+        punch.resolve(&combat, &mut misses),
+        Err(CombatError::NoProtector)
+    ));
+    assert!(matches!(
+        punch
+            .clone()
+            .with_protector(DefenseProfile::npc("Brawler", 4, 4, 0, 0))
+            .resolve(&combat, &mut misses),
+        Err(CombatError::NoProtector)
+    ));
+
+    // The protector's higher Parry is used, and they suffer the onslaught
+    let outcome = punch
+        .clone()
+        .with_protector(DefenseProfile::npc("Squire", 4, 2, 2, 0))
+        .resolve(&combat, &mut misses)
+        .unwrap();
+    let steps = outcome.steps().collect::<Vec<&AttackStep>>();
+    assert!(steps.contains(&&AttackStep::DefendedBy("Squire".to_owned())));
+    assert!(steps.contains(&&AttackStep::Miss { defense: 4 }));
+    for mutation in outcome.combat_mutations() {
+        combat.apply_mutation(mutation).unwrap();
+    }
+    assert_eq!(combat.combatant("Squire").unwrap().defense_modifier(), -1);
+    assert_eq!(combat.combatant("Archer").unwrap().defense_modifier(), 0);
+
+    // A protector with a lower Parry doesn't help
+    let outcome = punch
+        .with_protector(DefenseProfile::npc("Squire", 0, 2, 2, 0))
+        .resolve(&combat, &mut misses)
+        .unwrap();
+    assert!(!outcome
+        .steps()
+        .any(|step| matches!(step, AttackStep::DefendedBy(_))));
+    assert!(outcome
+        .steps()
+        .any(|step| *step == AttackStep::Miss { defense: 1 }));
+}
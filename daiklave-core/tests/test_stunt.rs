@@ -0,0 +1,76 @@
+use daiklave_core::{
+    stunt::{AwardStunt, StuntLevel},
+    CharacterEvent, CharacterEventSource, CharacterMutation,
+};
+
+#[test]
+fn test_stunt_level() {
+    assert_eq!(StuntLevel::new(0), None);
+    assert_eq!(StuntLevel::new(4), None);
+    assert_eq!(StuntLevel::new(2), Some(StuntLevel::Two));
+    assert_eq!(StuntLevel::Three.get(), 3);
+
+    // Every stunt grants the same bonus; only Willpower scales with level
+    for level in [StuntLevel::One, StuntLevel::Two, StuntLevel::Three] {
+        assert_eq!(level.bonus_dice(), 2);
+        assert_eq!(level.static_bonus(), 1);
+    }
+    assert_eq!(StuntLevel::One.willpower(), 0);
+    assert_eq!(StuntLevel::Three.willpower(), 2);
+}
+
+#[test]
+fn test_stunt_willpower() {
+    let mut event_source = CharacterEventSource::default();
+
+    // One-point stunts grant dice but no Willpower
+    let character = AwardStunt {
+        level: StuntLevel::One,
+    }
+    .apply_event(&mut event_source)
+    .unwrap();
+    assert_eq!(character.willpower().current(), 3);
+
+    // Two- and three-point stunts restore Willpower, even above the rating
+    AwardStunt {
+        level: StuntLevel::Two,
+    }
+    .apply_event(&mut event_source)
+    .unwrap();
+    let character = AwardStunt {
+        level: StuntLevel::Three,
+    }
+    .apply_event(&mut event_source)
+    .unwrap();
+    assert_eq!(character.willpower().current(), 6);
+    assert_eq!(character.willpower().rating().get(), 3);
+
+    // Applying directly to a character returns the bonus dice
+    let mut character = event_source.as_character().unwrap();
+    assert_eq!(character.award_stunt(StuntLevel::Three).unwrap(), 2);
+    assert_eq!(character.willpower().current(), 8);
+}
+
+#[test]
+fn test_stunt_undo() {
+    let mut event_source = CharacterEventSource::default();
+    let stunt = AwardStunt {
+        level: StuntLevel::Two,
+    };
+    let mutation: CharacterMutation = stunt.into();
+    assert_eq!(mutation, CharacterMutation::AwardStunt(stunt));
+
+    // Stunts can be undone like any other mutation
+    assert_eq!(
+        stunt
+            .apply_event(&mut event_source)
+            .unwrap()
+            .willpower()
+            .current(),
+        4
+    );
+    assert!(event_source.can_undo());
+    let character = event_source.undo().unwrap();
+    assert_eq!(character.willpower().current(), 3);
+    assert!(!event_source.can_undo());
+}